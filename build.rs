@@ -3,6 +3,7 @@ use regex::Regex;
 use sha2::{Digest, Sha256};
 use std::env;
 use std::fs;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 fn main() {
@@ -21,11 +22,17 @@ fn main() {
         .or_else(|_| get_git_describe())
         .unwrap_or_else(|_| env::var("CARGO_PKG_VERSION").unwrap_or("unknown".to_string()));
 
+    // Bundle the first-party templates so they work without network access
+    if let Err(e) = generate_embedded_templates() {
+        panic!("Failed to embed templates: {}", e);
+    }
+
     println!("cargo:rustc-env=APP_VERSION={}", version);
     println!("cargo:rustc-env=BUILD_RS_CHECKSUM={}", build_rs_checksum);
     println!("cargo:rustc-env=BUILD_TIME={}", build_time);
     println!("cargo:rerun-if-env-changed=APP_VERSION");
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=templates");
     println!("cargo:rerun-if-changed=.git/HEAD");
     println!("cargo:rerun-if-changed=.git/refs/tags");
 }
@@ -44,10 +51,53 @@ fn generate_build_rs_checksum() -> Result<String, Box<dyn std::error::Error>> {
     Ok(format!("{:x}", result))
 }
 
+fn generate_embedded_templates() -> Result<(), Box<dyn std::error::Error>> {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR")?);
+    let templates_dir = manifest_dir.join("templates");
+
+    let mut files = Vec::new();
+    collect_template_files(&templates_dir, &mut files)?;
+    files.sort();
+
+    // One (relative path, contents) pair per file, e.g. ("issue-templates/bug.yml", ...)
+    let mut generated = String::from("pub static EMBEDDED_TEMPLATES: &[(&str, &str)] = &[\n");
+    for file in &files {
+        let relative_path = file
+            .strip_prefix(&templates_dir)?
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        generated.push_str(&format!(
+            "    ({:?}, include_str!({:?})),\n",
+            relative_path,
+            file.display().to_string()
+        ));
+    }
+    generated.push_str("];\n");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+    fs::write(out_dir.join("embedded_templates.rs"), generated)?;
+
+    Ok(())
+}
+
+fn collect_template_files(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_template_files(&path, files)?;
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
 fn get_latest_semantic_git_tag() -> Result<String, Box<dyn std::error::Error>> {
     // Get all tags
     let output = Command::new("git")
-        .args(&["tag", "--list", "--sort=-version:refname"])
+        .args(["tag", "--list", "--sort=-version:refname"])
         .output()?;
 
     if !output.status.success() {
@@ -95,7 +145,7 @@ fn get_latest_semantic_git_tag() -> Result<String, Box<dyn std::error::Error>> {
 
 fn get_git_describe() -> Result<String, Box<dyn std::error::Error>> {
     let output = Command::new("git")
-        .args(&["describe", "--always", "--dirty"])
+        .args(["describe", "--always", "--dirty"])
         .output()?;
 
     if output.status.success() {
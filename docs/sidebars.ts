@@ -37,6 +37,8 @@ const sidebars: SidebarsConfig = {
           items: [
             'commands/issue/issue',
             'commands/issue/issue-add',
+            'commands/issue/issue-config',
+            'commands/issue/issue-convert',
             'commands/issue/issue-list',
             'commands/issue/issue-preview',
           ],
@@ -47,6 +49,9 @@ const sidebars: SidebarsConfig = {
           items: [
             'commands/pr/pr',
             'commands/pr/pr-add',
+            'commands/pr/pr-compose',
+            'commands/pr/pr-link',
+            'commands/pr/pr-lint',
             'commands/pr/pr-list',
             'commands/pr/pr-preview',
           ],
@@ -57,6 +62,7 @@ const sidebars: SidebarsConfig = {
           items: [
             'commands/license/license',
             'commands/license/license-add',
+            'commands/license/license-check',
             'commands/license/license-list',
             'commands/license/license-preview',
           ],
@@ -0,0 +1,166 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+
+use crate::commands::base::Runnable;
+use crate::commands::{ci, code_of_conduct, gitignore, issue, license, pr};
+use crate::utils::project_config::{self, ProjectConfig, PROJECT_CONFIG_FILE};
+use crate::utils::source::SourceArgs;
+
+// Command to install every template declared in .gh-templates.toml
+
+#[derive(clap::Args, Debug)]
+pub struct ApplyArgs {
+    /// Force overwrite files that already exist
+    #[arg(long)]
+    pub force: bool,
+}
+
+impl Runnable for ApplyArgs {
+    fn run(&self) -> Result<()> {
+        let (root, config) = ProjectConfig::find()?;
+        let steps = install_steps(&config, self.force);
+
+        if steps.is_empty() {
+            println!(
+                "Nothing to apply: {} does not list any templates.",
+                root.join(PROJECT_CONFIG_FILE).display()
+            );
+            return Ok(());
+        }
+
+        let mut failed = Vec::new();
+        for (category, step) in &steps {
+            if let Err(e) = step.run() {
+                eprintln!("{}", format!("Failed to apply {}: {}", category, e).red());
+                failed.push(*category);
+            }
+        }
+
+        if !failed.is_empty() {
+            return Err(anyhow!(
+                "Some templates could not be applied: {}",
+                failed.join(", ")
+            ));
+        }
+
+        println!(
+            "{} Applied all templates from {}",
+            "✓".green(),
+            PROJECT_CONFIG_FILE
+        );
+        Ok(())
+    }
+}
+
+/// One `add` invocation per category declared in the project config
+fn install_steps(config: &ProjectConfig, force: bool) -> Vec<(&'static str, Box<dyn Runnable>)> {
+    let mut steps: Vec<(&'static str, Box<dyn Runnable>)> = Vec::new();
+
+    if let Some(section) = &config.issue {
+        steps.push((
+            "issue",
+            Box::new(issue::add::AddArgs {
+                templates: section.templates.clone(),
+                dir: None,
+                force,
+                all: false,
+                output: Vec::new(),
+                metadata: issue::add::MetadataArgs::default(),
+                source: source_args(&section.source),
+            }),
+        ));
+    }
+
+    // A `[pr]` section may only hold params for `pr add`
+    if let Some(section) = config.pr.as_ref().filter(|s| !s.templates.is_empty()) {
+        steps.push((
+            "pr",
+            Box::new(pr::add::AddArgs {
+                templates: section.templates.clone(),
+                dir: None,
+                force,
+                all: false,
+                update_cache: false,
+                interactive: false,
+                // `pr add` reads the section's params itself
+                params: Vec::new(),
+                output: Vec::new(),
+                source: source_args(&section.source),
+            }),
+        ));
+    }
+
+    if let Some(section) = &config.license {
+        steps.push((
+            "license",
+            Box::new(license::add::AddArgs {
+                licenses: vec![section.id.clone()],
+                dir: None,
+                force,
+                all: false,
+                interactive: false,
+                update_cache: false,
+                params: project_config::to_param_args(&section.params),
+                output: Vec::new(),
+                source: source_args(&section.source),
+            }),
+        ));
+    }
+
+    if let Some(section) = &config.gitignore {
+        steps.push((
+            "gitignore",
+            Box::new(gitignore::add::AddArgs {
+                templates: section.templates.clone(),
+                dir: None,
+                force,
+                all: false,
+                auto: false,
+                append: false,
+                update_cache: false,
+                use_remote_name: false,
+                output: vec![".gitignore".to_string()],
+                source: source_args(&section.source),
+            }),
+        ));
+    }
+
+    if let Some(section) = &config.ci {
+        steps.push((
+            "ci",
+            Box::new(ci::add::AddArgs {
+                templates: section.templates.clone(),
+                dir: None,
+                force,
+                output: Vec::new(),
+                source: source_args(&section.source),
+            }),
+        ));
+    }
+
+    if let Some(section) = &config.code_of_conduct {
+        steps.push((
+            "code-of-conduct",
+            Box::new(code_of_conduct::add::AddArgs {
+                template: section
+                    .template
+                    .clone()
+                    .unwrap_or_else(|| code_of_conduct::DEFAULT_TEMPLATE.to_string()),
+                dir: None,
+                github: section.github,
+                force,
+                interactive: false,
+                params: project_config::to_param_args(&section.params),
+                output: None,
+            }),
+        ));
+    }
+
+    steps
+}
+
+fn source_args(source: &Option<String>) -> SourceArgs {
+    SourceArgs {
+        source: source.clone(),
+    }
+}
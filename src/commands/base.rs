@@ -1,11 +1,27 @@
 use clap::ValueEnum;
 
-#[derive(Clone, ValueEnum)]
+#[derive(Clone, Copy, ValueEnum)]
 pub enum TemplateCategory {
     Issue,
     License,
     PR, // pull request
     Gitignore,
+    Ci,
+    CodeOfConduct,
+}
+
+impl TemplateCategory {
+    /// Name of the category in config files (e.g. `[sources.issue]`)
+    pub fn config_key(&self) -> &'static str {
+        match self {
+            Self::Issue => "issue",
+            Self::License => "license",
+            Self::PR => "pr",
+            Self::Gitignore => "gitignore",
+            Self::Ci => "ci",
+            Self::CodeOfConduct => "code-of-conduct",
+        }
+    }
 }
 
 pub trait Runnable {
@@ -0,0 +1,127 @@
+use std::path::{Path, PathBuf};
+
+use crate::commands::base::TemplateCategory;
+use crate::utils::file;
+use crate::utils::lockfile;
+use crate::utils::progress;
+use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
+
+use super::{template_file, templates_base};
+
+const OUTPUT_BASE_PATH: &str = ".github";
+const OUTPUT: &str = "workflows";
+
+// Command to add CI workflow templates
+
+#[derive(clap::Args, Debug)]
+pub struct AddArgs {
+    /// Template names to add (e.g., python/lint/ruff, python/test/pytest)
+    #[arg(value_name = "TEMPLATE")]
+    pub templates: Vec<String>,
+
+    /// Directory to save the workflow files
+    #[arg(long, value_name = "DIR")]
+    pub dir: Option<PathBuf>,
+
+    /// Force overwrite existing workflow files
+    #[arg(long)]
+    pub force: bool,
+
+    /// Output file names for the workflows (in order of templates, default: python-lint-ruff.yml for python/lint/ruff)
+    #[arg(short = 'o', long, value_name = "OUTPUT", num_args = 1.., requires = "templates")]
+    pub output: Vec<String>,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
+}
+
+impl super::Runnable for AddArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        let base = templates_base(&self.source)?;
+
+        if self.templates.is_empty() {
+            return Err(anyhow::anyhow!(
+                "No CI template specified. Pass template names (e.g., python/lint/ruff)."
+            ));
+        }
+
+        if !self.output.is_empty() {
+            if self.templates.len() != self.output.len() {
+                return Err(anyhow::anyhow!(
+                    "The number of templates and output file names must match."
+                ));
+            }
+            for (template_name, output_name) in self.templates.iter().zip(self.output.iter()) {
+                download_single_template(
+                    &base,
+                    template_name,
+                    self.dir.as_ref(),
+                    self.force,
+                    Some(output_name.clone()),
+                )?;
+            }
+        } else {
+            for template_name in &self.templates {
+                download_single_template(
+                    &base,
+                    template_name,
+                    self.dir.as_ref(),
+                    self.force,
+                    None,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Helper functions
+
+fn download_single_template(
+    base: &str,
+    template_name: &str,
+    dir_path: Option<&PathBuf>,
+    force: bool,
+    output: Option<String>,
+) -> anyhow::Result<()> {
+    let fetcher = Fetcher::new();
+
+    let template_file = template_file(template_name);
+    let url = format!("{}/{}", base, template_file);
+
+    let msg = format!("Downloading CI template: {}", template_name);
+    let pb = progress::spinner(&msg);
+    let content = fetcher.fetch_content(&url)?;
+    pb.set_message("Download Complete");
+    pb.finish_and_clear();
+
+    // Workflows are flat but keep their category, so python/lint/black.yml
+    // and python/format/black.yml do not collide: python-lint-black.yml
+    let filename = match output {
+        Some(mut output_file) => {
+            if Path::new(&output_file).extension().is_none() {
+                output_file.push_str(".yml");
+            }
+            output_file
+        }
+        None => template_file.replace('/', "-"),
+    };
+
+    let dest_path = dir_path
+        .map(|p| p.join(&filename))
+        .unwrap_or_else(|| Path::new(OUTPUT_BASE_PATH).join(OUTPUT).join(&filename));
+
+    file::save_file(&content, &dest_path, force)?;
+    lockfile::record(
+        &dest_path,
+        TemplateCategory::Ci,
+        template_name,
+        &url,
+        &content,
+        &content,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use colored::*;
+
+use crate::commands::base::TemplateCategory;
+use crate::utils::get_comment;
+use crate::utils::manifest_navigator::ManifestNavigator;
+use crate::utils::output::{print_records, FormatArgs, TemplateRecord};
+use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
+
+use super::templates_base;
+
+#[derive(clap::Args)]
+pub struct ListArgs {
+    /// Only list templates under this path (e.g., python, python/lint)
+    #[arg(value_name = "PREFIX")]
+    pub prefix: Option<String>,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    #[command(flatten)]
+    pub format: FormatArgs,
+}
+
+impl super::Runnable for ListArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        let templates =
+            list_all_ci_templates(&templates_base(&self.source)?, self.prefix.as_deref())?;
+
+        if !self.format.is_text() {
+            return print_records(&templates, self.format.format, TemplateRecord::COLUMNS);
+        }
+
+        if templates.is_empty() {
+            println!("No CI templates found.");
+            return Ok(());
+        }
+
+        println!("{} Available CI templates:", "✓".green());
+        for template in templates {
+            match template.description {
+                Some(description) => {
+                    println!("  {} {:<36} - {}", ">".green(), template.name, description)
+                }
+                None => println!("  {} {}", ">".green(), template.name),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn list_all_ci_templates(base: &str, prefix: Option<&str>) -> anyhow::Result<Vec<TemplateRecord>> {
+    let fetcher = Fetcher::new();
+
+    let manifest_url = format!("{}/manifest.yml", base);
+    let manifest_navigator = ManifestNavigator::new(&manifest_url)?;
+    let prefix = prefix.map(|p| format!("{}/", p.trim_matches('/')));
+
+    let template_entries: Vec<_> = manifest_navigator
+        .list_entries_recursive()?
+        .into_iter()
+        .filter(|entry| prefix.as_ref().is_none_or(|p| entry.name.starts_with(p)))
+        .collect();
+
+    let mut templates = Vec::new();
+    for entry in template_entries {
+        let file_url = &entry.full_url;
+        let extension = std::path::Path::new(file_url)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("");
+        let comment = match fetcher.fetch_content(file_url) {
+            Ok(text) => text
+                .lines()
+                .next()
+                .and_then(|line| get_comment::extract_comment(line, extension)),
+            _ => None,
+        };
+
+        let template_name = entry.name.strip_suffix(".yml").unwrap_or(&entry.name);
+
+        templates.push(TemplateRecord {
+            name: template_name.to_string(),
+            category: TemplateCategory::Ci.config_key(),
+            url: file_url.clone(),
+            description: comment,
+        });
+    }
+    Ok(templates)
+}
@@ -0,0 +1,51 @@
+use clap::Subcommand;
+
+use crate::commands::base::{Runnable, TemplateCategory};
+use crate::utils::source::SourceArgs;
+
+pub(crate) mod add;
+mod list;
+mod preview;
+
+// Global constants - these can stay in the main module file
+const GITHUB_RAW_BASE: &str =
+    "https://raw.githubusercontent.com/rafaeljohn9/gh-templates/main/templates";
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Add one or more CI workflow templates to the repository
+    Add(add::AddArgs),
+    /// List available CI workflow templates
+    List(list::ListArgs),
+    /// Preview a specific CI workflow template
+    Preview(preview::PreviewArgs),
+}
+
+impl Command {
+    pub fn execute(&self) -> anyhow::Result<()> {
+        match self {
+            Command::Add(args) => args.run(),
+            Command::List(args) => args.run(),
+            Command::Preview(args) => args.run(),
+        }
+    }
+}
+
+/// Normalize a CI template name (e.g. `python/lint/ruff`) to its file path
+/// inside `ci-templates/`, appending `.yml` when no extension is given.
+fn template_file(template_name: &str) -> String {
+    let template_name = template_name.trim_matches('/');
+    if std::path::Path::new(template_name).extension().is_some() {
+        template_name.to_string()
+    } else {
+        format!("{}.yml", template_name)
+    }
+}
+
+/// Base URL of the ci-templates directory for the selected source
+fn templates_base(source: &SourceArgs) -> anyhow::Result<String> {
+    source.resolve(
+        TemplateCategory::Ci,
+        &format!("{}/ci-templates", GITHUB_RAW_BASE),
+    )
+}
@@ -0,0 +1,47 @@
+use crate::utils::pretty_print;
+use crate::utils::progress;
+use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
+
+use super::{template_file, templates_base};
+
+#[derive(clap::Args)]
+pub struct PreviewArgs {
+    /// CI template names to preview (e.g., python/lint/ruff)
+    #[arg(value_name = "TEMPLATE")]
+    pub templates: Vec<String>,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
+}
+
+impl super::Runnable for PreviewArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        if self.templates.is_empty() {
+            return Err(anyhow::anyhow!(
+                "No CI template specified. Pass template names as arguments."
+            ));
+        }
+
+        let base = templates_base(&self.source)?;
+        for template_name in &self.templates {
+            preview_single_template(&base, template_name)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn preview_single_template(base: &str, template: &str) -> anyhow::Result<()> {
+    let fetcher = Fetcher::new();
+    let url = format!("{}/{}", base, template_file(template));
+
+    let pb = progress::spinner(&format!("Fetching CI template: {}", template));
+    let content = fetcher.fetch_content(&url)?;
+    let msg = format!("Successfully fetched CI template: {}", template);
+    pb.set_message(msg);
+    pb.finish_and_clear();
+
+    pretty_print::print_highlighted("yml", &content);
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use regex::Regex;
+
+use crate::utils::file;
+use crate::utils::placeholder;
+use crate::utils::progress;
+use crate::utils::remote::Fetcher;
+
+use super::{template_url, DEFAULT_TEMPLATE};
+
+const OUTPUT_BASE_PATH: &str = ".github";
+const OUTPUT: &str = "CODE_OF_CONDUCT.md";
+
+// Command to add a code of conduct
+
+#[derive(clap::Args, Debug)]
+pub struct AddArgs {
+    /// Code of conduct to add
+    #[arg(value_name = "TEMPLATE", default_value = DEFAULT_TEMPLATE)]
+    pub template: String,
+
+    /// Directory to save the code of conduct (default: repository root)
+    #[arg(long, value_name = "DIR", conflicts_with = "github")]
+    pub dir: Option<PathBuf>,
+
+    /// Save the code of conduct in the .github directory
+    #[arg(long)]
+    pub github: bool,
+
+    /// Force overwrite an existing code of conduct
+    #[arg(long)]
+    pub force: bool,
+
+    /// Interactive mode for filling placeholders
+    #[arg(long, short = 'i')]
+    pub interactive: bool,
+
+    /// Values for the placeholders, e.g. contact-email=conduct@example.com
+    #[arg(long = "param", value_name = "KEY=VALUE", num_args = 0.., action = clap::ArgAction::Append)]
+    pub params: Vec<String>,
+
+    /// Output file name (default: CODE_OF_CONDUCT.md)
+    #[arg(short = 'o', long, value_name = "OUTPUT")]
+    pub output: Option<String>,
+}
+
+impl super::Runnable for AddArgs {
+    fn run(&self) -> Result<()> {
+        let placeholder_params = placeholder::parse_params(&self.params)?;
+
+        let fetcher = Fetcher::new();
+        let url = template_url(&self.template)?;
+
+        let pb = progress::spinner(&format!("Downloading code of conduct: {}", self.template));
+        let content = fetcher.fetch_content(&url)?;
+        pb.set_message("Download Complete");
+        pb.finish_and_clear();
+
+        // Only ALL-CAPS [PLACEHOLDERS]; Markdown link text is left alone
+        let patterns = [Regex::new(r"\[([A-Z][A-Z0-9 _-]*)\]")?];
+        let processed = placeholder::process_placeholders(
+            &content,
+            "Code of conduct",
+            &patterns,
+            self.interactive,
+            &placeholder_params,
+        )?;
+
+        let filename = self.output.as_deref().unwrap_or(OUTPUT);
+        let dest_path = if self.github {
+            Path::new(OUTPUT_BASE_PATH).join(filename)
+        } else {
+            let dir = match &self.dir {
+                Some(d) => d.clone(),
+                None => file::find_repo_root().unwrap_or_else(|_| PathBuf::from(".")),
+            };
+            dir.join(filename)
+        };
+
+        file::save_file(&processed, &dest_path, self.force)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use colored::*;
+
+use crate::commands::base::TemplateCategory;
+use crate::utils::get_comment;
+use crate::utils::output::{print_records, FormatArgs, TemplateRecord};
+use crate::utils::remote::Fetcher;
+
+use super::{template_url, TEMPLATES};
+
+#[derive(clap::Args)]
+pub struct ListArgs {
+    #[command(flatten)]
+    pub format: FormatArgs,
+}
+
+impl super::Runnable for ListArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        let templates = list_all_templates()?;
+
+        if !self.format.is_text() {
+            return print_records(&templates, self.format.format, TemplateRecord::COLUMNS);
+        }
+
+        println!("{} Available codes of conduct:", "✓".green());
+        for template in templates {
+            println!(
+                "  {} {:<12} - {}",
+                ">".green(),
+                template.name,
+                template.description.unwrap_or_default()
+            );
+        }
+        Ok(())
+    }
+}
+
+fn list_all_templates() -> anyhow::Result<Vec<TemplateRecord>> {
+    let fetcher = Fetcher::new();
+
+    let mut templates = Vec::new();
+    for (name, _) in TEMPLATES {
+        let url = template_url(name)?;
+        let comment = match fetcher.fetch_content(&url) {
+            Ok(text) => text
+                .lines()
+                .next()
+                .and_then(|line| get_comment::extract_comment(line, "md")),
+            _ => None,
+        };
+
+        templates.push(TemplateRecord {
+            name: name.to_string(),
+            category: TemplateCategory::CodeOfConduct.config_key(),
+            url,
+            description: comment,
+        });
+    }
+    Ok(templates)
+}
@@ -0,0 +1,51 @@
+use clap::Subcommand;
+
+use crate::commands::base::Runnable;
+
+pub(crate) mod add;
+mod list;
+mod preview;
+
+// Global constants - these can stay in the main module file
+const GITHUB_RAW_BASE: &str =
+    "https://raw.githubusercontent.com/rafaeljohn9/gh-templates/main/templates";
+pub(crate) const DEFAULT_TEMPLATE: &str = "default";
+
+/// Available codes of conduct: (name, file under `templates/`)
+const TEMPLATES: &[(&str, &str)] = &[(DEFAULT_TEMPLATE, "CODE_OF_CONDUCT.md")];
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Add a code of conduct to the repository
+    Add(add::AddArgs),
+    /// List available codes of conduct
+    List(list::ListArgs),
+    /// Preview a specific code of conduct
+    Preview(preview::PreviewArgs),
+}
+
+impl Command {
+    pub fn execute(&self) -> anyhow::Result<()> {
+        match self {
+            Command::Add(args) => args.run(),
+            Command::List(args) => args.run(),
+            Command::Preview(args) => args.run(),
+        }
+    }
+}
+
+/// Resolve a code of conduct name to its raw URL
+fn template_url(template_name: &str) -> anyhow::Result<String> {
+    let template_name = template_name.strip_suffix(".md").unwrap_or(template_name);
+
+    TEMPLATES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(template_name))
+        .map(|(_, file)| format!("{}/{}", GITHUB_RAW_BASE, file))
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Code of conduct '{}' not found. Try `gh-templates code-of-conduct list` to view available templates.",
+                template_name
+            )
+        })
+}
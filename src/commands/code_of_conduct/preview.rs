@@ -0,0 +1,28 @@
+use crate::utils::pretty_print;
+use crate::utils::progress;
+use crate::utils::remote::Fetcher;
+
+use super::{template_url, DEFAULT_TEMPLATE};
+
+#[derive(clap::Args)]
+pub struct PreviewArgs {
+    /// Code of conduct to preview
+    #[arg(value_name = "TEMPLATE", default_value = DEFAULT_TEMPLATE)]
+    pub template: String,
+}
+
+impl super::Runnable for PreviewArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        let fetcher = Fetcher::new();
+        let url = template_url(&self.template)?;
+
+        let pb = progress::spinner(&format!("Fetching code of conduct: {}", self.template));
+        let content = fetcher.fetch_content(&url)?;
+        let msg = format!("Successfully fetched code of conduct: {}", self.template);
+        pb.set_message(msg);
+        pb.finish_and_clear();
+
+        pretty_print::print_highlighted("md", &content);
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+use colored::*;
+
+use crate::commands::base::Runnable;
+use crate::utils::detect::{self, Suggestion};
+use crate::utils::file;
+use crate::utils::output::{print_records, FormatArgs};
+
+// Command to suggest templates from the files in the repository
+
+#[derive(clap::Args, Debug)]
+pub struct DetectArgs {
+    #[command(flatten)]
+    pub format: FormatArgs,
+}
+
+impl Runnable for DetectArgs {
+    fn run(&self) -> Result<()> {
+        let root = file::find_repo_root()?;
+        let suggestions = detect::detect(&root)?;
+
+        if !self.format.is_text() {
+            return print_records(&suggestions, self.format.format, Suggestion::COLUMNS);
+        }
+
+        if suggestions.is_empty() {
+            println!("No known stack detected in {}.", root.display());
+            return Ok(());
+        }
+
+        println!("Suggested templates for {}:\n", root.display());
+        for suggestion in &suggestions {
+            println!(
+                "  {:<10} {:<28} {}",
+                suggestion.category.cyan(),
+                suggestion.template.bold(),
+                suggestion.reason.dimmed()
+            );
+        }
+
+        println!("\nInstall them with:");
+        if suggestions.iter().any(|s| s.category == "gitignore") {
+            println!("  gh-templates gitignore add --auto");
+        }
+        for category in ["ci", "license"] {
+            let templates: Vec<&str> = suggestions
+                .iter()
+                .filter(|s| s.category == category)
+                .map(|s| s.template.as_str())
+                .collect();
+            if !templates.is_empty() {
+                println!("  gh-templates {} add {}", category, templates.join(" "));
+            }
+        }
+
+        Ok(())
+    }
+}
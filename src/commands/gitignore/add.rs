@@ -1,15 +1,21 @@
+use std::fs;
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use colored::*;
 
+use crate::commands::pick::{self, PickCategory};
 use crate::utils::cache::{Cache, CacheManager};
+use crate::utils::detect;
 use crate::utils::file;
+use crate::utils::gitignore;
+use crate::utils::picker;
 use crate::utils::progress;
 use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
 
 use super::{
-    GITHUB_RAW_BASE, OUTPUT, OUTPUT_BASE_PATH, ensure_gitignore_cache, find_template_in_cache,
+    GitignoreSource, OUTPUT, OUTPUT_BASE_PATH, ensure_gitignore_cache, find_template_in_cache,
 };
 
 #[derive(clap::Args, Debug)]
@@ -22,7 +28,7 @@ pub struct AddArgs {
     #[arg(long, value_name = "DIR")]
     pub dir: Option<PathBuf>,
 
-    /// Force overwrite existing .gitignore file
+    /// Force overwrite existing .gitignore file (with --append, replace sections already present)
     #[arg(long)]
     pub force: bool,
 
@@ -30,7 +36,11 @@ pub struct AddArgs {
     #[arg(long)]
     pub all: bool,
 
-    /// Append to the existing .gitignore file instead of overwriting
+    /// Add the templates suggested for the files in the repository (see `gh-templates detect`)
+    #[arg(long, conflicts_with_all = ["templates", "all"])]
+    pub auto: bool,
+
+    /// Append to the existing .gitignore file, skipping templates it already contains
     #[arg(long, short = 'a')]
     pub append: bool,
 
@@ -52,13 +62,45 @@ pub struct AddArgs {
         requires = "templates"
     )]
     pub output: Vec<String>,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
 }
 
 impl super::Runnable for AddArgs {
     fn run(&self) -> anyhow::Result<()> {
+        if self.auto {
+            let root = match &self.dir {
+                Some(dir) => dir.clone(),
+                None => file::find_repo_root()?,
+            };
+            let templates: Vec<String> = detect::detect(&root)?
+                .into_iter()
+                .filter(|s| s.category == "gitignore")
+                .map(|s| s.template)
+                .collect();
+            if templates.is_empty() {
+                println!("No known stack detected in {}.", root.display());
+                return Ok(());
+            }
+            println!("Detected gitignore templates: {}", templates.join(", "));
+            return self.with_templates(templates).run();
+        }
+
+        if !self.all && self.templates.is_empty() && picker::is_interactive() {
+            let templates = pick::choose(PickCategory::Gitignore, &self.source)?;
+            if templates.is_empty() {
+                println!("No templates selected.");
+                return Ok(());
+            }
+            return self.with_templates(templates).run();
+        }
+
+        let source = GitignoreSource::resolve(&self.source)?;
         let mut cache_manager = CacheManager::new()?;
 
-        let cache: Cache<String> = ensure_gitignore_cache(&mut cache_manager, self.update_cache)?;
+        let cache: Cache<String> =
+            ensure_gitignore_cache(&mut cache_manager, self.update_cache, &source)?;
 
         let dir = match &self.dir {
             Some(dir) => Some(dir.clone()),
@@ -66,7 +108,13 @@ impl super::Runnable for AddArgs {
         };
 
         if self.all {
-            download_all_templates(dir.as_ref(), self.force, &cache, self.use_remote_name)?;
+            download_all_templates(
+                dir.as_ref(),
+                self.force,
+                &cache,
+                &source,
+                self.use_remote_name,
+            )?;
         } else if self.templates.is_empty() {
             return Err(anyhow::anyhow!(
                 "No gitignore template specified. Use `--all` or pass template names."
@@ -78,6 +126,7 @@ impl super::Runnable for AddArgs {
                 &self.output,
                 self.force,
                 &cache,
+                &source,
                 self.append,
                 self.use_remote_name,
             )?;
@@ -87,10 +136,25 @@ impl super::Runnable for AddArgs {
     }
 }
 
+impl AddArgs {
+    /// The same command for an explicit list of templates, merged into one file
+    fn with_templates(&self, templates: Vec<String>) -> Self {
+        Self {
+            templates,
+            dir: self.dir.clone(),
+            auto: false,
+            output: vec![".gitignore".to_string()],
+            source: self.source.clone(),
+            ..*self
+        }
+    }
+}
+
 fn download_all_templates(
     dir_path: Option<&PathBuf>,
     force: bool,
     cache: &Cache<String>,
+    source: &GitignoreSource,
     use_remote_name: bool,
 ) -> Result<()> {
     println!("Fetching all gitignore templates...");
@@ -99,7 +163,7 @@ fn download_all_templates(
     if use_remote_name {
         // Save each template as its remote filename (e.g., Python.gitignore)
         for (_key, rel_path_entry) in cache.entries.iter() {
-            let url = format!("{}/{}", GITHUB_RAW_BASE, rel_path_entry.data);
+            let url = source.template_url(&rel_path_entry.data);
 
             // Extract remote filename from rel_path_entry.data
             let remote_filename = Path::new(&rel_path_entry.data)
@@ -115,10 +179,6 @@ fn download_all_templates(
                         .join(remote_filename)
                 });
 
-            if force && dest_path.exists() {
-                std::fs::remove_file(&dest_path)?;
-            }
-
             let msg = format!("Downloading gitignore template: {}", remote_filename);
             let pb = progress::spinner(&msg);
             let content = fetcher.fetch_content(&url)?;
@@ -134,12 +194,10 @@ fn download_all_templates(
             .map(|p| p.join(".gitignore"))
             .unwrap_or_else(|| Path::new(OUTPUT_BASE_PATH).join(OUTPUT).join(".gitignore"));
 
-        if force && dest_path.exists() {
-            std::fs::remove_file(&dest_path)?;
-        }
-
+        // Collect every section first so the file is written (or planned) once
+        let mut sections = Vec::new();
         for (key, rel_path_entry) in cache.entries.iter() {
-            let url = format!("{}/{}", GITHUB_RAW_BASE, rel_path_entry.data);
+            let url = source.template_url(&rel_path_entry.data);
 
             let msg = format!("Downloading gitignore template: {}", key);
             let pb = progress::spinner(&msg);
@@ -147,8 +205,13 @@ fn download_all_templates(
             pb.set_message("Download Complete");
             pb.finish_and_clear();
 
-            let section = format!("# ===== {}.gitignore =====\n{}\n\n", key, content);
-            file::append_file(&section, &dest_path, None)?;
+            sections.push((format!("{}.gitignore", key), content));
+        }
+
+        if force {
+            file::save_file(&merge_sections(&sections), &dest_path, true)?;
+        } else {
+            append_sections(&sections, &dest_path, false)?;
         }
 
         println!(
@@ -161,12 +224,14 @@ fn download_all_templates(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn download_templates(
     templates: &[String],
     dir_path: Option<&PathBuf>,
     output: &[String],
     force: bool,
     cache: &Cache<String>,
+    source: &GitignoreSource,
     append: bool,
     use_remote_name: bool,
 ) -> Result<()> {
@@ -176,7 +241,7 @@ fn download_templates(
         // Each template is saved using its remote filename (e.g., Python.gitignore)
         for template_name in templates {
             let template_path = find_template_in_cache(template_name, cache)?;
-            let url = format!("{}/{}", GITHUB_RAW_BASE, template_path);
+            let url = source.template_url(template_path);
 
             // Extract filename from template_path (e.g., "Python.gitignore")
             let remote_filename = Path::new(&template_path)
@@ -190,8 +255,6 @@ fn download_templates(
             pb.set_message("Download Complete");
             pb.finish_and_clear();
 
-            let section = format!("# ===== {} =====\n{}\n\n", remote_filename, content);
-
             let dest_path = dir_path
                 .map(|p| p.join(remote_filename))
                 .unwrap_or_else(|| {
@@ -201,8 +264,9 @@ fn download_templates(
                 });
 
             if append {
-                file::append_file(&section, &dest_path, None)?;
+                append_sections(&[(remote_filename.to_string(), content)], &dest_path, force)?;
             } else {
+                let section = format!("# ===== {} =====\n{}\n\n", remote_filename, content);
                 file::save_file(&section, &dest_path, force)?;
             }
         }
@@ -210,9 +274,11 @@ fn download_templates(
         // Save each template to its own file as specified in output
         for (template_name, output_file) in templates.iter().zip(output.iter()) {
             // check if the template_name has  a .gitignore ext rm it to normalize it
-            let template_name = template_name.strip_suffix(".gitignore").unwrap_or(template_name);
+            let template_name = template_name
+                .strip_suffix(".gitignore")
+                .unwrap_or(template_name);
             let template_path = find_template_in_cache(template_name, cache)?;
-            let url = format!("{}/{}", GITHUB_RAW_BASE, template_path);
+            let url = source.template_url(template_path);
 
             let msg = format!("Downloading gitignore template: {}", template_name);
             let pb = progress::spinner(&msg);
@@ -220,15 +286,15 @@ fn download_templates(
             pb.set_message("Download Complete");
             pb.finish_and_clear();
 
-            let section = format!("# ===== {}.gitignore =====\n{}\n\n", template_name, content);
-
             let dest_path = dir_path
                 .map(|p| p.join(output_file))
                 .unwrap_or_else(|| Path::new(OUTPUT_BASE_PATH).join(OUTPUT).join(output_file));
 
             if append {
-                file::append_file(&section, &dest_path, None)?;
+                let name = format!("{}.gitignore", template_name);
+                append_sections(&[(name, content)], &dest_path, force)?;
             } else {
+                let section = format!("# ===== {}.gitignore =====\n{}\n\n", template_name, content);
                 file::save_file(&section, &dest_path, force)?;
             }
 
@@ -241,13 +307,13 @@ fn download_templates(
         }
     } else if output.len() == 1 {
         // Merge all templates into one file, but skip invalid templates and collect errors
-        let mut merged_content = String::new();
+        let mut sections = Vec::new();
         let mut errors = Vec::new();
 
         for template_name in templates {
             match find_template_in_cache(template_name, cache) {
                 Ok(template_path) => {
-                    let url = format!("{}/{}", GITHUB_RAW_BASE, template_path);
+                    let url = source.template_url(template_path);
 
                     let msg = format!("Downloading gitignore template: {}", template_name);
                     let pb = progress::spinner(&msg);
@@ -255,10 +321,7 @@ fn download_templates(
                         Ok(content) => {
                             pb.set_message("Download Complete");
                             pb.finish_and_clear();
-                            merged_content.push_str(&format!(
-                                "# ===== {}.gitignore =====\n{}\n\n",
-                                template_name, content
-                            ));
+                            sections.push((format!("{}.gitignore", template_name), content));
                         }
                         Err(e) => {
                             pb.finish_and_clear();
@@ -279,11 +342,11 @@ fn download_templates(
             .map(|p| p.join(&output[0]))
             .unwrap_or_else(|| Path::new(OUTPUT_BASE_PATH).join(OUTPUT).join(&output[0]));
 
-        if !merged_content.is_empty() {
+        if !sections.is_empty() {
             if append {
-                file::append_file(&merged_content, &dest_path, None)?;
+                append_sections(&sections, &dest_path, force)?;
             } else {
-                file::save_file(&merged_content, &dest_path, force)?;
+                file::save_file(&merge_sections(&sections), &dest_path, force)?;
             }
         }
 
@@ -299,3 +362,65 @@ fn download_templates(
 
     Ok(())
 }
+
+/// Merge downloaded templates into one file without repeating rules
+fn merge_sections(sections: &[(String, String)]) -> String {
+    let merged = gitignore::merge(sections);
+    if merged.removed > 0 {
+        println!(
+            "{} Skipped {} rule(s) already covered by an earlier template",
+            "✓".green(),
+            merged.removed
+        );
+    }
+    merged.content
+}
+
+/// Add templates to `dest_path`, skipping sections it already has, or
+/// replacing them when `replace` is set
+fn append_sections(sections: &[(String, String)], dest_path: &Path, replace: bool) -> Result<()> {
+    let resolved_path = file::resolve_output_path(dest_path)?;
+    if !resolved_path.exists() {
+        return file::save_file(&merge_sections(sections), dest_path, false);
+    }
+
+    let existing = fs::read_to_string(&resolved_path)?;
+    let merged = gitignore::append(&existing, sections, replace);
+
+    for name in &merged.skipped {
+        println!(
+            "{} {} is already in {}, skipping (use --force to replace it)",
+            "⚠".yellow(),
+            name,
+            dest_path.display()
+        );
+    }
+    if merged.removed > 0 {
+        println!(
+            "{} Skipped {} rule(s) already covered by an earlier template",
+            "✓".green(),
+            merged.removed
+        );
+    }
+
+    if merged.content == existing {
+        return Ok(());
+    }
+
+    if merged.replaced.is_empty() {
+        file::append_file(&merged.content[existing.len()..], dest_path, None)?;
+    } else {
+        file::overwrite_file(&merged.content, &resolved_path)?;
+        if !file::is_dry_run() {
+            for name in &merged.replaced {
+                println!(
+                    "{} Replaced {} in {}",
+                    "✓".green(),
+                    name,
+                    dest_path.display()
+                );
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use colored::*;
+
+use crate::utils::file;
+use crate::utils::gitignore::Gitignore;
+
+#[derive(clap::Args, Debug)]
+pub struct CheckArgs {
+    /// Paths to check, relative to the current directory
+    #[arg(value_name = "PATH", required = true)]
+    pub paths: Vec<String>,
+
+    /// Directory containing the .gitignore file
+    #[arg(long, value_name = "DIR")]
+    pub dir: Option<PathBuf>,
+
+    /// Gitignore file to check against (default: .gitignore)
+    #[arg(
+        long = "output",
+        short = 'o',
+        value_name = "FILENAME",
+        default_value = ".gitignore"
+    )]
+    pub output: String,
+}
+
+impl super::Runnable for CheckArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        let current_dir = std::env::current_dir()?;
+        let dir = match &self.dir {
+            Some(dir) => normalize(&current_dir.join(dir)),
+            None => file::find_repo_root()?,
+        };
+        let gitignore_path = dir.join(&self.output);
+
+        let content = fs::read_to_string(&gitignore_path)
+            .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", gitignore_path.display(), e))?;
+        let gitignore = Gitignore::parse(&content);
+
+        for path in &self.paths {
+            let absolute = normalize(&current_dir.join(path));
+            let relative = absolute
+                .strip_prefix(&dir)
+                .map_err(|_| anyhow::anyhow!("'{}' is outside of '{}'", path, dir.display()))?;
+            let relative = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            if relative.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "'{}' is the directory of the .gitignore itself",
+                    path
+                ));
+            }
+            let is_dir = path.ends_with('/') || absolute.is_dir();
+
+            let Some(verdict) = gitignore.check(&relative, is_dir) else {
+                println!("{} {}: not ignored", "✗".dimmed(), path);
+                continue;
+            };
+
+            let location = format!(
+                "{}:{} `{}`",
+                self.output, verdict.line_number, verdict.rule.raw
+            );
+            let section = verdict
+                .section
+                .map(|name| format!(" (from {})", name))
+                .unwrap_or_default();
+            let via = if verdict.matched_path != relative {
+                format!(" via its directory {}", verdict.matched_path)
+            } else {
+                String::new()
+            };
+
+            if verdict.is_ignored() {
+                println!(
+                    "{} {}: ignored by {}{}{}",
+                    "✓".green(),
+                    path,
+                    location.cyan(),
+                    via,
+                    section
+                );
+            } else {
+                println!(
+                    "{} {}: not ignored, re-included by {}{}",
+                    "✗".dimmed(),
+                    path,
+                    location.cyan(),
+                    section
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Resolve `.` and `..` without touching the filesystem, since the path may not exist
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    normalized
+}
@@ -1,9 +1,12 @@
 use colored::*;
+use serde::Serialize;
 use std::collections::HashMap;
 
 use crate::utils::cache::{Cache, CacheManager};
+use crate::utils::output::{print_records, FormatArgs};
+use crate::utils::source::SourceArgs;
 
-use super::ensure_gitignore_cache;
+use super::{ensure_gitignore_cache, GitignoreSource};
 
 #[derive(clap::Args)]
 pub struct ListArgs {
@@ -22,16 +25,28 @@ pub struct ListArgs {
     /// Update the gitignore cache
     #[arg(long = "update-cache", default_value = "false")]
     pub update_cache: bool,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    #[command(flatten)]
+    pub format: FormatArgs,
 }
 
 impl super::Runnable for ListArgs {
     fn run(&self) -> anyhow::Result<()> {
+        let source = GitignoreSource::resolve(&self.source)?;
         let mut cache_manager = CacheManager::new()?;
 
-        let cache: Cache<String> = ensure_gitignore_cache(&mut cache_manager, self.update_cache)?;
+        let cache: Cache<String> =
+            ensure_gitignore_cache(&mut cache_manager, self.update_cache, &source)?;
 
         // Filter templates based on arguments
-        let templates = filter_templates(&cache, self);
+        let templates = filter_templates(&cache, self, &source);
+
+        if !self.format.is_text() {
+            return print_records(&templates, self.format.format, GitIgnoreTemplate::COLUMNS);
+        }
 
         // Display results
         display_templates(templates);
@@ -40,20 +55,30 @@ impl super::Runnable for ListArgs {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GitIgnoreTemplate {
     pub name: String,
     pub path: String,
     pub category: String,
+    pub url: String,
+}
+
+impl GitIgnoreTemplate {
+    const COLUMNS: &'static [&'static str] = &["name", "path", "category", "url"];
 }
 
-fn filter_templates(cache: &Cache<String>, args: &ListArgs) -> Vec<GitIgnoreTemplate> {
+fn filter_templates(
+    cache: &Cache<String>,
+    args: &ListArgs,
+    source: &GitignoreSource,
+) -> Vec<GitIgnoreTemplate> {
     let mut templates = Vec::new();
 
     // Determine which categories to include
-    let show_popular = args.popular || (!args.popular && !args.global && !args.community);
-    let show_global = args.global || (!args.popular && !args.global && !args.community);
-    let show_community = args.community || (!args.popular && !args.global && !args.community);
+    let show_everything = !args.popular && !args.global && !args.community;
+    let show_popular = args.popular || show_everything;
+    let show_global = args.global || show_everything;
+    let show_community = args.community || show_everything;
 
     for (key, entry) in &cache.entries {
         let path = &entry.data;
@@ -71,6 +96,7 @@ fn filter_templates(cache: &Cache<String>, args: &ListArgs) -> Vec<GitIgnoreTemp
                 name: key.clone(),
                 path: path.clone(),
                 category,
+                url: source.template_url(path),
             });
         }
     }
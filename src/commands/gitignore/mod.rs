@@ -1,14 +1,19 @@
-use crate::commands::base::Runnable;
+use crate::commands::base::{Runnable, TemplateCategory};
 
 use crate::utils::cache::{Cache, CacheManager};
+use crate::utils::manifest_navigator::ManifestNavigator;
+use crate::utils::picker::Candidate;
 use crate::utils::progress;
-use crate::utils::remote::Fetcher;
+use crate::utils::remote::{self, Fetcher};
+use crate::utils::source::SourceArgs;
 
 use clap::Subcommand;
 
-mod add;
+pub(crate) mod add;
+mod check;
 mod list;
 mod preview;
+mod remove;
 
 // Global constants - these can stay in the main module file
 const GITHUB_API_BASE: &str = "https://api.github.com/repos/github/gitignore";
@@ -21,20 +26,48 @@ const CACHE_MAX_AGE_SECONDS: u64 = 60 * 60 * 24 * 30; // 30 days
 #[derive(Subcommand)]
 pub enum Command {
     Add(add::AddArgs),
+    /// Explain which .gitignore rule, and which template, ignores a path
+    Check(check::CheckArgs),
     List(list::ListArgs),
     Preview(preview::PreviewArgs),
+    /// Remove template sections added by `gitignore add` from a .gitignore file
+    Remove(remove::RemoveArgs),
 }
 
 impl Command {
     pub fn execute(&self) -> anyhow::Result<()> {
         match self {
             Command::Add(args) => args.run(),
+            Command::Check(args) => args.run(),
             Command::List(args) => args.run(),
             Command::Preview(args) => args.run(),
+            Command::Remove(args) => args.run(),
         }
     }
 }
 
+/// Where gitignore templates come from: the upstream `github/gitignore`
+/// repository, or a configured source laid out with `manifest.yml` files
+struct GitignoreSource {
+    raw_base: String,
+    cache_name: String,
+    is_upstream: bool,
+}
+
+impl GitignoreSource {
+    fn resolve(source: &SourceArgs) -> anyhow::Result<Self> {
+        Ok(Self {
+            raw_base: source.resolve(TemplateCategory::Gitignore, GITHUB_RAW_BASE)?,
+            cache_name: source.cache_name(GITIGNORE_CACHE_NAME),
+            is_upstream: source.source.is_none(),
+        })
+    }
+
+    fn template_url(&self, template_path: &str) -> String {
+        format!("{}/{}", self.raw_base, template_path)
+    }
+}
+
 fn find_template_in_cache<'a>(
     template_name: &str,
     cache: &'a Cache<String>,
@@ -55,7 +88,7 @@ fn find_template_in_cache<'a>(
     }
 
     // Fallback: if the template is "global/windows", try "windows"
-    if let Some(last_part) = normalized_template.split('/').last() {
+    if let Some(last_part) = normalized_template.split('/').next_back() {
         if let Some(entry) = cache.entries.get(last_part) {
             return Ok(&entry.data);
         }
@@ -94,13 +127,17 @@ fn find_template_in_cache<'a>(
 fn ensure_gitignore_cache(
     cache_manager: &mut CacheManager,
     update_cache: bool,
+    source: &GitignoreSource,
 ) -> Result<Cache<String>, anyhow::Error> {
     // Only print if we are updating the cache
     let should_update =
-        cache_manager.should_update_cache::<String>(GITIGNORE_CACHE_NAME, CACHE_MAX_AGE_SECONDS)?;
+        cache_manager.should_update_cache::<String>(&source.cache_name, CACHE_MAX_AGE_SECONDS)?;
+
+    // Offline, any existing index beats a rebuild from cached API responses
+    let offline_with_cache = remote::is_offline() && cache_manager.cache_exists(&source.cache_name);
 
-    if !should_update && !update_cache {
-        let cache = cache_manager.load_cache(GITIGNORE_CACHE_NAME)?;
+    if (!should_update && !update_cache) || offline_with_cache {
+        let cache = cache_manager.load_cache(&source.cache_name)?;
         // Only print if running in verbose/debug mode (not implemented here)
         // e.g., println!("Loaded gitignore template cache ({} templates)", cache.entries.len());
         return Ok(cache);
@@ -108,6 +145,25 @@ fn ensure_gitignore_cache(
 
     let pb = progress::spinner("Updating gitignore template cache...");
 
+    let cache = if source.is_upstream {
+        index_github_api()
+    } else {
+        index_manifest(&source.raw_base)
+    };
+    pb.finish_and_clear();
+    let cache = cache?;
+
+    eprintln!(
+        "Gitignore template cache updated ({} templates available).",
+        cache.entries.len()
+    );
+
+    cache_manager.save_cache(&source.cache_name, &cache)?;
+    Ok(cache)
+}
+
+/// Index the upstream repository through the GitHub contents API
+fn index_github_api() -> Result<Cache<String>, anyhow::Error> {
     let fetcher = Fetcher::new();
     let folders = vec![
         ("", ""), // root
@@ -128,9 +184,7 @@ fn ensure_gitignore_cache(
         if let Some(array) = entries.as_array() {
             for entry in array {
                 if let Some(name) = entry.get("name").and_then(|n| n.as_str()) {
-                    if name.ends_with(".gitignore") {
-                        let template_name = &name[..name.len() - ".gitignore".len()];
-
+                    if let Some(template_name) = name.strip_suffix(".gitignore") {
                         // Create the full path for fetching
                         let full_path = if prefix.is_empty() {
                             name.to_string()
@@ -151,12 +205,45 @@ fn ensure_gitignore_cache(
             }
         }
     }
-    pb.finish_and_clear();
-    println!(
-        "Gitignore template cache updated ({} templates available).",
-        cache.entries.len()
-    );
+    Ok(cache)
+}
+
+/// Index a configured source from its `manifest.yml` files
+fn index_manifest(raw_base: &str) -> Result<Cache<String>, anyhow::Error> {
+    let navigator = ManifestNavigator::new(&format!("{}/manifest.yml", raw_base))?;
+    let mut cache = Cache::new();
+
+    for entry in navigator.list_entries_recursive()? {
+        if let Some(template_name) = entry.name.strip_suffix(".gitignore") {
+            // Same key format as the upstream index: Global/Windows -> global-windows
+            let cache_key = template_name.to_lowercase().replace('/', "-");
+            cache.insert(cache_key, entry.name.clone());
+        }
+    }
 
-    cache_manager.save_cache(GITIGNORE_CACHE_NAME, &cache)?;
     Ok(cache)
 }
+
+/// Templates offered by `pick` and by `gitignore add` without arguments
+pub(crate) fn pick_candidates(source: &SourceArgs) -> anyhow::Result<Vec<Candidate>> {
+    let source = GitignoreSource::resolve(source)?;
+    let mut cache_manager = CacheManager::new()?;
+    let cache = ensure_gitignore_cache(&mut cache_manager, false, &source)?;
+
+    let mut candidates: Vec<Candidate> = cache
+        .entries
+        .into_iter()
+        .map(|(name, entry)| {
+            let url = source.template_url(&entry.data);
+            Candidate {
+                group: TemplateCategory::Gitignore.config_key(),
+                name,
+                detail: entry.data,
+                ext: "gitignore",
+                preview: Box::new(move || Fetcher::new().fetch_content(&url)),
+            }
+        })
+        .collect();
+    candidates.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(candidates)
+}
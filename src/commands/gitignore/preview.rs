@@ -2,8 +2,9 @@ use crate::utils::cache::CacheManager;
 use crate::utils::pretty_print;
 use crate::utils::progress;
 use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
 
-use super::{GITHUB_RAW_BASE, ensure_gitignore_cache, find_template_in_cache};
+use super::{GitignoreSource, ensure_gitignore_cache, find_template_in_cache};
 
 #[derive(clap::Args)]
 pub struct PreviewArgs {
@@ -14,6 +15,9 @@ pub struct PreviewArgs {
     /// Update the gitignore cache
     #[arg(long = "update-cache")]
     pub update_cache: bool,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
 }
 
 impl super::Runnable for PreviewArgs {
@@ -24,26 +28,31 @@ impl super::Runnable for PreviewArgs {
             ));
         }
 
+        let source = GitignoreSource::resolve(&self.source)?;
         let mut cache_manager = CacheManager::new()?;
-        let cache = ensure_gitignore_cache(&mut cache_manager, self.update_cache)?;
+        let cache = ensure_gitignore_cache(&mut cache_manager, self.update_cache, &source)?;
 
         for template_name in &self.args {
-            preview_single_template(template_name, &cache)?;
+            preview_single_template(template_name, &cache, &source)?;
         }
 
         Ok(())
     }
 }
 
-fn preview_single_template(template: &str, cache: &super::Cache<String>) -> anyhow::Result<()> {
+fn preview_single_template(
+    template: &str,
+    cache: &super::Cache<String>,
+    source: &GitignoreSource,
+) -> anyhow::Result<()> {
     // normalize template if it has the .gitignore ext
     let template = template.strip_suffix(".gitignore").unwrap_or(template);
-    
+
     // Find the template path in cache
     let template_path = find_template_in_cache(template, cache)?;
 
     let fetcher = Fetcher::new();
-    let url = format!("{}/{}", GITHUB_RAW_BASE, template_path);
+    let url = source.template_url(template_path);
 
     let pb = progress::spinner(&format!("Fetching gitignore template: {}", template));
     let content = fetcher.fetch_content(&url)?;
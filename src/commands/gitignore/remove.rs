@@ -0,0 +1,62 @@
+use std::fs;
+use std::path::PathBuf;
+
+use colored::*;
+
+use crate::utils::file;
+use crate::utils::gitignore;
+
+#[derive(clap::Args, Debug)]
+pub struct RemoveArgs {
+    /// Template sections to remove (e.g., rust, python, global/windows)
+    #[arg(value_name = "TEMPLATE", required = true)]
+    pub templates: Vec<String>,
+
+    /// Directory containing the .gitignore file
+    #[arg(long, value_name = "DIR")]
+    pub dir: Option<PathBuf>,
+
+    /// File to remove the sections from (default: .gitignore)
+    #[arg(
+        long = "output",
+        short = 'o',
+        value_name = "FILENAME",
+        default_value = ".gitignore"
+    )]
+    pub output: String,
+}
+
+impl super::Runnable for RemoveArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        let dir = match &self.dir {
+            Some(dir) => dir.clone(),
+            None => file::find_repo_root()?,
+        };
+        let path = dir.join(&self.output);
+
+        let existing = fs::read_to_string(&path)
+            .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", path.display(), e))?;
+        let (content, missing) = gitignore::remove(&existing, &self.templates);
+
+        if !missing.is_empty() {
+            return Err(anyhow::anyhow!(
+                "No '# ===== <template> =====' section for {} in '{}'.",
+                missing.join(", "),
+                path.display()
+            ));
+        }
+
+        file::overwrite_file(&content, &path)?;
+        if !file::is_dry_run() {
+            for template in &self.templates {
+                println!(
+                    "{} Removed {} from {}",
+                    "✓".green(),
+                    template,
+                    path.display()
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,12 +1,19 @@
 use colored::*;
+use serde_yaml::Value;
 use std::path::{Path, PathBuf};
 
+use crate::commands::base::TemplateCategory;
+use crate::commands::pick::{self, PickCategory};
 use crate::utils::file;
+use crate::utils::lockfile;
 use crate::utils::manifest_navigator::ManifestNavigator;
+use crate::utils::picker;
 use crate::utils::progress;
 use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
+use crate::utils::yaml_keys;
 
-use super::GITHUB_RAW_BASE;
+use super::templates_base;
 
 const OUTPUT_BASE_PATH: &str = ".github";
 const OUTPUT: &str = "ISSUE_TEMPLATE";
@@ -34,12 +41,129 @@ pub struct AddArgs {
     /// Output file names for the templates (in order of templates)
     #[arg(short='o', long, value_name = "OUTPUT", num_args = 1.., requires = "templates")]
     pub output: Vec<String>,
+
+    #[command(flatten)]
+    pub metadata: MetadataArgs,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
+}
+
+/// Issue metadata that replaces the template's own when it is installed
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct MetadataArgs {
+    /// Label to apply to new issues, replacing the template's labels (can be repeated)
+    #[arg(long = "label", value_name = "LABEL")]
+    pub labels: Vec<String>,
+
+    /// User to assign new issues to, replacing the template's assignees (can be repeated)
+    #[arg(long = "assignee", value_name = "USER")]
+    pub assignees: Vec<String>,
+
+    /// Prefix for the issue title, e.g. "BUG: "
+    #[arg(long, value_name = "PREFIX")]
+    pub title_prefix: Option<String>,
+
+    /// Project to add new issues to, as OWNER/NUMBER (can be repeated)
+    #[arg(long = "project", value_name = "OWNER/NUMBER")]
+    pub projects: Vec<String>,
+}
+
+impl MetadataArgs {
+    fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+            && self.assignees.is_empty()
+            && self.title_prefix.is_none()
+            && self.projects.is_empty()
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if let Some(project) = self.projects.iter().find(|p| {
+            !p.split_once('/')
+                .is_some_and(|(owner, number)| !owner.is_empty() && number.parse::<u32>().is_ok())
+        }) {
+            return Err(anyhow::anyhow!(
+                "Invalid project '{}'. Expected OWNER/NUMBER, e.g. octo-org/1.",
+                project
+            ));
+        }
+        Ok(())
+    }
+
+    /// Set the requested top-level keys of an issue form.
+    /// Only their lines change; comments and formatting elsewhere are kept.
+    fn apply(&self, content: &str) -> anyhow::Result<String> {
+        if self.is_empty() {
+            return Ok(content.to_string());
+        }
+
+        let Value::Mapping(_) = serde_yaml::from_str(content)? else {
+            return Err(anyhow::anyhow!("The template is not an issue form."));
+        };
+
+        let mut updates = Vec::new();
+        if let Some(prefix) = &self.title_prefix {
+            updates.push(("title", Value::from(prefix.as_str())));
+        }
+        if !self.labels.is_empty() {
+            updates.push(("labels", string_list(&self.labels)));
+        }
+        if !self.assignees.is_empty() {
+            // GitHub expects logins, `@triage` is accepted for convenience
+            let assignees: Vec<String> = self
+                .assignees
+                .iter()
+                .map(|a| a.trim_start_matches('@').to_string())
+                .collect();
+            updates.push(("assignees", string_list(&assignees)));
+        }
+        if !self.projects.is_empty() {
+            updates.push(("projects", string_list(&self.projects)));
+        }
+
+        // New keys go before `body`, where GitHub's documentation places the metadata
+        let mut form = content.to_string();
+        for (key, value) in updates {
+            form = yaml_keys::set_key(&form, key, &value, Some("body"))?;
+        }
+        Ok(form)
+    }
+}
+
+fn string_list(items: &[String]) -> Value {
+    Value::Sequence(
+        items
+            .iter()
+            .map(|item| Value::from(item.as_str()))
+            .collect(),
+    )
 }
 
 impl super::Runnable for AddArgs {
     fn run(&self) -> anyhow::Result<()> {
+        if !self.all && self.templates.is_empty() && picker::is_interactive() {
+            let templates = pick::choose(PickCategory::Issue, &self.source)?;
+            if templates.is_empty() {
+                println!("No templates selected.");
+                return Ok(());
+            }
+            return Self {
+                templates,
+                dir: self.dir.clone(),
+                output: Vec::new(),
+                metadata: self.metadata.clone(),
+                source: self.source.clone(),
+                ..*self
+            }
+            .run();
+        }
+
+        self.metadata.validate()?;
+        let base = templates_base(&self.source)?;
+
         if self.all {
-            download_all_templates(self.dir.as_ref(), self.force)?;
+            download_all_templates(&base, self.dir.as_ref(), self.force, &self.metadata)?;
+            super::config::offer(self.dir.as_ref())?;
         } else if self.templates.is_empty() {
             return Err(anyhow::anyhow!(
                 "No issue template specified. Use `--all` or pass template names."
@@ -53,15 +177,24 @@ impl super::Runnable for AddArgs {
                 }
                 for (template_name, output_name) in self.templates.iter().zip(self.output.iter()) {
                     download_single_template(
+                        &base,
                         template_name,
                         self.dir.as_ref(),
                         self.force,
                         Some(output_name.clone()),
+                        &self.metadata,
                     )?;
                 }
             } else {
                 for template_name in &self.templates {
-                    download_single_template(template_name, self.dir.as_ref(), self.force, None)?;
+                    download_single_template(
+                        &base,
+                        template_name,
+                        self.dir.as_ref(),
+                        self.force,
+                        None,
+                        &self.metadata,
+                    )?;
                 }
             }
         }
@@ -71,8 +204,13 @@ impl super::Runnable for AddArgs {
 }
 
 // Helper functions
-fn download_all_templates(dir_path: Option<&PathBuf>, force: bool) -> anyhow::Result<()> {
-    let manifest_url = format!("{}/issue-templates/manifest.yml", GITHUB_RAW_BASE);
+fn download_all_templates(
+    base: &str,
+    dir_path: Option<&PathBuf>,
+    force: bool,
+    metadata: &MetadataArgs,
+) -> anyhow::Result<()> {
+    let manifest_url = format!("{}/manifest.yml", base);
     let manifest_navigator = ManifestNavigator::new(&manifest_url)?;
     let template_entries = manifest_navigator.list_entries()?;
 
@@ -85,7 +223,9 @@ fn download_all_templates(dir_path: Option<&PathBuf>, force: bool) -> anyhow::Re
             None => &entry.name,
         };
 
-        if let Err(e) = download_single_template(template_name, dir_path, force, None) {
+        if let Err(e) =
+            download_single_template(base, template_name, dir_path, force, None, metadata)
+        {
             eprintln!(
                 "{} Failed to add template '{}': {}",
                 "✗".red(),
@@ -118,10 +258,12 @@ fn download_all_templates(dir_path: Option<&PathBuf>, force: bool) -> anyhow::Re
 }
 
 fn download_single_template(
+    base: &str,
     template_name: &str,
     dir_path: Option<&PathBuf>,
     force: bool,
     output: Option<String>,
+    metadata: &MetadataArgs,
 ) -> anyhow::Result<()> {
     let fetcher = Fetcher::new();
 
@@ -131,7 +273,7 @@ fn download_single_template(
     } else {
         format!("{}.yml", template_name)
     };
-    let url = format!("{}/issue-templates/{}", GITHUB_RAW_BASE, template_file);
+    let url = format!("{}/{}", base, template_file);
 
     let msg = format!("Downloading issue template: {}", template_name);
     let pb = progress::spinner(&msg);
@@ -152,11 +294,21 @@ fn download_single_template(
         // Default: .github/ISSUE_TEMPLATE/<template_name>.yml
         let default_path = Path::new(OUTPUT_BASE_PATH).join(OUTPUT);
         dir_path
-            .map(|p| p.join(format!("{}", template_file)))
-            .unwrap_or_else(|| default_path.join(format!("{}", template_file)))
+            .map(|p| p.join(&template_file))
+            .unwrap_or_else(|| default_path.join(&template_file))
     };
 
-    file::save_file(&content, &dest_path, force)?;
+    // The upstream content stays the merge base, so `update` keeps the customised metadata
+    let customised = metadata.apply(&content)?;
+    file::save_file(&customised, &dest_path, force)?;
+    lockfile::record(
+        &dest_path,
+        TemplateCategory::Issue,
+        template_name,
+        &url,
+        &content,
+        &customised,
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,274 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use colored::*;
+use serde_yaml::{Mapping, Value};
+
+use crate::utils::file;
+use crate::utils::picker;
+use crate::utils::prompt::{ask, confirm};
+use crate::utils::yaml_keys;
+
+const CONFIG_PATH: &str = ".github/ISSUE_TEMPLATE/config.yml";
+const CONFIG_FILE: &str = "config.yml";
+
+// Command to write the issue template chooser configuration
+
+#[derive(clap::Args, Debug)]
+pub struct ConfigArgs {
+    /// Allow (true) or disallow (false) opening issues without a template
+    #[arg(long, value_name = "BOOL")]
+    pub blank_issues: Option<bool>,
+
+    /// Add a contact link, or update the one with the same name (can be repeated)
+    #[arg(long = "contact-link", value_name = "NAME|URL|ABOUT")]
+    pub contact_links: Vec<String>,
+
+    /// Remove the contact link with this name (can be repeated)
+    #[arg(long, value_name = "NAME")]
+    pub remove_link: Vec<String>,
+
+    /// Directory holding the issue templates
+    #[arg(long, value_name = "DIR")]
+    pub dir: Option<PathBuf>,
+}
+
+/// A `contact_links` entry of the issue template chooser
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContactLink {
+    pub name: String,
+    pub url: String,
+    pub about: String,
+}
+
+impl ContactLink {
+    /// Parse `NAME|URL|ABOUT`
+    fn parse(spec: &str) -> anyhow::Result<Self> {
+        let parts: Vec<&str> = spec.splitn(3, '|').map(str::trim).collect();
+        let [name, url, about] = parts[..] else {
+            return Err(anyhow!(
+                "Invalid contact link '{}'. Expected NAME|URL|ABOUT.",
+                spec
+            ));
+        };
+        Self::new(name, url, about)
+    }
+
+    fn new(name: &str, url: &str, about: &str) -> anyhow::Result<Self> {
+        if name.is_empty() || about.is_empty() {
+            return Err(anyhow!("A contact link needs a name and an about text."));
+        }
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return Err(anyhow!(
+                "Contact link URL '{}' must start with http:// or https://.",
+                url
+            ));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            url: url.to_string(),
+            about: about.to_string(),
+        })
+    }
+}
+
+impl super::Runnable for ConfigArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        let path = config_path(self.dir.as_ref());
+        let mut config = read_config(&path)?;
+
+        let nothing_passed = self.blank_issues.is_none()
+            && self.contact_links.is_empty()
+            && self.remove_link.is_empty();
+        if nothing_passed {
+            if picker::is_interactive() {
+                return prompt_and_write(&path, config);
+            }
+            if file::resolve_output_path(&path)?.exists() {
+                return Err(anyhow!(
+                    "Nothing to change. Pass --blank-issues, --contact-link or --remove-link."
+                ));
+            }
+        }
+
+        // A new file states GitHub's default so it is easy to flip later
+        if config.is_empty() {
+            set_blank_issues(&mut config, true);
+        }
+        if let Some(enabled) = self.blank_issues {
+            set_blank_issues(&mut config, enabled);
+        }
+
+        for name in &self.remove_link {
+            if !remove_contact_link(&mut config, name) {
+                return Err(anyhow!(
+                    "No contact link named '{}' in '{}'.",
+                    name,
+                    path.display()
+                ));
+            }
+        }
+        for spec in &self.contact_links {
+            set_contact_link(&mut config, ContactLink::parse(spec)?);
+        }
+
+        write_config(&path, &config)
+    }
+}
+
+/// Offer to create `config.yml` after every issue template has been added.
+/// Asks on a terminal; otherwise only points at `issue config`.
+pub(crate) fn offer(dir: Option<&PathBuf>) -> anyhow::Result<()> {
+    let path = config_path(dir);
+    if file::resolve_output_path(&path)?.exists() || file::is_dry_run() {
+        return Ok(());
+    }
+
+    if !picker::is_interactive() {
+        println!(
+            "\nRun `gh-templates issue config` to add {} (blank issues, contact links).",
+            CONFIG_FILE
+        );
+        return Ok(());
+    }
+
+    if confirm(&format!("Also create {}?", path.display()), false) {
+        prompt_and_write(&path, Mapping::new())?;
+    }
+    Ok(())
+}
+
+fn config_path(dir: Option<&PathBuf>) -> PathBuf {
+    match dir {
+        Some(dir) => dir.join(CONFIG_FILE),
+        None => PathBuf::from(CONFIG_PATH),
+    }
+}
+
+/// The existing configuration, or an empty one when the file does not exist yet
+fn read_config(path: &Path) -> anyhow::Result<Mapping> {
+    let resolved = file::resolve_output_path(path)?;
+    if !resolved.exists() {
+        return Ok(Mapping::new());
+    }
+
+    let content = fs::read_to_string(&resolved)?;
+    if content.trim().is_empty() {
+        return Ok(Mapping::new());
+    }
+    match serde_yaml::from_str(&content) {
+        Ok(Value::Mapping(config)) => Ok(config),
+        Ok(_) => Err(anyhow!("'{}' is not a YAML mapping.", path.display())),
+        Err(e) => Err(anyhow!("Failed to parse '{}': {}", path.display(), e)),
+    }
+}
+
+/// Write `config`; an existing file only has the lines of changed keys
+/// rewritten, so its comments and formatting are kept
+fn write_config(path: &Path, config: &Mapping) -> anyhow::Result<()> {
+    let resolved = file::resolve_output_path(path)?;
+
+    if resolved.exists() {
+        let mut content = fs::read_to_string(&resolved)?;
+        let current = read_config(path)?;
+        for (key, value) in config {
+            if current.get(key) == Some(value) {
+                continue;
+            }
+            let Some(key) = key.as_str() else { continue };
+            content = yaml_keys::set_key(&content, key, value, None)?;
+        }
+        file::overwrite_file(&content, &resolved)?;
+        if !file::is_dry_run() {
+            println!("{} Updated {}", "✓".green(), path.display());
+        }
+        Ok(())
+    } else {
+        file::save_file(&serde_yaml::to_string(config)?, path, false)
+    }
+}
+
+fn set_blank_issues(config: &mut Mapping, enabled: bool) {
+    config.insert("blank_issues_enabled".into(), Value::Bool(enabled));
+}
+
+fn contact_links(config: &mut Mapping) -> &mut Vec<Value> {
+    let links = config
+        .entry("contact_links".into())
+        .or_insert_with(|| Value::Sequence(Vec::new()));
+    if !links.is_sequence() {
+        *links = Value::Sequence(Vec::new());
+    }
+    links
+        .as_sequence_mut()
+        .expect("contact_links is a sequence")
+}
+
+fn has_name(entry: &Value, name: &str) -> bool {
+    entry
+        .get("name")
+        .and_then(Value::as_str)
+        .is_some_and(|n| n.trim().eq_ignore_ascii_case(name.trim()))
+}
+
+/// Update the link with the same name in place, or append it
+fn set_contact_link(config: &mut Mapping, link: ContactLink) {
+    let links = contact_links(config);
+
+    let existing = links.iter().position(|entry| has_name(entry, &link.name));
+    match existing.and_then(|index| links[index].as_mapping_mut()) {
+        Some(entry) => {
+            entry.insert("url".into(), link.url.into());
+            entry.insert("about".into(), link.about.into());
+        }
+        None => {
+            let mut entry = Mapping::new();
+            entry.insert("name".into(), link.name.into());
+            entry.insert("url".into(), link.url.into());
+            entry.insert("about".into(), link.about.into());
+            links.push(Value::Mapping(entry));
+        }
+    }
+}
+
+/// Remove the link with this name; false when there is none
+fn remove_contact_link(config: &mut Mapping, name: &str) -> bool {
+    let Some(links) = config
+        .get_mut("contact_links")
+        .and_then(Value::as_sequence_mut)
+    else {
+        return false;
+    };
+
+    let before = links.len();
+    links.retain(|entry| !has_name(entry, name));
+    links.len() != before
+}
+
+/// Ask for the blank issue setting and any new contact links, then write the file
+fn prompt_and_write(path: &Path, mut config: Mapping) -> anyhow::Result<()> {
+    let current = config
+        .get("blank_issues_enabled")
+        .and_then(Value::as_bool)
+        .unwrap_or(true);
+    set_blank_issues(
+        &mut config,
+        confirm("Allow issues without a template?", current),
+    );
+
+    loop {
+        let name = ask("Contact link name (leave empty to finish)");
+        if name.is_empty() {
+            break;
+        }
+        let url = ask("  URL");
+        let about = ask("  About");
+        match ContactLink::new(&name, &url, &about) {
+            Ok(link) => set_contact_link(&mut config, link),
+            Err(e) => eprintln!("{} {}", "✗".red(), e),
+        }
+    }
+
+    write_config(path, &config)
+}
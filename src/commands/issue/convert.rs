@@ -0,0 +1,396 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::utils::file;
+use crate::utils::issue_form::{
+    BodyItem, CheckboxOption, CheckboxesAttributes, CheckboxesItem, IssueForm, MarkdownAttributes,
+    MarkdownItem, StringList, TextareaAttributes, TextareaItem,
+};
+
+use super::parse_form;
+
+// Command to convert between Markdown issue templates and YAML issue forms
+
+#[derive(clap::Args, Debug)]
+pub struct ConvertArgs {
+    /// Markdown template (.md) to turn into an issue form, or issue form (.yml) to turn into Markdown
+    #[arg(value_name = "PATH", required = true)]
+    pub paths: Vec<PathBuf>,
+
+    /// Output file (default: next to the input, with the other extension)
+    #[arg(short = 'o', long, value_name = "OUTPUT")]
+    pub output: Option<PathBuf>,
+
+    /// Overwrite output files that already exist
+    #[arg(long)]
+    pub force: bool,
+}
+
+impl super::Runnable for ConvertArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        if self.output.is_some() && self.paths.len() > 1 {
+            return Err(anyhow::anyhow!(
+                "--output can only be used when converting a single file."
+            ));
+        }
+
+        for path in &self.paths {
+            let content = fs::read_to_string(path)
+                .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", path.display(), e))?;
+            let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("issue");
+
+            let (converted, ext) = match path.extension().and_then(|e| e.to_str()) {
+                Some("md") => {
+                    let form = from_markdown(&content, stem)?;
+                    (serde_yaml::to_string(&form)?, "yml")
+                }
+                Some("yml") | Some("yaml") => {
+                    let form = parse_form(&content, &path.display().to_string())?;
+                    (to_markdown(&form), "md")
+                }
+                _ => {
+                    return Err(anyhow::anyhow!(
+                        "Cannot convert '{}': expected a .md template or a .yml issue form.",
+                        path.display()
+                    ));
+                }
+            };
+
+            let dest = self
+                .output
+                .clone()
+                .unwrap_or_else(|| path.with_extension(ext));
+            file::save_file(&converted, &dest, self.force)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Front matter of a Markdown issue template
+#[derive(Debug, Default, Deserialize)]
+struct FrontMatter {
+    name: Option<String>,
+    about: Option<String>,
+    title: Option<String>,
+    labels: Option<StringList>,
+    assignees: Option<StringList>,
+}
+
+/// A headed part of a Markdown template
+struct Section {
+    heading: String,
+    body: String,
+}
+
+/// Build an issue form from a Markdown template: front matter becomes the
+/// metadata, text before the first heading a markdown block, and every
+/// heading a field. Sections that only hold task list items become
+/// checkboxes, the others textareas with the section's HTML comments as
+/// description and its text as placeholder.
+fn from_markdown(content: &str, fallback_name: &str) -> anyhow::Result<IssueForm> {
+    let (front_matter, body) = split_front_matter(content)?;
+    let (intro, sections) = split_sections(body);
+
+    let name = non_empty(front_matter.name).unwrap_or_else(|| fallback_name.to_string());
+    let description = non_empty(front_matter.about).unwrap_or_else(|| name.clone());
+
+    let mut items = Vec::new();
+    if !intro.trim().is_empty() {
+        items.push(BodyItem::Markdown(MarkdownItem {
+            attributes: MarkdownAttributes {
+                value: intro.trim().to_string(),
+            },
+        }));
+    }
+
+    let sections = if sections.is_empty() {
+        // Without headings the whole template is one free-text field
+        vec![Section {
+            heading: "Description".to_string(),
+            body: String::new(),
+        }]
+    } else {
+        sections
+    };
+
+    let mut ids = HashSet::new();
+    let mut labels = HashSet::new();
+    for section in sections {
+        let label = unique(&section.heading, &mut labels, |base, n| {
+            format!("{} ({})", base, n)
+        });
+        let id = unique(&slug(&section.heading), &mut ids, |base, n| {
+            format!("{}-{}", base, n)
+        });
+        items.push(section_item(id, label, &section.body));
+    }
+
+    Ok(IssueForm {
+        name,
+        description,
+        // A title prefix keeps its trailing space
+        title: front_matter.title.filter(|t| !t.trim().is_empty()),
+        labels: non_empty_list(front_matter.labels),
+        assignees: non_empty_list(front_matter.assignees),
+        projects: None,
+        issue_type: None,
+        body: items,
+    })
+}
+
+fn section_item(id: String, label: String, body: &str) -> BodyItem {
+    let comment = Regex::new(r"(?s)<!--(.*?)-->").unwrap();
+    let task = Regex::new(r"^\s*[-*]\s+\[[ xX]\]\s+(.+)$").unwrap();
+
+    let comments: Vec<String> = comment
+        .captures_iter(body)
+        .map(|c| c[1].trim().to_string())
+        .filter(|c| !c.is_empty())
+        .collect();
+    let description = (!comments.is_empty()).then(|| comments.join("\n"));
+    let text = comment.replace_all(body, "").trim().to_string();
+
+    let tasks: Vec<CheckboxOption> = text
+        .lines()
+        .filter_map(|line| task.captures(line))
+        .map(|c| CheckboxOption {
+            label: c[1].trim().to_string(),
+            required: None,
+        })
+        .collect();
+    let only_tasks = !tasks.is_empty()
+        && text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .all(|line| task.is_match(line));
+
+    if only_tasks {
+        return BodyItem::Checkboxes(CheckboxesItem {
+            id: Some(id),
+            attributes: CheckboxesAttributes {
+                label,
+                description,
+                options: tasks,
+            },
+            validations: None,
+        });
+    }
+
+    BodyItem::Textarea(TextareaItem {
+        id: Some(id),
+        attributes: TextareaAttributes {
+            label,
+            description,
+            placeholder: (!text.is_empty()).then_some(text),
+            value: None,
+            render: None,
+        },
+        validations: None,
+    })
+}
+
+/// Write an issue form as a Markdown template: one `###` section per field,
+/// descriptions as HTML comments and placeholders or values as text.
+/// Dropdown and checkbox options become task lists; validations are lost.
+fn to_markdown(form: &IssueForm) -> String {
+    let mut out = String::from("---\n");
+    out.push_str(&format!("name: {}\n", yaml_scalar(&form.name)));
+    out.push_str(&format!("about: {}\n", yaml_scalar(&form.description)));
+    if let Some(title) = &form.title {
+        out.push_str(&format!("title: {}\n", yaml_scalar(title)));
+    }
+    if let Some(labels) = &form.labels {
+        out.push_str(&format!(
+            "labels: {}\n",
+            yaml_scalar(&labels.items().join(", "))
+        ));
+    }
+    if let Some(assignees) = &form.assignees {
+        out.push_str(&format!(
+            "assignees: {}\n",
+            yaml_scalar(&assignees.items().join(", "))
+        ));
+    }
+    out.push_str("---\n");
+
+    for item in &form.body {
+        out.push('\n');
+        if let BodyItem::Markdown(markdown) = item {
+            out.push_str(&format!("{}\n", markdown.attributes.value.trim_end()));
+            continue;
+        }
+
+        out.push_str(&format!("### {}\n\n", item.label().unwrap_or_default()));
+        let (description, text) = match item {
+            BodyItem::Textarea(i) => (
+                i.attributes.description.as_deref(),
+                i.attributes
+                    .value
+                    .as_ref()
+                    .or(i.attributes.placeholder.as_ref())
+                    .cloned(),
+            ),
+            BodyItem::Input(i) => (
+                i.attributes.description.as_deref(),
+                i.attributes
+                    .value
+                    .as_ref()
+                    .or(i.attributes.placeholder.as_ref())
+                    .cloned(),
+            ),
+            BodyItem::Dropdown(i) => (
+                i.attributes.description.as_deref(),
+                Some(task_list(i.attributes.options.iter())),
+            ),
+            BodyItem::Checkboxes(i) => (
+                i.attributes.description.as_deref(),
+                Some(task_list(i.attributes.options.iter().map(|o| &o.label))),
+            ),
+            BodyItem::Markdown(_) => (None, None),
+        };
+
+        if let Some(description) = description {
+            out.push_str(&format!("<!-- {} -->\n", description.trim()));
+        }
+        if let Some(text) = text.filter(|t| !t.trim().is_empty()) {
+            out.push_str(&format!("{}\n", text.trim_end()));
+        }
+    }
+
+    out
+}
+
+fn task_list<'a>(options: impl Iterator<Item = &'a String>) -> String {
+    options
+        .map(|option| format!("- [ ] {}", option))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Split `---` delimited front matter from the template body
+fn split_front_matter(content: &str) -> anyhow::Result<(FrontMatter, &str)> {
+    let Some(rest) = content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))
+    else {
+        return Ok((FrontMatter::default(), content));
+    };
+
+    let Some(end) = rest
+        .match_indices("\n---")
+        .map(|(index, _)| index)
+        .find(|&index| {
+            let after = &rest[index + 4..];
+            after.is_empty() || after.starts_with('\n') || after.starts_with("\r\n")
+        })
+    else {
+        return Err(anyhow::anyhow!(
+            "The front matter is not closed with '---'."
+        ));
+    };
+
+    let yaml = &rest[..end];
+    let body = rest[end + 4..].trim_start_matches(['\r', '\n']);
+    let front_matter = if yaml.trim().is_empty() {
+        FrontMatter::default()
+    } else {
+        serde_yaml::from_str(yaml)
+            .map_err(|e| anyhow::anyhow!("Failed to parse the front matter: {}", e))?
+    };
+    Ok((front_matter, body))
+}
+
+/// Split the body at `#` headings and lines that are entirely bold,
+/// ignoring anything inside code fences
+fn split_sections(body: &str) -> (String, Vec<Section>) {
+    let heading = Regex::new(r"^#{1,6}\s+(.+?)\s*#*\s*$").unwrap();
+    let bold = Regex::new(r"^\*\*(.+?)\*\*:?\s*$").unwrap();
+
+    let mut intro = String::new();
+    let mut sections: Vec<Section> = Vec::new();
+    let mut in_fence = false;
+
+    for line in body.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+        let title = if in_fence {
+            None
+        } else {
+            heading
+                .captures(line)
+                .or_else(|| bold.captures(line.trim()))
+                .map(|c| c[1].trim().to_string())
+        };
+
+        match (title, sections.last_mut()) {
+            (Some(title), _) => sections.push(Section {
+                heading: title,
+                body: String::new(),
+            }),
+            (None, Some(section)) => {
+                section.body.push_str(line);
+                section.body.push('\n');
+            }
+            (None, None) => {
+                intro.push_str(line);
+                intro.push('\n');
+            }
+        }
+    }
+
+    (intro, sections)
+}
+
+/// Lowercase id made of letters, digits and `-`
+fn slug(text: &str) -> String {
+    let slug = text
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        "field".to_string()
+    } else {
+        slug
+    }
+}
+
+/// `base`, or `base` numbered by `numbered` when it was seen before (case-insensitively)
+fn unique(
+    base: &str,
+    seen: &mut HashSet<String>,
+    numbered: impl Fn(&str, usize) -> String,
+) -> String {
+    let mut candidate = base.to_string();
+    let mut n = 2;
+    while !seen.insert(candidate.to_lowercase()) {
+        candidate = numbered(base, n);
+        n += 1;
+    }
+    candidate
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn non_empty_list(list: Option<StringList>) -> Option<StringList> {
+    let items = list?.items();
+    (!items.is_empty()).then_some(StringList::List(items))
+}
+
+/// A front matter value, quoted when plain YAML would read it differently
+fn yaml_scalar(value: &str) -> String {
+    serde_yaml::to_string(value)
+        .map(|s| s.trim_end().to_string())
+        .unwrap_or_else(|_| format!("{:?}", value))
+}
@@ -1,29 +1,51 @@
 use colored::*;
 
+use crate::commands::base::TemplateCategory;
 use crate::utils::get_comment;
 use crate::utils::manifest_navigator::ManifestNavigator;
+use crate::utils::output::{print_records, FormatArgs, TemplateRecord};
 use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
 
-use super::GITHUB_RAW_BASE;
+use super::templates_base;
 
 #[derive(clap::Args)]
 pub struct ListArgs {
-    // You can add options here if needed in the future
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    #[command(flatten)]
+    pub format: FormatArgs,
 }
 
 impl super::Runnable for ListArgs {
     fn run(&self) -> anyhow::Result<()> {
-        list_all_templates()
+        let templates = list_all_templates(&templates_base(&self.source)?)?;
+
+        if !self.format.is_text() {
+            return print_records(&templates, self.format.format, TemplateRecord::COLUMNS);
+        }
+
+        for template in templates {
+            println!(
+                "{} {} - {}",
+                ">".green(),
+                template.name,
+                template.description.unwrap_or_default()
+            );
+        }
+        Ok(())
     }
 }
 
-fn list_all_templates() -> anyhow::Result<()> {
+pub(super) fn list_all_templates(base: &str) -> anyhow::Result<Vec<TemplateRecord>> {
     let fetcher = Fetcher::new();
 
-    let manifest_url = format!("{}/issue-templates/manifest.yml", GITHUB_RAW_BASE);
+    let manifest_url = format!("{}/manifest.yml", base);
     let manifest_navigator = ManifestNavigator::new(&manifest_url)?;
     let template_entries = manifest_navigator.list_entries()?;
 
+    let mut templates = Vec::new();
     for entry in template_entries {
         let file_url = &entry.full_url;
         let extension = std::path::Path::new(file_url)
@@ -43,12 +65,12 @@ fn list_all_templates() -> anyhow::Result<()> {
             .and_then(|stem| stem.to_str())
             .unwrap_or("");
 
-        println!(
-            "{} {} - {}",
-            ">".green(),
-            file_name,
-            comment.unwrap_or_default()
-        );
+        templates.push(TemplateRecord {
+            name: file_name.to_string(),
+            category: TemplateCategory::Issue.config_key(),
+            url: file_url.clone(),
+            description: comment,
+        });
     }
-    Ok(())
+    Ok(templates)
 }
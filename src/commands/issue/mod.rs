@@ -1,10 +1,16 @@
 use clap::Subcommand;
 
-use crate::commands::base::Runnable;
+use crate::commands::base::{Runnable, TemplateCategory};
+use crate::utils::issue_form::{self, IssueForm};
+use crate::utils::picker::Candidate;
+use crate::utils::source::SourceArgs;
 
-mod add;
+pub(crate) mod add;
+mod config;
+mod convert;
 mod list;
 mod preview;
+mod validate;
 
 // Global constants - these can stay in the main module file
 const GITHUB_RAW_BASE: &str =
@@ -14,18 +20,57 @@ const GITHUB_RAW_BASE: &str =
 pub enum Command {
     /// Add one or more Issue templates to the repository
     Add(add::AddArgs),
+    /// Create or edit the issue template chooser (.github/ISSUE_TEMPLATE/config.yml)
+    Config(config::ConfigArgs),
+    /// Convert Markdown issue templates to issue forms and back
+    Convert(convert::ConvertArgs),
     /// List available Issue templates
     List(list::ListArgs),
     /// Preview a specific Issue template
     Preview(preview::PreviewArgs),
+    /// Check issue forms against GitHub's issue form schema
+    Validate(validate::ValidateArgs),
 }
 
 impl Command {
     pub fn execute(&self) -> anyhow::Result<()> {
         match self {
             Command::Add(args) => args.run(),
+            Command::Config(args) => args.run(),
+            Command::Convert(args) => args.run(),
             Command::List(args) => args.run(),
             Command::Preview(args) => args.run(),
+            Command::Validate(args) => args.run(),
         }
     }
 }
+
+/// Base URL of the issue-templates directory for the selected source
+fn templates_base(source: &SourceArgs) -> anyhow::Result<String> {
+    source.resolve(
+        TemplateCategory::Issue,
+        &format!("{}/issue-templates", GITHUB_RAW_BASE),
+    )
+}
+
+/// Templates offered by `pick` and by `issue add` without arguments
+pub(crate) fn pick_candidates(source: &SourceArgs) -> anyhow::Result<Vec<Candidate>> {
+    let records = list::list_all_templates(&templates_base(source)?)?;
+
+    Ok(records
+        .into_iter()
+        .map(|record| Candidate::from_record(record, "yml"))
+        .collect())
+}
+
+/// Parse an issue form, listing every schema problem when it is not valid
+fn parse_form(content: &str, name: &str) -> anyhow::Result<IssueForm> {
+    issue_form::parse(content).map_err(|problems| {
+        let problems: Vec<String> = problems.iter().map(|p| format!("  {}", p)).collect();
+        anyhow::anyhow!(
+            "'{}' is not a valid issue form:\n{}",
+            name,
+            problems.join("\n")
+        )
+    })
+}
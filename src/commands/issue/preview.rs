@@ -1,13 +1,27 @@
+use colored::*;
+
+use crate::utils::issue_form::{BodyItem, IssueForm};
 use crate::utils::pretty_print;
 use crate::utils::progress;
 use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
+
+use super::{parse_form, templates_base};
 
-use super::GITHUB_RAW_BASE;
+/// Width of the rules and boxes drawn by `--render`
+const RENDER_WIDTH: usize = 60;
 
 #[derive(clap::Args)]
 pub struct PreviewArgs {
     #[arg(allow_hyphen_values = true)]
     pub templates: Vec<String>,
+
+    /// Draw the issue form as GitHub shows it instead of printing its YAML
+    #[arg(long)]
+    pub render: bool,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
 }
 
 impl super::Runnable for PreviewArgs {
@@ -18,17 +32,18 @@ impl super::Runnable for PreviewArgs {
             ));
         }
 
+        let base = templates_base(&self.source)?;
         for template_name in &self.templates {
-            preview_single_template(template_name)?;
+            preview_single_template(&base, template_name, self.render)?;
         }
 
         Ok(())
     }
 }
 
-fn preview_single_template(template: &str) -> anyhow::Result<()> {
+fn preview_single_template(base: &str, template: &str, render: bool) -> anyhow::Result<()> {
     let fetcher = Fetcher::new();
-    let url = format!("{}/issue-templates/{}.yml", GITHUB_RAW_BASE, template);
+    let url = format!("{}/{}.yml", base, template);
 
     let pb = progress::spinner(&format!("Fetching issue template: {}", template));
     let content = fetcher.fetch_content(&url)?;
@@ -36,6 +51,186 @@ fn preview_single_template(template: &str) -> anyhow::Result<()> {
     pb.set_message(msg);
     pb.finish_and_clear();
 
-    pretty_print::print_highlighted("yml", &content);
+    if !render {
+        pretty_print::print_highlighted("yml", &content);
+        return Ok(());
+    }
+
+    let form = parse_form(&content, template)?;
+    print!("{}", render_form(&form));
     Ok(())
 }
+
+/// Draw the form the way GitHub's "new issue" page lays it out
+fn render_form(form: &IssueForm) -> String {
+    let mut out = String::new();
+    let rule = "─".repeat(RENDER_WIDTH);
+
+    out.push_str(&format!("{}\n", form.name.bold()));
+    out.push_str(&format!("{}\n\n", form.description.dimmed()));
+
+    let title = form.title.as_deref().unwrap_or_default();
+    out.push_str(&format!("{}\n", "Title".bold()));
+    out.push_str(&boxed(&[title.to_string()], title.is_empty()));
+
+    let metadata = [
+        ("Labels", form.labels.as_ref()),
+        ("Assignees", form.assignees.as_ref()),
+        ("Projects", form.projects.as_ref()),
+    ];
+    for (name, list) in metadata {
+        if let Some(list) = list {
+            out.push_str(&format!("{:<10} {}\n", name, list.items().join(", ")));
+        }
+    }
+    if let Some(issue_type) = &form.issue_type {
+        out.push_str(&format!("{:<10} {}\n", "Type", issue_type));
+    }
+    out.push_str(&format!("{}\n", rule.dimmed()));
+
+    for item in &form.body {
+        out.push('\n');
+        render_item(item, &mut out);
+    }
+
+    out.push('\n');
+    out.push_str(&format!("{}\n", rule.dimmed()));
+    out
+}
+
+fn render_item(item: &BodyItem, out: &mut String) {
+    if let BodyItem::Markdown(markdown) = item {
+        for line in markdown.attributes.value.trim_end().lines() {
+            out.push_str(&format!("{}\n", line.dimmed()));
+        }
+        return;
+    }
+
+    let label = item.label().unwrap_or_default();
+    if item.is_required() {
+        out.push_str(&format!("{} {}\n", label.bold(), "*".red()));
+    } else {
+        out.push_str(&format!("{}\n", label.bold()));
+    }
+
+    let description = match item {
+        BodyItem::Textarea(i) => i.attributes.description.as_deref(),
+        BodyItem::Input(i) => i.attributes.description.as_deref(),
+        BodyItem::Dropdown(i) => i.attributes.description.as_deref(),
+        BodyItem::Checkboxes(i) => i.attributes.description.as_deref(),
+        BodyItem::Markdown(_) => None,
+    };
+    if let Some(description) = description {
+        for line in description.trim_end().lines() {
+            out.push_str(&format!("{}\n", line.dimmed()));
+        }
+    }
+
+    match item {
+        BodyItem::Textarea(textarea) => {
+            let attributes = &textarea.attributes;
+            if let Some(language) = &attributes.render {
+                out.push_str(&format!(
+                    "{}\n",
+                    format!("(rendered as {})", language).dimmed()
+                ));
+            }
+            out.push_str(&field(
+                attributes.value.as_deref(),
+                attributes.placeholder.as_deref(),
+                3,
+            ));
+        }
+        BodyItem::Input(input) => {
+            let attributes = &input.attributes;
+            out.push_str(&field(
+                attributes.value.as_deref(),
+                attributes.placeholder.as_deref(),
+                1,
+            ));
+        }
+        BodyItem::Dropdown(dropdown) => {
+            let attributes = &dropdown.attributes;
+            let hint = if attributes.multiple.unwrap_or(false) {
+                "Selections: (select one or more)"
+            } else {
+                "Selection: (select one)"
+            };
+            out.push_str(&format!("▾ {}\n", hint.dimmed()));
+            for (index, option) in attributes.options.iter().enumerate() {
+                let mark = if attributes.default == Some(index) {
+                    "●"
+                } else {
+                    "○"
+                };
+                out.push_str(&format!("  {} {}\n", mark, option));
+            }
+        }
+        BodyItem::Checkboxes(checkboxes) => {
+            for option in &checkboxes.attributes.options {
+                if option.required.unwrap_or(false) {
+                    out.push_str(&format!("[ ] {} {}\n", option.label, "*".red()));
+                } else {
+                    out.push_str(&format!("[ ] {}\n", option.label));
+                }
+            }
+        }
+        BodyItem::Markdown(_) => {}
+    }
+}
+
+/// A text box showing the prefilled value, or else the dimmed placeholder
+fn field(value: Option<&str>, placeholder: Option<&str>, min_lines: usize) -> String {
+    let (text, is_placeholder) = match (value, placeholder) {
+        (Some(value), _) if !value.is_empty() => (value, false),
+        (_, Some(placeholder)) => (placeholder, true),
+        _ => ("", true),
+    };
+
+    let mut lines: Vec<String> = text.trim_end().lines().map(str::to_string).collect();
+    while lines.len() < min_lines {
+        lines.push(String::new());
+    }
+    boxed(&lines, is_placeholder)
+}
+
+fn boxed(lines: &[String], dimmed: bool) -> String {
+    let width = RENDER_WIDTH - 4;
+    let mut out = format!("┌{}┐\n", "─".repeat(RENDER_WIDTH - 2));
+    for shown in lines.iter().flat_map(|line| wrap(line, width)) {
+        let padding = width.saturating_sub(shown.chars().count());
+        let shown = if dimmed {
+            shown.dimmed().to_string()
+        } else {
+            shown
+        };
+        out.push_str(&format!("│ {}{} │\n", shown, " ".repeat(padding)));
+    }
+    out.push_str(&format!("└{}┘\n", "─".repeat(RENDER_WIDTH - 2)));
+    out
+}
+
+/// Split `line` at spaces into pieces of at most `width` characters;
+/// words longer than `width` are cut
+fn wrap(line: &str, width: usize) -> Vec<String> {
+    let mut pieces = Vec::new();
+    let mut current = String::new();
+
+    for word in line.split(' ') {
+        let needed = current.chars().count() + word.chars().count() + 1;
+        if !current.is_empty() && needed > width {
+            pieces.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+        while current.chars().count() > width {
+            let rest = current.chars().skip(width).collect();
+            pieces.push(current.chars().take(width).collect());
+            current = rest;
+        }
+    }
+    pieces.push(current);
+    pieces
+}
@@ -0,0 +1,98 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use colored::*;
+
+use crate::utils::file;
+use crate::utils::issue_form;
+
+const ISSUE_TEMPLATE_DIR: &str = ".github/ISSUE_TEMPLATE";
+
+/// Files in the issue template directory that are not issue forms
+const NON_FORM_FILES: &[&str] = &["config.yml", "config.yaml"];
+
+// Command to validate issue forms
+
+#[derive(clap::Args, Debug)]
+pub struct ValidateArgs {
+    /// Issue form file or directory to validate (default: .github/ISSUE_TEMPLATE)
+    #[arg(value_name = "PATH")]
+    pub paths: Vec<PathBuf>,
+}
+
+impl super::Runnable for ValidateArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        let paths = if self.paths.is_empty() {
+            vec![file::find_repo_root()?.join(ISSUE_TEMPLATE_DIR)]
+        } else {
+            self.paths.clone()
+        };
+
+        let mut files = Vec::new();
+        for path in &paths {
+            collect_forms(path, &mut files)?;
+        }
+
+        if files.is_empty() {
+            return Err(anyhow::anyhow!("No issue forms (*.yml) found to validate."));
+        }
+
+        let mut invalid = 0;
+        for path in &files {
+            let content = fs::read_to_string(path)?;
+            let problems = issue_form::validate(&content);
+
+            if problems.is_empty() {
+                println!("{} {}", "✓".green(), path.display());
+                continue;
+            }
+
+            invalid += 1;
+            for problem in problems {
+                match problem.line {
+                    Some(line) => eprintln!(
+                        "{} {}:{}: {}",
+                        "✗".red(),
+                        path.display(),
+                        line,
+                        problem.message
+                    ),
+                    None => eprintln!("{} {}: {}", "✗".red(), path.display(), problem.message),
+                }
+            }
+        }
+
+        if invalid > 0 {
+            return Err(anyhow::anyhow!(
+                "{} of {} issue form(s) are invalid.",
+                invalid,
+                files.len()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `path` itself when it is a file, or the issue forms directly inside it
+fn collect_forms(path: &Path, files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    if path.is_file() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    let entries = fs::read_dir(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", path.display(), e))?;
+    let mut forms: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| {
+            let is_yaml = p
+                .extension()
+                .is_some_and(|ext| ext == "yml" || ext == "yaml");
+            let name = p.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+            p.is_file() && is_yaml && !NON_FORM_FILES.contains(&name)
+        })
+        .collect();
+    forms.sort();
+    files.extend(forms);
+    Ok(())
+}
@@ -0,0 +1,603 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use colored::*;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::utils::detect;
+use crate::utils::file;
+use crate::utils::output::{print_records, FormatArgs, OutputFormat};
+use crate::utils::spdx::{self, Status};
+
+// Command to check dependency licenses against the project license
+
+#[derive(clap::Args, Debug)]
+pub struct CheckArgs {
+    /// Project license to check against (default: the one declared in Cargo.toml, package.json or pyproject.toml)
+    #[arg(long, value_name = "SPDX")]
+    pub license: Option<String>,
+
+    /// Output of `cargo metadata --format-version 1`, for the licenses of the crates in Cargo.lock
+    #[arg(long, value_name = "FILE")]
+    pub cargo_metadata: Option<PathBuf>,
+
+    /// site-packages directory of the Python environment (default: the one in .venv or venv)
+    #[arg(long, value_name = "DIR")]
+    pub site_packages: Option<PathBuf>,
+
+    /// Also check npm dev dependencies
+    #[arg(long)]
+    pub include_dev: bool,
+
+    /// Fail on dependencies that need a review, not only on incompatible ones
+    #[arg(long)]
+    pub strict: bool,
+
+    #[command(flatten)]
+    pub format: FormatArgs,
+}
+
+/// A dependency and how its license combines with the project license
+#[derive(Debug, Serialize)]
+pub struct DependencyRecord {
+    pub ecosystem: &'static str,
+    pub name: String,
+    pub version: String,
+    pub license: Option<String>,
+    pub status: Status,
+    pub reason: Option<String>,
+}
+
+impl DependencyRecord {
+    pub const COLUMNS: &'static [&'static str] = &[
+        "ecosystem",
+        "name",
+        "version",
+        "license",
+        "status",
+        "reason",
+    ];
+}
+
+/// The machine-readable report printed by `--format json|yaml`
+#[derive(Debug, Serialize)]
+struct Report<'a> {
+    license: &'a str,
+    /// File the license was read from; `None` when given with --license
+    declared_in: Option<&'a str>,
+    ok: usize,
+    review: usize,
+    incompatible: usize,
+    dependencies: &'a [DependencyRecord],
+}
+
+/// A dependency as read from a lockfile or package metadata
+struct Dependency {
+    ecosystem: &'static str,
+    name: String,
+    version: String,
+    license: Option<String>,
+}
+
+impl super::Runnable for CheckArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        let root = file::find_repo_root()?;
+
+        let (license, declared_in) = match &self.license {
+            Some(license) => (license.clone(), None),
+            None => detect::declared_license(&root)
+                .map(|(license, file)| (license, Some(file)))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "No license declared in Cargo.toml, package.json or pyproject.toml. Pass the project license with --license."
+                    )
+                })?,
+        };
+        let project = spdx::parse(&license)
+            .ok_or_else(|| anyhow!("'{}' is not a valid SPDX license expression.", license))?;
+
+        let mut notes = Vec::new();
+        let mut dependencies = Vec::new();
+        dependencies.extend(cargo_dependencies(
+            &root,
+            self.cargo_metadata.as_deref(),
+            &mut notes,
+        )?);
+        dependencies.extend(npm_dependencies(&root, self.include_dev, &mut notes)?);
+        dependencies.extend(python_dependencies(
+            &root,
+            self.site_packages.as_deref(),
+            &mut notes,
+        )?);
+
+        for note in &notes {
+            eprintln!("{} Warning: {}", "⚠".yellow(), note);
+        }
+        if dependencies.is_empty() {
+            return Err(anyhow!(
+                "No dependency licenses found. Supported sources: Cargo.lock with --cargo-metadata, package-lock.json or node_modules, and pyproject.toml with an installed environment."
+            ));
+        }
+
+        let records: Vec<DependencyRecord> = dependencies
+            .into_iter()
+            .map(|dependency| {
+                let verdict = match dependency.license.as_deref().map(|l| (l, spdx::parse(l))) {
+                    Some((_, Some(expression))) => spdx::check(&project, &expression),
+                    Some((license, None)) => spdx::Verdict {
+                        status: Status::Review,
+                        reason: Some(format!("cannot read license expression '{}'", license)),
+                    },
+                    None => spdx::Verdict {
+                        status: Status::Review,
+                        reason: Some("no license information".to_string()),
+                    },
+                };
+                DependencyRecord {
+                    ecosystem: dependency.ecosystem,
+                    name: dependency.name,
+                    version: dependency.version,
+                    license: dependency.license,
+                    status: verdict.status,
+                    reason: verdict.reason,
+                }
+            })
+            .collect();
+
+        let count = |status| records.iter().filter(|r| r.status == status).count();
+        let (ok, review, incompatible) = (
+            count(Status::Ok),
+            count(Status::Review),
+            count(Status::Incompatible),
+        );
+
+        match self.format.format {
+            OutputFormat::Text => print_text(&license, declared_in, &records),
+            OutputFormat::Tsv => {
+                print_records(&records, OutputFormat::Tsv, DependencyRecord::COLUMNS)?
+            }
+            format => {
+                let report = Report {
+                    license: &license,
+                    declared_in,
+                    ok,
+                    review,
+                    incompatible,
+                    dependencies: &records,
+                };
+                if format == OutputFormat::Json {
+                    println!("{}", serde_json::to_string_pretty(&report)?);
+                } else {
+                    print!("{}", serde_yaml::to_string(&report)?);
+                }
+            }
+        }
+
+        if incompatible > 0 {
+            return Err(anyhow!(
+                "{} dependency license(s) are incompatible with {}.",
+                incompatible,
+                license
+            ));
+        }
+        if self.strict && review > 0 {
+            return Err(anyhow!(
+                "{} dependency license(s) need a review (--strict).",
+                review
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn print_text(license: &str, declared_in: Option<&str>, records: &[DependencyRecord]) {
+    let origin = match declared_in {
+        Some(file) => format!("declared in {}", file),
+        None => "given with --license".to_string(),
+    };
+    println!("{} {} ({})\n", "License:".cyan(), license, origin);
+
+    let mut flagged: Vec<&DependencyRecord> =
+        records.iter().filter(|r| r.status != Status::Ok).collect();
+    flagged.sort_by_key(|r| std::cmp::Reverse(r.status));
+    for record in &flagged {
+        let mark = match record.status {
+            Status::Incompatible => "✗".red(),
+            _ => "⚠".yellow(),
+        };
+        let version = if record.version.is_empty() {
+            String::new()
+        } else {
+            format!(" {}", record.version)
+        };
+        println!(
+            "{} {}{} ({}) - {}: {}",
+            mark,
+            record.name,
+            version,
+            record.ecosystem,
+            record.license.as_deref().unwrap_or("unknown"),
+            record.reason.as_deref().unwrap_or_default()
+        );
+    }
+    if !flagged.is_empty() {
+        println!();
+    }
+
+    let count = |status| records.iter().filter(|r| r.status == status).count();
+    println!(
+        "Checked {} dependencies: {} compatible, {} to review, {} incompatible.",
+        records.len(),
+        count(Status::Ok),
+        count(Status::Review),
+        count(Status::Incompatible)
+    );
+}
+
+/// Crates of `Cargo.lock`, with their licenses from a `cargo metadata` file.
+/// Path and workspace crates have no `source` and are skipped.
+fn cargo_dependencies(
+    root: &Path,
+    metadata_path: Option<&Path>,
+    notes: &mut Vec<String>,
+) -> Result<Vec<Dependency>> {
+    let lock_path = root.join("Cargo.lock");
+    let locked: Option<BTreeSet<(String, String)>> = if lock_path.exists() {
+        let lock: toml::Value = toml::from_str(&fs::read_to_string(&lock_path)?)
+            .map_err(|e| anyhow!("Failed to parse Cargo.lock: {}", e))?;
+        let packages = lock
+            .get("package")
+            .and_then(|p| p.as_array())
+            .cloned()
+            .unwrap_or_default();
+        Some(
+            packages
+                .iter()
+                .filter(|p| p.get("source").is_some())
+                .filter_map(|p| {
+                    let name = p.get("name")?.as_str()?;
+                    let version = p.get("version")?.as_str()?;
+                    Some((name.to_string(), version.to_string()))
+                })
+                .collect(),
+        )
+    } else {
+        None
+    };
+
+    let Some(metadata_path) = metadata_path else {
+        if locked.is_some() {
+            notes.push(
+                "Cargo.lock has no license information; pass --cargo-metadata FILE with the output of `cargo metadata --format-version 1` to check crates.".to_string(),
+            );
+        }
+        return Ok(Vec::new());
+    };
+
+    let content = fs::read_to_string(metadata_path)
+        .map_err(|e| anyhow!("Failed to read '{}': {}", metadata_path.display(), e))?;
+    let metadata: Value = serde_json::from_str(&content).map_err(|e| {
+        anyhow!(
+            "'{}' is not `cargo metadata` output: {}",
+            metadata_path.display(),
+            e
+        )
+    })?;
+
+    let mut licenses: HashMap<(String, String), Option<String>> = HashMap::new();
+    for package in metadata
+        .get("packages")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let (Some(name), Some(version)) = (
+            package.get("name").and_then(Value::as_str),
+            package.get("version").and_then(Value::as_str),
+        ) else {
+            continue;
+        };
+        // Workspace members and path dependencies are part of the project
+        if package.get("source").is_none_or(Value::is_null) {
+            continue;
+        }
+        let license = package
+            .get("license")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        licenses.insert((name.to_string(), version.to_string()), license);
+    }
+
+    let crates: Vec<(String, String)> = match locked {
+        Some(locked) => locked.into_iter().collect(),
+        None => {
+            let mut crates: Vec<_> = licenses.keys().cloned().collect();
+            crates.sort();
+            crates
+        }
+    };
+
+    Ok(crates
+        .into_iter()
+        .map(|(name, version)| {
+            let license = licenses
+                .get(&(name.clone(), version.clone()))
+                .cloned()
+                .flatten();
+            Dependency {
+                ecosystem: "cargo",
+                name,
+                version,
+                license,
+            }
+        })
+        .collect())
+}
+
+/// Packages of `package-lock.json` (v2 and later record their license), or
+/// else the dependencies of `package.json` as installed in `node_modules`
+fn npm_dependencies(
+    root: &Path,
+    include_dev: bool,
+    notes: &mut Vec<String>,
+) -> Result<Vec<Dependency>> {
+    let lock_path = root.join("package-lock.json");
+    if let Some(packages) = read_json(&lock_path)?
+        .and_then(|lock| lock.get("packages").and_then(Value::as_object).cloned())
+    {
+        let mut dependencies = Vec::new();
+        for (key, package) in &packages {
+            let Some((_, name)) = key.rsplit_once("node_modules/") else {
+                // The root package
+                continue;
+            };
+            let is_dev = package.get("dev").and_then(Value::as_bool) == Some(true);
+            let is_link = package.get("link").and_then(Value::as_bool) == Some(true);
+            if is_link || (is_dev && !include_dev) {
+                continue;
+            }
+            dependencies.push(Dependency {
+                ecosystem: "npm",
+                name: name.to_string(),
+                version: string_field(package, "version").unwrap_or_default(),
+                license: npm_license(package),
+            });
+        }
+        return Ok(dependencies);
+    }
+
+    let Some(manifest) = read_json(&root.join("package.json"))? else {
+        return Ok(Vec::new());
+    };
+    let mut names: Vec<&String> = Vec::new();
+    let sections: &[&str] = if include_dev {
+        &["dependencies", "devDependencies"]
+    } else {
+        &["dependencies"]
+    };
+    for section in sections {
+        if let Some(deps) = manifest.get(*section).and_then(Value::as_object) {
+            names.extend(deps.keys());
+        }
+    }
+    if names.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let node_modules = root.join("node_modules");
+    if !node_modules.is_dir() {
+        notes.push(
+            "package.json dependencies were skipped: run `npm install` so package-lock.json or node_modules records their licenses.".to_string(),
+        );
+        return Ok(Vec::new());
+    }
+
+    let mut dependencies = Vec::new();
+    for name in names {
+        let installed = read_json(&node_modules.join(name).join("package.json"))?;
+        dependencies.push(Dependency {
+            ecosystem: "npm",
+            name: name.clone(),
+            version: installed
+                .as_ref()
+                .and_then(|p| string_field(p, "version"))
+                .unwrap_or_default(),
+            license: installed.as_ref().and_then(npm_license),
+        });
+    }
+    Ok(dependencies)
+}
+
+/// `license` of an npm package, or the old `license: {type}` / `licenses: [{type}]` forms
+fn npm_license(package: &Value) -> Option<String> {
+    match package.get("license") {
+        Some(Value::String(license)) => return Some(license.clone()),
+        Some(license) if license.get("type").is_some() => {
+            return string_field(license, "type");
+        }
+        _ => {}
+    }
+    let types: Vec<String> = package
+        .get("licenses")?
+        .as_array()?
+        .iter()
+        .filter_map(|l| string_field(l, "type"))
+        .collect();
+    (!types.is_empty()).then(|| types.join(" OR "))
+}
+
+/// Dependencies of `pyproject.toml`, with their licenses from the metadata
+/// of the installed distributions
+fn python_dependencies(
+    root: &Path,
+    site_packages: Option<&Path>,
+    notes: &mut Vec<String>,
+) -> Result<Vec<Dependency>> {
+    let path = root.join("pyproject.toml");
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let manifest: toml::Value = toml::from_str(&fs::read_to_string(&path)?)
+        .map_err(|e| anyhow!("Failed to parse pyproject.toml: {}", e))?;
+
+    // PEP 621 `dependencies = ["requests>=2"]` or Poetry's `[tool.poetry.dependencies]`
+    let mut names: Vec<String> = manifest
+        .get("project")
+        .and_then(|p| p.get("dependencies"))
+        .and_then(|d| d.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|d| d.as_str())
+        .filter_map(requirement_name)
+        .collect();
+    if let Some(poetry) = manifest
+        .get("tool")
+        .and_then(|t| t.get("poetry"))
+        .and_then(|p| p.get("dependencies"))
+        .and_then(|d| d.as_table())
+    {
+        names.extend(poetry.keys().filter(|k| *k != "python").cloned());
+    }
+    if names.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let Some(site_packages) = site_packages
+        .map(Path::to_path_buf)
+        .or_else(|| find_site_packages(root))
+    else {
+        notes.push(
+            "pyproject.toml dependencies were skipped: no .venv or venv found; pass --site-packages DIR.".to_string(),
+        );
+        return Ok(Vec::new());
+    };
+
+    let installed = installed_distributions(&site_packages)?;
+    Ok(names
+        .into_iter()
+        .map(|name| match installed.get(&normalize_distribution(&name)) {
+            Some((version, license)) => Dependency {
+                ecosystem: "python",
+                name,
+                version: version.clone(),
+                license: license.clone(),
+            },
+            None => Dependency {
+                ecosystem: "python",
+                name,
+                version: String::new(),
+                license: None,
+            },
+        })
+        .collect())
+}
+
+/// The distribution name of a PEP 508 requirement such as `requests[socks]>=2.31`
+fn requirement_name(requirement: &str) -> Option<String> {
+    let name: String = requirement
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
+
+fn normalize_distribution(name: &str) -> String {
+    name.to_lowercase().replace(['-', '.'], "_")
+}
+
+/// `lib/python3.*/site-packages` (or `Lib/site-packages` on Windows) of `.venv` or `venv`
+fn find_site_packages(root: &Path) -> Option<PathBuf> {
+    for venv in [".venv", "venv"] {
+        let windows = root.join(venv).join("Lib").join("site-packages");
+        if windows.is_dir() {
+            return Some(windows);
+        }
+        let Ok(entries) = fs::read_dir(root.join(venv).join("lib")) else {
+            continue;
+        };
+        let found = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path().join("site-packages")))
+            .find(|path| path.is_dir());
+        if found.is_some() {
+            return found;
+        }
+    }
+    None
+}
+
+/// Version and license of every `*.dist-info` in `site_packages`, by normalized name
+fn installed_distributions(
+    site_packages: &Path,
+) -> Result<HashMap<String, (String, Option<String>)>> {
+    let entries = fs::read_dir(site_packages)
+        .map_err(|e| anyhow!("Failed to read '{}': {}", site_packages.display(), e))?;
+
+    let mut distributions = HashMap::new();
+    for path in entries.filter_map(|entry| entry.ok().map(|e| e.path())) {
+        let Some(stem) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_suffix(".dist-info"))
+        else {
+            continue;
+        };
+        let Some((name, version)) = stem.split_once('-') else {
+            continue;
+        };
+        let license = fs::read_to_string(path.join("METADATA"))
+            .ok()
+            .and_then(|metadata| python_license(&metadata));
+        distributions.insert(normalize_distribution(name), (version.to_string(), license));
+    }
+    Ok(distributions)
+}
+
+/// License of a distribution from its core metadata: `License-Expression`,
+/// else the license classifiers, else the free-form `License` field
+fn python_license(metadata: &str) -> Option<String> {
+    // Only the header; the description follows the first blank line
+    let headers: Vec<(&str, &str)> = metadata
+        .lines()
+        .take_while(|line| !line.trim().is_empty())
+        .filter_map(|line| line.split_once(": "))
+        .collect();
+    let field = |key: &str| {
+        headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.trim().to_string())
+    };
+
+    if let Some(expression) = field("License-Expression") {
+        return Some(expression);
+    }
+
+    let classifiers: Vec<&str> = headers
+        .iter()
+        .filter(|(k, v)| k.eq_ignore_ascii_case("Classifier") && v.starts_with("License ::"))
+        .filter_map(|(_, v)| spdx::from_classifier(v))
+        .collect();
+
+    if !classifiers.is_empty() {
+        return Some(classifiers.join(" OR "));
+    }
+    field("License").filter(|l| !l.is_empty() && l != "UNKNOWN")
+}
+
+fn read_json(path: &Path) -> Result<Option<Value>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(path)?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| anyhow!("Failed to parse '{}': {}", path.display(), e))
+}
+
+fn string_field(value: &Value, key: &str) -> Option<String> {
+    value.get(key).and_then(Value::as_str).map(str::to_string)
+}
@@ -1,12 +1,14 @@
 use colored::*;
+use serde::Serialize;
 
 use crate::utils::cache::{Cache, CacheManager};
+use crate::utils::output::{FormatArgs, print_records};
 use crate::utils::pattern::filter_by_wildcard;
 use crate::utils::progress;
 use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
 
-// SPDX license list URL
-use super::{SPDX_LICENSE_LIST_URL, ensure_github_api_license_cache, ensure_spdx_license_cache};
+use super::{SpdxSource, ensure_github_api_license_cache, ensure_spdx_license_cache};
 
 #[derive(clap::Args)]
 pub struct ListArgs {
@@ -40,17 +42,23 @@ pub struct ListArgs {
     /// Show only FSF libre-approved licenses
     #[arg(long)]
     pub fsf_libre: bool,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    #[command(flatten)]
+    pub format: FormatArgs,
 }
 
 impl super::Runnable for ListArgs {
     fn run(&self) -> anyhow::Result<()> {
         // Handle any unknown arguments
-        if !self.args.is_empty() {
-            for arg in &self.args {
-                return Err(anyhow::anyhow!("Unknown argument: {}", arg));
-            }
+        if let Some(arg) = self.args.first() {
+            return Err(anyhow::anyhow!("Unknown argument: {}", arg));
         }
 
+        let spdx = SpdxSource::resolve(&self.source)?;
+
         // License Args
         let license_args = LicenseArgs {
             update_cache: self.update_cache,
@@ -58,14 +66,21 @@ impl super::Runnable for ListArgs {
             include_deprecated: self.include_deprecated,
             osi_approved: self.osi_approved,
             fsf_libre: self.fsf_libre,
+            format: self.format.clone(),
         };
 
+        if self.popular && !spdx.is_upstream {
+            return Err(anyhow::anyhow!(
+                "--popular lists the licenses featured by GitHub and cannot be used with --source."
+            ));
+        }
+
         if self.popular {
-            return list_popular_licenses(license_args);
+            list_popular_licenses(license_args)
         } else if self.non_software {
-            return list_non_software_licenses(self.update_cache);
+            list_non_software_licenses(self.update_cache, &spdx, &self.format)
         } else {
-            list_all_licenses(license_args)
+            list_all_licenses(license_args, &spdx)
                 .map_err(|e| anyhow::anyhow!("Failed to list licenses: {}", e))
         }
     }
@@ -77,6 +92,54 @@ struct LicenseArgs {
     include_deprecated: bool,
     osi_approved: bool,
     fsf_libre: bool,
+    format: FormatArgs,
+}
+
+/// One license as printed by `--format`
+#[derive(Debug, Serialize)]
+struct LicenseRecord {
+    id: String,
+    name: String,
+    /// `popular`, `spdx`, or the non-software group (`data`, `fonts`, `hardware`)
+    category: &'static str,
+    url: Option<String>,
+    osi_approved: Option<bool>,
+    fsf_libre: Option<bool>,
+    deprecated: Option<bool>,
+}
+
+impl LicenseRecord {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "name",
+        "category",
+        "url",
+        "osi_approved",
+        "fsf_libre",
+        "deprecated",
+    ];
+
+    /// Build a record from an SPDX or GitHub API license object
+    fn new(id: &str, category: &'static str, data: &serde_json::Value) -> Self {
+        let flag = |key: &str| data.get(key).and_then(|v| v.as_bool());
+        Self {
+            id: id.to_string(),
+            name: data
+                .get("name")
+                .and_then(|n| n.as_str())
+                .unwrap_or(id)
+                .to_string(),
+            category,
+            url: data
+                .get("reference")
+                .or_else(|| data.get("url"))
+                .and_then(|u| u.as_str())
+                .map(str::to_string),
+            osi_approved: flag("isOsiApproved"),
+            fsf_libre: flag("isFsfLibre"),
+            deprecated: flag("isDeprecatedLicenseId"),
+        }
+    }
 }
 
 fn list_popular_licenses(args: LicenseArgs) -> anyhow::Result<()> {
@@ -85,10 +148,10 @@ fn list_popular_licenses(args: LicenseArgs) -> anyhow::Result<()> {
     let cache: Cache<serde_json::Value> =
         ensure_github_api_license_cache(&mut cache_manager, args.update_cache)?;
 
+    let mut matches: Vec<_> = cache.entries.iter().collect();
+
     // If search parameter is passed, filter licenses by closest matches
     if let Some(search) = &args.search {
-        let mut matches = Vec::new();
-
         // Collect all IDs and names for wildcard filtering
         let mut all_items = Vec::new();
         for (id, entry) in &cache.entries {
@@ -101,17 +164,26 @@ fn list_popular_licenses(args: LicenseArgs) -> anyhow::Result<()> {
         // Use wildcard pattern matching
         let filtered = filter_by_wildcard(search, &all_items);
 
-        for (id, entry) in &cache.entries {
+        matches.retain(|(id, entry)| {
             let name = entry
                 .data
                 .get("name")
                 .and_then(|n| n.as_str())
                 .unwrap_or("");
-            if filtered.contains(id) || filtered.contains(&name.to_string()) {
-                matches.push((id, entry));
-            }
-        }
+            filtered.contains(id) || filtered.contains(&name.to_string())
+        });
+    }
 
+    if !args.format.is_text() {
+        matches.sort_by(|a, b| a.0.cmp(b.0));
+        let records: Vec<_> = matches
+            .iter()
+            .map(|(id, entry)| LicenseRecord::new(id, "popular", &entry.data))
+            .collect();
+        return print_records(&records, args.format.format, LicenseRecord::COLUMNS);
+    }
+
+    if let Some(search) = &args.search {
         if matches.is_empty() {
             println!("No popular licenses found matching '{}'", search);
             return Ok(());
@@ -136,7 +208,7 @@ fn list_popular_licenses(args: LicenseArgs) -> anyhow::Result<()> {
         return Ok(());
     }
 
-    for (id, entry) in &cache.entries {
+    for (id, entry) in matches {
         if let Some(name) = entry.data.get("name").and_then(|n| n.as_str()) {
             println!("  {} {:<20} {}", ">".green(), id, name);
         } else {
@@ -148,12 +220,12 @@ fn list_popular_licenses(args: LicenseArgs) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn list_all_licenses(args: LicenseArgs) -> anyhow::Result<()> {
+fn list_all_licenses(args: LicenseArgs, spdx: &SpdxSource) -> anyhow::Result<()> {
     let fetcher = Fetcher::new();
 
     let pb = progress::spinner("Fetching SPDX license list...");
 
-    let licenses_data = fetcher.fetch_json(SPDX_LICENSE_LIST_URL)?;
+    let licenses_data = fetcher.fetch_json(&spdx.list_url())?;
 
     pb.set_message("Parsing license list...");
 
@@ -234,6 +306,14 @@ fn list_all_licenses(args: LicenseArgs) -> anyhow::Result<()> {
     // Sort by license ID for consistent output
     filtered_licenses.sort_by(|a, b| a.0.cmp(b.0));
 
+    if !args.format.is_text() {
+        let records: Vec<_> = filtered_licenses
+            .iter()
+            .map(|(id, _, _, license)| LicenseRecord::new(id, "spdx", license))
+            .collect();
+        return print_records(&records, args.format.format, LicenseRecord::COLUMNS);
+    }
+
     // Display results
     if filtered_licenses.is_empty() {
         if let Some(search) = &args.search {
@@ -281,7 +361,21 @@ fn display_simple_licenses(licenses: &[(&str, &str, bool, &serde_json::Value)])
 }
 
 /// Source: https://choosealicense.com/non-software/
-fn list_non_software_licenses(update_cache: bool) -> anyhow::Result<()> {
+/// Licenses relevant for non-software works, grouped as on choosealicense.com
+const NON_SOFTWARE_LICENSES: &[(&str, &[&str])] = &[
+    ("data", &["CC0-1.0", "CC-BY-4.0", "CC-BY-SA-4.0"]),
+    ("fonts", &["OFL-1.1"]),
+    (
+        "hardware",
+        &["CERN-OHL-P-2.0", "CERN-OHL-W-2.0", "CERN-OHL-S-2.0"],
+    ),
+];
+
+fn list_non_software_licenses(
+    update_cache: bool,
+    spdx: &SpdxSource,
+    format: &FormatArgs,
+) -> anyhow::Result<()> {
     let mut cache_manager = CacheManager::new()?;
 
     // License IDs relevant for non-software works
@@ -297,7 +391,19 @@ fn list_non_software_licenses(update_cache: bool) -> anyhow::Result<()> {
 
     // Use SPDX cache for license info
     let cache: Cache<serde_json::Value> =
-        ensure_spdx_license_cache(&mut cache_manager, update_cache)?;
+        ensure_spdx_license_cache(&mut cache_manager, update_cache, spdx)?;
+
+    if !format.is_text() {
+        let records: Vec<_> = NON_SOFTWARE_LICENSES
+            .iter()
+            .flat_map(|(category, ids)| ids.iter().map(move |id| (*category, *id)))
+            .filter_map(|(category, id)| {
+                let entry = cache.entries.get(id)?;
+                Some(LicenseRecord::new(id, category, &entry.data))
+            })
+            .collect();
+        return print_records(&records, format.format, LicenseRecord::COLUMNS);
+    }
 
     println!("{}", "✓ Non-Software Licenses:".green());
     println!();
@@ -1,20 +1,21 @@
 use clap::Subcommand;
 
-use crate::commands::base::Runnable;
+use crate::commands::base::{Runnable, TemplateCategory};
 use crate::utils::cache::{Cache, CacheManager};
+use crate::utils::picker::Candidate;
 use crate::utils::progress;
-use crate::utils::remote::Fetcher;
+use crate::utils::remote::{self, Fetcher};
+use crate::utils::source::SourceArgs;
 
-mod add;
+pub(crate) mod add;
+mod check;
 mod list;
 mod preview;
 
 // Global constants - these can stay in the main module file
 const CACHE_MAX_AGE_SECONDS: u64 = 60 * 60 * 24 * 30; // 30 days
-const SPDX_LICENSE_DETAILS_BASE_URL: &str =
-    "https://raw.githubusercontent.com/spdx/license-list-data/main/json/details";
-const SPDX_LICENSE_LIST_URL: &str =
-    "https://raw.githubusercontent.com/spdx/license-list-data/main/json/licenses.json";
+const SPDX_LICENSE_DATA_BASE_URL: &str =
+    "https://raw.githubusercontent.com/spdx/license-list-data/main";
 const CHOOSEALICENSE_RAW_BASE_URL: &str =
     "https://raw.githubusercontent.com/github/choosealicense.com/gh-pages/_licenses";
 
@@ -27,6 +28,8 @@ const SPDX_CACHE_NAME: &str = "spdx_license_cache";
 pub enum Command {
     /// Add one or more licenses to the repository
     Add(add::AddArgs),
+    /// Check dependency licenses against the project license
+    Check(check::CheckArgs),
     /// List available licenses
     List(list::ListArgs),
     /// Preview a specific license
@@ -37,22 +40,59 @@ impl Command {
     pub fn execute(&self) -> anyhow::Result<()> {
         match self {
             Command::Add(args) => args.run(),
+            Command::Check(args) => args.run(),
             Command::List(args) => args.run(),
             Command::Preview(args) => args.run(),
         }
     }
 }
 
+/// An SPDX `license-list-data` layout: `json/licenses.json` plus `json/details/<ID>.json`
+struct SpdxSource {
+    base_url: String,
+    cache_name: String,
+    /// choosealicense.com and the GitHub licenses API only describe the upstream list
+    is_upstream: bool,
+}
+
+impl SpdxSource {
+    fn resolve(source: &SourceArgs) -> anyhow::Result<Self> {
+        Ok(Self {
+            base_url: source.resolve(TemplateCategory::License, SPDX_LICENSE_DATA_BASE_URL)?,
+            cache_name: source.cache_name(SPDX_CACHE_NAME),
+            is_upstream: source.source.is_none(),
+        })
+    }
+
+    fn list_url(&self) -> String {
+        format!("{}/json/licenses.json", self.base_url)
+    }
+
+    fn details_url(&self, license_id: &str) -> String {
+        format!("{}/json/details/{}.json", self.base_url, license_id)
+    }
+
+    /// choosealicense.com metadata (permissions, conditions, limitations) of an upstream license
+    fn choosealicense_url(&self, license_id: &str) -> Option<String> {
+        self.is_upstream
+            .then(|| format!("{}/{}.txt", CHOOSEALICENSE_RAW_BASE_URL, license_id))
+    }
+}
+
 fn ensure_spdx_license_cache(
     cache_manager: &mut CacheManager,
     update_cache: bool,
+    spdx: &SpdxSource,
 ) -> Result<Cache<serde_json::Value>, anyhow::Error> {
     // Only print if we are updating the cache
     let should_update = cache_manager
-        .should_update_cache::<serde_json::Value>(SPDX_CACHE_NAME, CACHE_MAX_AGE_SECONDS)?;
+        .should_update_cache::<serde_json::Value>(&spdx.cache_name, CACHE_MAX_AGE_SECONDS)?;
 
-    if !should_update || !update_cache {
-        let cache = cache_manager.load_cache(SPDX_CACHE_NAME)?;
+    // Offline, any existing index beats a rebuild from cached API responses
+    let offline_with_cache = remote::is_offline() && cache_manager.cache_exists(&spdx.cache_name);
+
+    if (!should_update && !update_cache) || offline_with_cache {
+        let cache = cache_manager.load_cache(&spdx.cache_name)?;
         // Only print if running in verbose/debug mode (not implemented here)
         // e.g., println!("Loaded license template cache ({} templates)", cache.entries.len());
         return Ok(cache);
@@ -61,9 +101,17 @@ fn ensure_spdx_license_cache(
     let pb = progress::spinner("Updating license template cache...");
 
     let fetcher = Fetcher::new();
-    let url = SPDX_LICENSE_LIST_URL;
-
-    let data = fetcher.fetch_json(url)?;
+    let url = spdx.list_url();
+
+    let data = match fetcher.fetch_json(&url) {
+        Ok(data) => data,
+        // Without --update-cache, an outdated index is better than none
+        Err(_) if !update_cache && cache_manager.cache_exists(&spdx.cache_name) => {
+            pb.finish_and_clear();
+            return cache_manager.load_cache(&spdx.cache_name);
+        }
+        Err(e) => return Err(e),
+    };
     let mut cache = Cache::new();
 
     if let Some(licenses) = data.get("licenses").and_then(|v| v.as_array()) {
@@ -76,12 +124,12 @@ fn ensure_spdx_license_cache(
     }
 
     pb.finish_and_clear();
-    println!(
+    eprintln!(
         "License template cache updated ({} templates available).",
         cache.entries.len()
     );
 
-    cache_manager.save_cache(SPDX_CACHE_NAME, &cache)?;
+    cache_manager.save_cache(&spdx.cache_name, &cache)?;
     Ok(cache)
 }
 
@@ -116,7 +164,7 @@ fn ensure_github_api_license_cache(
     }
 
     pb.finish_and_clear();
-    println!(
+    eprintln!(
         "Popular licenses cache updated ({} licenses available).",
         new_cache.entries.len()
     );
@@ -124,3 +172,46 @@ fn ensure_github_api_license_cache(
     cache_manager.save_cache(GITHUB_LICENSES_CACHE_NAME, &new_cache)?;
     Ok(new_cache)
 }
+
+/// Licenses offered by `pick` and by `license add` without arguments
+pub(crate) fn pick_candidates(source: &SourceArgs) -> anyhow::Result<Vec<Candidate>> {
+    let spdx = SpdxSource::resolve(source)?;
+    let mut cache_manager = CacheManager::new()?;
+    let cache = ensure_spdx_license_cache(&mut cache_manager, false, &spdx)?;
+
+    let mut candidates: Vec<Candidate> = cache
+        .entries
+        .into_iter()
+        .filter(|(_, entry)| {
+            !entry
+                .data
+                .get("isDeprecatedLicenseId")
+                .and_then(|d| d.as_bool())
+                .unwrap_or(false)
+        })
+        .map(|(id, entry)| {
+            let url = spdx.details_url(&id);
+            Candidate {
+                group: TemplateCategory::License.config_key(),
+                detail: entry
+                    .data
+                    .get("name")
+                    .and_then(|n| n.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                name: id,
+                ext: "txt",
+                preview: Box::new(move || {
+                    Fetcher::new()
+                        .fetch_json(&url)?
+                        .get("licenseText")
+                        .and_then(|t| t.as_str())
+                        .map(str::to_string)
+                        .ok_or_else(|| anyhow::anyhow!("No license text found at {}", url))
+                }),
+            }
+        })
+        .collect();
+    candidates.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(candidates)
+}
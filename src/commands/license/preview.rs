@@ -1,12 +1,10 @@
 use colored::*;
 
-use super::{
-    CHOOSEALICENSE_RAW_BASE_URL, SPDX_LICENSE_DETAILS_BASE_URL, SPDX_LICENSE_LIST_URL,
-    ensure_spdx_license_cache,
-};
+use super::{SpdxSource, ensure_spdx_license_cache};
 
 use crate::utils::cache::{Cache, CacheManager};
 use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
 use serde::{Deserialize, Serialize};
 
 #[derive(clap::Args, Debug)]
@@ -38,14 +36,18 @@ pub struct PreviewArgs {
     /// Update the license cache
     #[arg(long, short = 'u')]
     pub update_cache: bool,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
 }
 
 impl super::Runnable for PreviewArgs {
     fn run(&self) -> anyhow::Result<()> {
+        let spdx = SpdxSource::resolve(&self.source)?;
         let mut cache_manager = CacheManager::new()?;
 
         let cache: Cache<serde_json::Value> =
-            ensure_spdx_license_cache(&mut cache_manager, self.update_cache)?;
+            ensure_spdx_license_cache(&mut cache_manager, self.update_cache, &spdx)?;
 
         let normalized_id = normalize_license_id(&self.id);
         let id_lower = normalized_id.to_lowercase();
@@ -75,7 +77,7 @@ impl super::Runnable for PreviewArgs {
         );
 
         // Try to fetch ChooseALicense metadata if available
-        let choosealicense_meta = fetch_choosealicense_meta(&normalized_id);
+        let choosealicense_meta = fetch_choosealicense_meta(&spdx, &normalized_id);
 
         // If no flags, show full license
         if !self.description
@@ -84,14 +86,13 @@ impl super::Runnable for PreviewArgs {
             && !self.conditions
             && !self.details
         {
-            let license_url = format!("{}/{}.json", SPDX_LICENSE_DETAILS_BASE_URL, license_key);
-            show_full_license(&license_url)?;
+            show_full_license(&spdx.details_url(&license_key))?;
             return Ok(());
         }
 
         // Fetch SPDX metadata if needed
         let license_metadata = if self.description || self.details {
-            Some(get_license_metadata(&self.id)?)
+            Some(get_license_metadata(&self.id, &spdx)?)
         } else {
             None
         };
@@ -121,16 +122,15 @@ impl super::Runnable for PreviewArgs {
         }
         // Show license text unless metadata-only is specified
         // if !self.metadata_only {
-        //     let license_url = format!("{}/{}.json", SPDX_LICENSE_DETAILS_BASE_URL, license_key);
-        //     show_full_license(&license_url)?;
+        //     show_full_license(&spdx.details_url(&license_key))?;
         // }
 
         Ok(())
     }
 }
 
-fn fetch_choosealicense_meta(normalized_id: &str) -> Option<ChooseALicenseFile> {
-    let url = format!("{}/{}.txt", CHOOSEALICENSE_RAW_BASE_URL, normalized_id);
+fn fetch_choosealicense_meta(spdx: &SpdxSource, normalized_id: &str) -> Option<ChooseALicenseFile> {
+    let url = spdx.choosealicense_url(normalized_id)?;
     let fetcher = Fetcher::new();
     match fetcher.fetch_content(&url) {
         Ok(content) => parse_choosealicense_txt(&content).ok(),
@@ -138,9 +138,9 @@ fn fetch_choosealicense_meta(normalized_id: &str) -> Option<ChooseALicenseFile>
     }
 }
 
-fn get_license_metadata(license_id: &str) -> anyhow::Result<serde_json::Value> {
+fn get_license_metadata(license_id: &str, spdx: &SpdxSource) -> anyhow::Result<serde_json::Value> {
     let fetcher = Fetcher::new();
-    let licenses_data = fetcher.fetch_json(SPDX_LICENSE_LIST_URL)?;
+    let licenses_data = fetcher.fetch_json(&spdx.list_url())?;
 
     let licenses = licenses_data
         .get("licenses")
@@ -1,10 +1,19 @@
 use clap::Subcommand;
 
+use crate::commands::base::Runnable;
+
+pub mod apply;
 pub mod base;
+pub mod ci;
+pub mod code_of_conduct;
+pub mod detect;
 pub mod gitignore;
 pub mod issue;
 pub mod license;
+pub mod pick;
 pub mod pr;
+pub mod status;
+pub mod update;
 
 #[derive(Subcommand)]
 pub enum CategoryCommand {
@@ -23,6 +32,29 @@ pub enum CategoryCommand {
     #[command(subcommand)]
     /// The `Gitignore` subcommand provides functionality related to managing `.gitignore` templates.
     Gitignore(gitignore::Command),
+
+    #[command(subcommand)]
+    /// The `Ci` subcommand provides functionality related to managing GitHub Actions workflow templates.
+    Ci(ci::Command),
+
+    #[command(subcommand)]
+    /// The `CodeOfConduct` subcommand provides functionality related to managing the code of conduct.
+    CodeOfConduct(code_of_conduct::Command),
+
+    /// Install every template declared in the repository's `.gh-templates.toml`.
+    Apply(apply::ApplyArgs),
+
+    /// Suggest gitignore, CI and license templates from the files in the repository.
+    Detect(detect::DetectArgs),
+
+    /// Choose templates in an interactive fuzzy finder with a live preview, then add them.
+    Pick(pick::PickArgs),
+
+    /// Report which installed templates were edited locally or changed upstream.
+    Status(status::StatusArgs),
+
+    /// Refresh installed templates that changed upstream and were not edited locally.
+    Update(update::UpdateArgs),
 }
 
 impl CategoryCommand {
@@ -32,6 +64,13 @@ impl CategoryCommand {
             Self::License(cmd) => cmd.execute(),
             Self::PR(cmd) => cmd.execute(),
             Self::Gitignore(cmd) => cmd.execute(),
+            Self::Ci(cmd) => cmd.execute(),
+            Self::CodeOfConduct(cmd) => cmd.execute(),
+            Self::Apply(args) => args.run(),
+            Self::Detect(args) => args.run(),
+            Self::Pick(args) => args.run(),
+            Self::Status(args) => args.run(),
+            Self::Update(args) => args.run(),
         }
     }
 }
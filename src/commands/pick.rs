@@ -0,0 +1,190 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+
+use crate::commands::base::{Runnable, TemplateCategory};
+use crate::commands::{gitignore, issue, license, pr};
+use crate::utils::picker::{self, Candidate};
+use crate::utils::source::SourceArgs;
+
+// Command to choose templates interactively and add them
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickCategory {
+    Issue,
+    Pr,
+    Gitignore,
+    License,
+}
+
+impl PickCategory {
+    const ALL: [Self; 4] = [Self::Issue, Self::Pr, Self::Gitignore, Self::License];
+
+    fn candidates(self, source: &SourceArgs) -> Result<Vec<Candidate>> {
+        match self {
+            Self::Issue => issue::pick_candidates(source),
+            Self::Pr => pr::pick_candidates(source),
+            Self::Gitignore => gitignore::pick_candidates(source),
+            Self::License => license::pick_candidates(source),
+        }
+    }
+
+    /// The `group` of this category's candidates
+    fn group(self) -> &'static str {
+        let category = match self {
+            Self::Issue => TemplateCategory::Issue,
+            Self::Pr => TemplateCategory::PR,
+            Self::Gitignore => TemplateCategory::Gitignore,
+            Self::License => TemplateCategory::License,
+        };
+        category.config_key()
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::Issue => "Issue templates",
+            Self::Pr => "Pull request templates",
+            Self::Gitignore => "Gitignore templates",
+            Self::License => "Licenses",
+        }
+    }
+}
+
+#[derive(clap::Args, Debug)]
+pub struct PickArgs {
+    /// Only offer templates of this kind (default: all of them)
+    #[arg(value_enum)]
+    pub category: Option<PickCategory>,
+
+    /// Force overwrite files that already exist
+    #[arg(long)]
+    pub force: bool,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
+}
+
+impl Runnable for PickArgs {
+    fn run(&self) -> Result<()> {
+        if self.source.source.is_some() && self.category.is_none() {
+            return Err(anyhow!(
+                "--source names a source of one category. Pass a category too, e.g. `pick issue --source NAME`."
+            ));
+        }
+        if !picker::is_interactive() {
+            return Err(anyhow!(
+                "`pick` needs an interactive terminal. Use `list` and `add` in scripts."
+            ));
+        }
+
+        let (title, candidates) = match self.category {
+            Some(category) => (category.title(), category.candidates(&self.source)?),
+            None => ("Templates", all_candidates()),
+        };
+
+        let chosen = picker::pick(title, &candidates)?;
+        if chosen.is_empty() {
+            println!("No templates selected.");
+            return Ok(());
+        }
+
+        for category in PickCategory::ALL {
+            let group = category.group();
+            let names: Vec<String> = chosen
+                .iter()
+                .map(|&index| &candidates[index])
+                .filter(|candidate| candidate.group == group)
+                .map(|candidate| candidate.name.clone())
+                .collect();
+            if !names.is_empty() {
+                add_step(category, names, self.force, &self.source).run()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Candidates from every category; a category that cannot be loaded is skipped with a warning
+fn all_candidates() -> Vec<Candidate> {
+    let source = SourceArgs::default();
+    let mut candidates = Vec::new();
+
+    for category in PickCategory::ALL {
+        match category.candidates(&source) {
+            Ok(found) => candidates.extend(found),
+            Err(e) => eprintln!(
+                "{}",
+                format!("Skipping {}: {}", category.title().to_lowercase(), e).yellow()
+            ),
+        }
+    }
+
+    candidates
+}
+
+/// Let the user pick templates of one category for an `add` run without arguments
+pub(crate) fn choose(category: PickCategory, source: &SourceArgs) -> Result<Vec<String>> {
+    let candidates = category.candidates(source)?;
+    let chosen = picker::pick(category.title(), &candidates)?;
+
+    Ok(chosen
+        .into_iter()
+        .map(|index| candidates[index].name.clone())
+        .collect())
+}
+
+/// The `add` invocation for picked templates, with the command's defaults
+fn add_step(
+    category: PickCategory,
+    names: Vec<String>,
+    force: bool,
+    source: &SourceArgs,
+) -> Box<dyn Runnable> {
+    let source = source.clone();
+
+    match category {
+        PickCategory::Issue => Box::new(issue::add::AddArgs {
+            templates: names,
+            dir: None,
+            force,
+            all: false,
+            output: Vec::new(),
+            metadata: issue::add::MetadataArgs::default(),
+            source,
+        }),
+        PickCategory::Pr => Box::new(pr::add::AddArgs {
+            templates: names,
+            dir: None,
+            force,
+            all: false,
+            update_cache: false,
+            interactive: false,
+            params: Vec::new(),
+            output: Vec::new(),
+            source,
+        }),
+        PickCategory::Gitignore => Box::new(gitignore::add::AddArgs {
+            templates: names,
+            dir: None,
+            force,
+            all: false,
+            auto: false,
+            append: false,
+            update_cache: false,
+            use_remote_name: false,
+            output: vec![".gitignore".to_string()],
+            source,
+        }),
+        PickCategory::License => Box::new(license::add::AddArgs {
+            licenses: names,
+            dir: None,
+            force,
+            all: false,
+            interactive: false,
+            update_cache: false,
+            params: Vec::new(),
+            output: Vec::new(),
+            source,
+        }),
+    }
+}
@@ -1,12 +1,22 @@
 use colored::*;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use crate::commands::base::TemplateCategory;
+use crate::commands::pick::{self, PickCategory};
 use crate::utils::file;
+use crate::utils::lockfile;
 use crate::utils::manifest_navigator::ManifestNavigator;
+use crate::utils::picker;
+use crate::utils::placeholder;
 use crate::utils::progress;
+use crate::utils::project_config::{ProjectConfig, PROJECT_CONFIG_FILE};
 use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
+use crate::utils::templating;
 
-use super::GITHUB_RAW_BASE;
+use super::chooser;
+use super::templates_base;
 
 const OUTPUT_BASE_PATH: &str = ".github";
 const OUTPUT: &str = "PULL_REQUEST_TEMPLATE";
@@ -35,15 +45,50 @@ pub struct AddArgs {
     #[arg(long = "update-cache", default_value = "false")]
     pub update_cache: bool,
 
+    /// Prompt for template variables and conditional sections without a value
+    #[arg(long, short = 'i')]
+    pub interactive: bool,
+
+    /// Values for template variables and conditional sections (key=value format)
+    #[arg(long = "param", value_name = "KEY=VALUE", num_args = 0.., action = clap::ArgAction::Append)]
+    pub params: Vec<String>,
+
     /// Output file names for the templates (in order of templates)
     #[arg(short = 'o', long, value_name = "OUTPUT", num_args = 1.., requires = "templates")]
     pub output: Vec<String>,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
 }
 
 impl super::Runnable for AddArgs {
     fn run(&self) -> anyhow::Result<()> {
+        if !self.all && self.templates.is_empty() && picker::is_interactive() {
+            let templates = pick::choose(PickCategory::Pr, &self.source)?;
+            if templates.is_empty() {
+                println!("No templates selected.");
+                return Ok(());
+            }
+            return Self {
+                templates,
+                dir: self.dir.clone(),
+                output: Vec::new(),
+                params: self.params.clone(),
+                source: self.source.clone(),
+                ..*self
+            }
+            .run();
+        }
+
+        let base = templates_base(&self.source)?;
+        let vars = Variables {
+            params: self.template_params()?,
+            interactive: self.interactive,
+        };
+
+        let mut written = Vec::new();
         if self.all {
-            download_all_templates(self.dir.as_ref(), self.force)?;
+            written = download_all_templates(&base, self.dir.as_ref(), self.force, &vars)?;
         } else if self.templates.is_empty() {
             return Err(anyhow::anyhow!(
                 "No pull request template specified. Use `--all` or pass template names."
@@ -56,31 +101,83 @@ impl super::Runnable for AddArgs {
                     ));
                 }
                 for (template_name, output_name) in self.templates.iter().zip(self.output.iter()) {
-                    download_single_template(
+                    written.push(download_single_template(
+                        &base,
                         template_name,
                         self.dir.as_ref(),
                         self.force,
                         Some(output_name.clone()),
-                    )?;
+                        &vars,
+                    )?);
                 }
             } else {
                 for template_name in &self.templates {
-                    download_single_template(template_name, self.dir.as_ref(), self.force, None)?;
+                    written.push(download_single_template(
+                        &base,
+                        template_name,
+                        self.dir.as_ref(),
+                        self.force,
+                        None,
+                        &vars,
+                    )?);
                 }
             }
         }
 
+        // GitHub only offers the default template; link the others from it
+        if self.dir.is_none() {
+            if let Ok(root) = file::find_repo_root() {
+                chooser::update(&root, &written)?;
+            }
+        }
+
         Ok(())
     }
 }
 
+impl AddArgs {
+    /// `[pr] params` of the project config, overridden by `--param`
+    fn template_params(&self) -> anyhow::Result<HashMap<String, String>> {
+        let mut params = HashMap::new();
+
+        if let Ok(root) = file::find_repo_root() {
+            if root.join(PROJECT_CONFIG_FILE).exists() {
+                let config = ProjectConfig::load(&root)?;
+                if let Some(section) = config.pr {
+                    params.extend(
+                        section
+                            .params
+                            .into_iter()
+                            .map(|(key, value)| (key.to_lowercase(), value)),
+                    );
+                }
+            }
+        }
+
+        params.extend(placeholder::parse_params(&self.params)?);
+        Ok(params)
+    }
+}
+
+/// How the variables of the templates are filled
+struct Variables {
+    params: HashMap<String, String>,
+    interactive: bool,
+}
+
 // Helper functions
 
-fn download_all_templates(dir_path: Option<&PathBuf>, force: bool) -> anyhow::Result<()> {
-    let manifest_url = format!("{}/pr-templates/manifest.yml", GITHUB_RAW_BASE);
+fn download_all_templates(
+    base: &str,
+    dir_path: Option<&PathBuf>,
+    force: bool,
+    vars: &Variables,
+) -> anyhow::Result<Vec<(PathBuf, String)>> {
+    let manifest_url = format!("{}/manifest.yml", base);
     let manifest_navigator = ManifestNavigator::new(&manifest_url)?;
     let template_entries = manifest_navigator.list_entries()?;
 
+    let mut written = Vec::new();
     let mut errors = Vec::new();
 
     for entry in template_entries {
@@ -90,14 +187,17 @@ fn download_all_templates(dir_path: Option<&PathBuf>, force: bool) -> anyhow::Re
             None => &entry.name,
         };
 
-        if let Err(e) = download_single_template(template_name, dir_path, force, None) {
-            eprintln!(
-                "{} Failed to add template '{}': {}",
-                "✗".red(),
-                template_name,
-                e
-            );
-            errors.push((template_name.to_string(), e));
+        match download_single_template(base, template_name, dir_path, force, None, vars) {
+            Ok(template) => written.push(template),
+            Err(e) => {
+                eprintln!(
+                    "{} Failed to add template '{}': {}",
+                    "✗".red(),
+                    template_name,
+                    e
+                );
+                errors.push((template_name.to_string(), e));
+            }
         }
     }
 
@@ -119,18 +219,21 @@ fn download_all_templates(dir_path: Option<&PathBuf>, force: bool) -> anyhow::Re
         );
     }
 
-    Ok(())
+    Ok(written)
 }
 
+/// Add one template, returning where it went and what was written
 fn download_single_template(
+    base: &str,
     template_name: &str,
     dir_path: Option<&PathBuf>,
     force: bool,
     output: Option<String>,
-) -> anyhow::Result<()> {
+    vars: &Variables,
+) -> anyhow::Result<(PathBuf, String)> {
     let fetcher = Fetcher::new();
 
-    let url = format!("{}/pr-templates/{}.md", GITHUB_RAW_BASE, template_name);
+    let url = format!("{}/{}.md", base, template_name);
 
     let msg = format!("Downloading pull request template: {}", template_name);
     let pb = progress::spinner(&msg);
@@ -163,7 +266,19 @@ fn download_single_template(
         }
     };
 
-    file::save_file(&content, &dest_path, force)?;
+    // The upstream content stays the merge base, so `update` keeps the filled-in values
+    let rendered = templating::render(&content, &vars.params, vars.interactive)?;
+    // A default template holding only the generated chooser is replaced without --force
+    let force = force || chooser::only_chooser(&dest_path);
+    file::save_file(&rendered, &dest_path, force)?;
+    lockfile::record(
+        &dest_path,
+        TemplateCategory::PR,
+        template_name,
+        &url,
+        &content,
+        &rendered,
+    )?;
 
-    Ok(())
+    Ok((dest_path, rendered))
 }
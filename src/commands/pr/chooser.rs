@@ -0,0 +1,148 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use colored::*;
+
+use crate::utils::file;
+use crate::utils::git;
+use crate::utils::lockfile;
+
+pub(crate) const DEFAULT_TEMPLATE: &str = ".github/pull_request_template.md";
+pub(crate) const TEMPLATE_DIR: &str = ".github/PULL_REQUEST_TEMPLATE";
+
+const START: &str = "<!-- gh-templates:chooser:start -->";
+const END: &str = "<!-- gh-templates:chooser:end -->";
+
+/// File names of the templates in `.github/PULL_REQUEST_TEMPLATE/`, sorted
+pub(crate) fn alternatives(root: &Path) -> Result<Vec<String>> {
+    let dir = root.join(TEMPLATE_DIR);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut names: Vec<String> = fs::read_dir(&dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| name.ends_with(".md"))
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+/// Write or refresh the list of alternative templates in the default
+/// template once more than one template is installed.
+///
+/// GitHub only pre-fills the default template; the others are reached by
+/// adding `?template=NAME.md` to the compare page, so the list links to the
+/// compare page of the `origin` remote. Without a remote on GitHub, the
+/// links are relative query strings that work on the compare page itself.
+///
+/// `written` holds the templates this run wrote, keyed by their path from
+/// the repository root; a dry run has not put them on disk yet.
+pub(crate) fn update(root: &Path, written: &[(PathBuf, String)]) -> Result<()> {
+    let planned = |path: &Path| {
+        written
+            .iter()
+            .find(|(written_path, _)| written_path == path)
+            .map(|(_, content)| content.clone())
+    };
+
+    let mut alternatives = alternatives(root)?;
+    if file::is_dry_run() {
+        for (path, _) in written {
+            if path.parent() == Some(Path::new(TEMPLATE_DIR)) {
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                if name.ends_with(".md") && !alternatives.iter().any(|a| *a == name) {
+                    alternatives.push(name.to_string());
+                }
+            }
+        }
+        alternatives.sort();
+    }
+
+    let default_path = root.join(DEFAULT_TEMPLATE);
+    let existing = match planned(Path::new(DEFAULT_TEMPLATE)) {
+        Some(content) if file::is_dry_run() => Some(content),
+        _ => fs::read_to_string(&default_path).ok(),
+    };
+
+    let installed = alternatives.len() + usize::from(existing.is_some());
+    if alternatives.is_empty() || installed < 2 {
+        return Ok(());
+    }
+
+    let web_url = git::remote_web_url(root, "origin").ok();
+    let block = block(&alternatives, web_url.as_deref());
+    let content = match &existing {
+        Some(existing) => replace_block(existing, &block),
+        None => block,
+    };
+
+    match existing {
+        Some(existing) if existing == content => Ok(()),
+        Some(_) if file::is_dry_run() => {
+            file::save_file(&content, Path::new(DEFAULT_TEMPLATE), true)
+        }
+        Some(existing) => {
+            file::overwrite_file(&content, &default_path)?;
+            lockfile::record_rewrite(root, DEFAULT_TEMPLATE, &existing, &content)?;
+            println!("{} Updated {}", "✓".green(), DEFAULT_TEMPLATE);
+            Ok(())
+        }
+        None => file::save_file(&content, Path::new(DEFAULT_TEMPLATE), false),
+    }
+}
+
+/// Whether the file at `dest` holds nothing but a generated chooser, so a
+/// template may replace it without --force
+pub(crate) fn only_chooser(dest: &Path) -> bool {
+    file::resolve_output_path(dest)
+        .and_then(|path| Ok(fs::read_to_string(path)?))
+        .is_ok_and(|content| is_generated(&content))
+}
+
+fn is_generated(content: &str) -> bool {
+    match (content.find(START), content.find(END)) {
+        (Some(start), Some(end)) if start < end => {
+            let rest = format!("{}{}", &content[..start], &content[end + END.len()..]);
+            rest.trim().is_empty()
+        }
+        _ => false,
+    }
+}
+
+fn block(alternatives: &[String], web_url: Option<&str>) -> String {
+    let links: Vec<String> = alternatives
+        .iter()
+        .map(|name| {
+            let label = name.strip_suffix(".md").unwrap_or(name);
+            let url = match web_url {
+                Some(web_url) => git::compare_url(web_url, None, name),
+                None => format!("?expand=1&template={}", name),
+            };
+            format!("[{}]({})", label, url)
+        })
+        .collect();
+
+    format!(
+        "{}\n<!-- Need another template? Pick one below, or run `gh-templates pr link <template>` for a link to your branch. -->\n**Templates:** {}\n{}\n",
+        START,
+        links.join(" · "),
+        END
+    )
+}
+
+/// Swap the generated block of `existing` for `block`, or put it on top
+fn replace_block(existing: &str, block: &str) -> String {
+    if let (Some(start), Some(end)) = (existing.find(START), existing.find(END)) {
+        if start < end {
+            let after = existing[end + END.len()..]
+                .strip_prefix('\n')
+                .unwrap_or(&existing[end + END.len()..]);
+            return format!("{}{}{}", &existing[..start], block, after);
+        }
+    }
+    format!("{}\n{}", block, existing)
+}
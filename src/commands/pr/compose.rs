@@ -0,0 +1,195 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use colored::*;
+use serde::Deserialize;
+
+use crate::utils::file;
+use crate::utils::get_comment;
+use crate::utils::progress;
+use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
+
+use super::chooser::{self, DEFAULT_TEMPLATE, TEMPLATE_DIR};
+use super::templates_base;
+
+// Command to build a pull request template from section fragments
+
+#[derive(clap::Args, Debug)]
+pub struct ComposeArgs {
+    /// Fragments to assemble, in order (e.g., summary,testing,security,screenshots)
+    #[arg(
+        long,
+        value_name = "NAMES",
+        value_delimiter = ',',
+        required_unless_present = "list"
+    )]
+    pub sections: Vec<String>,
+
+    /// List the available fragments instead of composing a template
+    #[arg(long, conflicts_with = "sections")]
+    pub list: bool,
+
+    /// Name of the template in .github/PULL_REQUEST_TEMPLATE (default: the default template)
+    #[arg(short = 'o', long, value_name = "OUTPUT")]
+    pub output: Option<String>,
+
+    /// Directory to save the pull request template file
+    #[arg(long, value_name = "DIR")]
+    pub dir: Option<PathBuf>,
+
+    /// Force overwrite an existing pull request template file
+    #[arg(long)]
+    pub force: bool,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
+}
+
+/// `fragments` section of the pull request manifest
+#[derive(Debug, Default, Deserialize)]
+struct FragmentManifest {
+    #[serde(default)]
+    fragments: serde_yaml::Mapping,
+}
+
+/// A named Markdown section that templates are composed from
+struct Fragment {
+    name: String,
+    url: String,
+}
+
+impl super::Runnable for ComposeArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        let base = templates_base(&self.source)?;
+        let fragments = list_fragments(&base)?;
+        if fragments.is_empty() {
+            return Err(anyhow!(
+                "The pull request templates of this source define no fragments."
+            ));
+        }
+
+        if self.list {
+            return print_fragments(&fragments);
+        }
+
+        let mut selected = Vec::new();
+        for name in self
+            .sections
+            .iter()
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            let fragment = fragments
+                .iter()
+                .find(|f| f.name.eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    let available: Vec<&str> = fragments.iter().map(|f| f.name.as_str()).collect();
+                    anyhow!(
+                        "Unknown fragment '{}'. Available: {}",
+                        name,
+                        available.join(", ")
+                    )
+                })?;
+            if selected.iter().any(|f: &&Fragment| f.name == fragment.name) {
+                return Err(anyhow!("Fragment '{}' is listed twice.", fragment.name));
+            }
+            selected.push(fragment);
+        }
+        if selected.is_empty() {
+            return Err(anyhow!("No fragments given to --sections."));
+        }
+
+        let fetcher = Fetcher::new();
+        let pb = progress::spinner("Fetching pull request template fragments");
+        let bodies = selected
+            .iter()
+            .map(|fragment| fetcher.fetch_content(&fragment.url).map(|c| body(&c)))
+            .collect::<anyhow::Result<Vec<String>>>();
+        pb.finish_and_clear();
+        let template = bodies?.join("\n");
+
+        let dest = self.destination();
+        let force = self.force || chooser::only_chooser(&dest);
+        file::save_file(&template, &dest, force)?;
+
+        if self.dir.is_none() {
+            chooser::update(&file::find_repo_root()?, &[(dest, template)])?;
+        }
+        Ok(())
+    }
+}
+
+impl ComposeArgs {
+    /// The default template, or `--output` among the other templates
+    fn destination(&self) -> PathBuf {
+        let filename = match &self.output {
+            Some(name) if Path::new(name).extension().is_none() => format!("{}.md", name),
+            Some(name) => name.clone(),
+            None => "pull_request_template.md".to_string(),
+        };
+
+        match (&self.dir, &self.output) {
+            (Some(dir), _) => dir.join(filename),
+            (None, Some(_)) => Path::new(TEMPLATE_DIR).join(filename),
+            (None, None) => PathBuf::from(DEFAULT_TEMPLATE),
+        }
+    }
+}
+
+/// Fragments of the manifest in `base`, in manifest order
+fn list_fragments(base: &str) -> anyhow::Result<Vec<Fragment>> {
+    let manifest_url = format!("{}/manifest.yml", base);
+    let content = Fetcher::new().fetch_content(&manifest_url)?;
+    let manifest: FragmentManifest = serde_yaml::from_str(&content)
+        .map_err(|e| anyhow!("Failed to parse '{}': {}", manifest_url, e))?;
+
+    manifest
+        .fragments
+        .iter()
+        .map(|(name, path)| match (name.as_str(), path.as_str()) {
+            (Some(name), Some(path)) => Ok(Fragment {
+                name: name.to_string(),
+                url: format!("{}/{}", base, path.trim_start_matches('/')),
+            }),
+            _ => Err(anyhow!(
+                "Invalid fragment entry in '{}': expected `name: path`.",
+                manifest_url
+            )),
+        })
+        .collect()
+}
+
+fn print_fragments(fragments: &[Fragment]) -> anyhow::Result<()> {
+    let fetcher = Fetcher::new();
+    println!("{} Available pull request template fragments:", "✓".green());
+    for fragment in fragments {
+        let description = fetcher.fetch_content(&fragment.url).ok().and_then(|c| {
+            c.lines()
+                .next()
+                .and_then(|l| get_comment::extract_comment(l, "md"))
+        });
+        match description {
+            Some(description) => {
+                println!("  {} {:<16} - {}", ">".green(), fragment.name, description)
+            }
+            None => println!("  {}", fragment.name),
+        }
+    }
+    Ok(())
+}
+
+/// The fragment without its first-line description comment
+fn body(content: &str) -> String {
+    let mut lines = content.lines();
+    let first = lines.next().unwrap_or_default();
+    let is_description =
+        first.trim_start().starts_with("<!--") && first.trim_end().ends_with("-->");
+
+    let body = if is_description {
+        lines.collect::<Vec<_>>().join("\n")
+    } else {
+        content.to_string()
+    };
+    format!("{}\n", body.trim())
+}
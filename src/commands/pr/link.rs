@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use anyhow::anyhow;
+
+use crate::utils::file;
+use crate::utils::git;
+
+use super::chooser::{self, TEMPLATE_DIR};
+
+// Command to print the compare URL that opens a pull request with a template
+
+#[derive(clap::Args, Debug)]
+pub struct LinkArgs {
+    /// Template in .github/PULL_REQUEST_TEMPLATE (e.g. bugfix or bugfix.md)
+    #[arg(value_name = "TEMPLATE")]
+    pub template: String,
+
+    /// Branch with the changes (default: the current branch)
+    #[arg(long, value_name = "BRANCH")]
+    pub head: Option<String>,
+
+    /// Branch to merge into (default: the repository's default branch)
+    #[arg(long, value_name = "BRANCH")]
+    pub base: Option<String>,
+
+    /// Git remote of the repository on GitHub
+    #[arg(long, value_name = "NAME", default_value = "origin")]
+    pub remote: String,
+}
+
+impl super::Runnable for LinkArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        let root = file::find_repo_root()?;
+
+        let name = if Path::new(&self.template).extension().is_some() {
+            self.template.clone()
+        } else {
+            format!("{}.md", self.template)
+        };
+        let installed = chooser::alternatives(&root)?;
+        if !installed.contains(&name) {
+            let hint = if installed.is_empty() {
+                "No templates are installed there; add some with `gh-templates pr add`.".to_string()
+            } else {
+                format!("Installed: {}", installed.join(", "))
+            };
+            return Err(anyhow!("'{}' is not in {}/. {}", name, TEMPLATE_DIR, hint));
+        }
+
+        let web_url = git::remote_web_url(&root, &self.remote)?;
+        let head = match &self.head {
+            Some(head) => head.clone(),
+            None => git::current_branch(&root).ok_or_else(|| {
+                anyhow!("Not on a branch. Pass the branch with the changes using --head.")
+            })?,
+        };
+
+        // Without a base GitHub compares against the default branch
+        let refs = match &self.base {
+            Some(base) => format!("{}...{}", base, head),
+            None => head,
+        };
+        println!("{}", git::compare_url(&web_url, Some(&refs), &name));
+        Ok(())
+    }
+}
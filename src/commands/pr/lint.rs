@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use colored::*;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+use crate::utils::file;
+
+use super::chooser::{DEFAULT_TEMPLATE, TEMPLATE_DIR};
+
+// Command to lint pull request templates
+
+#[derive(clap::Args, Debug)]
+pub struct LintArgs {
+    /// Template file or directory to lint (default: the repository's PR templates)
+    #[arg(value_name = "PATH")]
+    pub paths: Vec<PathBuf>,
+
+    /// Most task list items allowed in one checklist
+    #[arg(long, value_name = "N", default_value_t = 15)]
+    pub max_checklist: usize,
+}
+
+/// A problem found in a template, with the 1-based line it starts on
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Problem {
+    line: usize,
+    message: String,
+}
+
+impl super::Runnable for LintArgs {
+    fn run(&self) -> anyhow::Result<()> {
+        let files = if self.paths.is_empty() {
+            repository_templates()?
+        } else {
+            let mut files = Vec::new();
+            for path in &self.paths {
+                collect_templates(path, &mut files)?;
+            }
+            files
+        };
+
+        if files.is_empty() {
+            return Err(anyhow::anyhow!(
+                "No pull request templates (*.md) found to lint."
+            ));
+        }
+
+        let mut failed = 0;
+        for path in &files {
+            let content = fs::read_to_string(path)
+                .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", path.display(), e))?;
+            let dir = path.parent().unwrap_or(Path::new("."));
+            let problems = lint(&content, dir, self.max_checklist);
+
+            if problems.is_empty() {
+                println!("{} {}", "✓".green(), path.display());
+                continue;
+            }
+
+            failed += 1;
+            for problem in problems {
+                eprintln!(
+                    "{} {}:{}: {}",
+                    "✗".red(),
+                    path.display(),
+                    problem.line,
+                    problem.message
+                );
+            }
+        }
+
+        if failed > 0 {
+            return Err(anyhow::anyhow!(
+                "{} of {} pull request template(s) have problems.",
+                failed,
+                files.len()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The default template and the templates in `.github/PULL_REQUEST_TEMPLATE/`
+fn repository_templates() -> anyhow::Result<Vec<PathBuf>> {
+    let root = file::find_repo_root()?;
+    let mut files = Vec::new();
+
+    let default = root.join(DEFAULT_TEMPLATE);
+    if default.is_file() {
+        files.push(default);
+    }
+    let dir = root.join(TEMPLATE_DIR);
+    if dir.is_dir() {
+        collect_templates(&dir, &mut files)?;
+    }
+    Ok(files)
+}
+
+/// `path` itself when it is a file, or the Markdown files directly inside it
+fn collect_templates(path: &Path, files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    if path.is_file() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    let entries = fs::read_dir(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", path.display(), e))?;
+    let mut templates: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    templates.sort();
+    files.extend(templates);
+    Ok(())
+}
+
+/// Check a template for empty sections, duplicate headings, unbalanced HTML
+/// comments, relative links to missing files (resolved from `dir`) and task
+/// lists longer than `max_checklist`
+fn lint(content: &str, dir: &Path, max_checklist: usize) -> Vec<Problem> {
+    let line_of = |offset: usize| content[..offset].matches('\n').count() + 1;
+    let mut problems = Vec::new();
+
+    let mut code: Vec<Range<usize>> = Vec::new();
+    // Heading being read: level, text, line
+    let mut heading: Option<(usize, String, usize)> = None;
+    // Section waiting for content: level, text, line
+    let mut open_section: Option<(usize, String, usize)> = None;
+    let mut seen_headings: HashMap<String, usize> = HashMap::new();
+    // Outermost list: line and task items so far, and the nesting depth
+    let mut checklist: Option<(usize, usize)> = None;
+    let mut list_depth = 0;
+
+    for (event, range) in Parser::new_ext(content, Options::ENABLE_TASKLISTS).into_offset_iter() {
+        if let Some((_, text, _)) = heading.as_mut() {
+            match event {
+                Event::Text(t) | Event::Code(t) => {
+                    text.push_str(&t);
+                    continue;
+                }
+                Event::End(TagEnd::Heading(_)) => {}
+                _ => continue,
+            }
+        }
+
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                let level = level as usize;
+                if let Some((open_level, text, line)) = open_section.take() {
+                    // A deeper heading starts a subsection, so the section is not empty
+                    if level <= open_level {
+                        problems.push(Problem {
+                            line,
+                            message: format!("Section '{}' is empty.", text),
+                        });
+                    }
+                }
+                heading = Some((level, String::new(), line_of(range.start)));
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                let (level, text, line) = heading.take().expect("heading was started");
+                let text = text.trim().trim_end_matches(':').trim().to_string();
+                if text.is_empty() {
+                    problems.push(Problem {
+                        line,
+                        message: "Heading has no text.".to_string(),
+                    });
+                } else if let Some(first) = seen_headings.get(&text.to_lowercase()) {
+                    problems.push(Problem {
+                        line,
+                        message: format!("Duplicate heading '{}' (first on line {}).", text, first),
+                    });
+                } else {
+                    seen_headings.insert(text.to_lowercase(), line);
+                }
+                open_section = Some((level, text, line));
+            }
+            Event::Start(Tag::CodeBlock(_)) | Event::Code(_) => {
+                code.push(range.clone());
+                open_section = None;
+            }
+            Event::Start(Tag::Link { dest_url, .. })
+            | Event::Start(Tag::Image { dest_url, .. }) => {
+                if let Some(message) = broken_link(&dest_url, dir) {
+                    problems.push(Problem {
+                        line: line_of(range.start),
+                        message,
+                    });
+                }
+                open_section = None;
+            }
+            Event::Start(Tag::List(_)) => {
+                if list_depth == 0 {
+                    checklist = Some((line_of(range.start), 0));
+                }
+                list_depth += 1;
+                open_section = None;
+            }
+            Event::End(TagEnd::List(_)) => {
+                list_depth -= 1;
+                if list_depth == 0 {
+                    if let Some((line, items)) = checklist.take() {
+                        if items > max_checklist {
+                            problems.push(Problem {
+                                line,
+                                message: format!(
+                                    "Checklist has {} items (at most {}); split it or trim it.",
+                                    items, max_checklist
+                                ),
+                            });
+                        }
+                    }
+                }
+            }
+            Event::TaskListMarker(_) => {
+                if let Some((_, items)) = checklist.as_mut() {
+                    *items += 1;
+                }
+            }
+            Event::End(_) => {}
+            // Text, HTML comments, rules and the like all fill a section
+            _ => open_section = None,
+        }
+    }
+
+    if let Some((_, text, line)) = open_section {
+        problems.push(Problem {
+            line,
+            message: format!("Section '{}' is empty.", text),
+        });
+    }
+
+    problems.extend(comment_problems(content, &code, line_of));
+    problems.sort_by_key(|problem| problem.line);
+    problems
+}
+
+/// `<!--` without a matching `-->`, `-->` without an opening `<!--`, and
+/// comments opened inside comments (the first `-->` closes both).
+/// Code spans and blocks are skipped.
+fn comment_problems(
+    content: &str,
+    code: &[Range<usize>],
+    line_of: impl Fn(usize) -> usize,
+) -> Vec<Problem> {
+    let in_code = |offset: usize| code.iter().any(|block| block.contains(&offset));
+    let mut problems = Vec::new();
+    let mut open: Option<usize> = None;
+    let mut index = 0;
+
+    while index < content.len() {
+        let rest = &content[index..];
+        if rest.starts_with("<!--") && !in_code(index) {
+            match open {
+                Some(start) => problems.push(Problem {
+                    line: line_of(index),
+                    message: format!(
+                        "HTML comment opened inside the comment from line {}; comments do not nest.",
+                        line_of(start)
+                    ),
+                }),
+                None => open = Some(index),
+            }
+            index += 4;
+        } else if rest.starts_with("-->") && !in_code(index) {
+            if open.take().is_none() {
+                problems.push(Problem {
+                    line: line_of(index),
+                    message: "'-->' closes an HTML comment that was never opened.".to_string(),
+                });
+            }
+            index += 3;
+        } else {
+            index += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+
+    if let Some(start) = open {
+        problems.push(Problem {
+            line: line_of(start),
+            message: "HTML comment is never closed with '-->'; the rest of the template is hidden."
+                .to_string(),
+        });
+    }
+    problems
+}
+
+/// A message when `url` is a relative link to a file that does not exist
+fn broken_link(url: &str, dir: &Path) -> Option<String> {
+    let is_external = url.contains("://")
+        || url.starts_with("mailto:")
+        || url.starts_with("//")
+        || url.starts_with('#')
+        || url.starts_with('?');
+    if url.is_empty() || is_external {
+        return None;
+    }
+
+    let target = url.split(['#', '?']).next().unwrap_or_default();
+    let path = match target.strip_prefix('/') {
+        Some(from_root) => file::find_repo_root().ok()?.join(from_root),
+        None => dir.join(target),
+    };
+    (!path.exists()).then(|| format!("Relative link '{}' points to a missing file.", url))
+}
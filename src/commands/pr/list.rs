@@ -1,54 +1,76 @@
 use colored::*;
 
+use crate::commands::base::TemplateCategory;
 use crate::utils::get_comment;
 use crate::utils::manifest_navigator::ManifestNavigator;
+use crate::utils::output::{print_records, FormatArgs, TemplateRecord};
 use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
 
-use super::GITHUB_RAW_BASE;
+use super::templates_base;
 
 #[derive(clap::Args)]
 pub struct ListArgs {
-    // You can add options here if needed in the future
+    #[command(flatten)]
+    pub source: SourceArgs,
+
+    #[command(flatten)]
+    pub format: FormatArgs,
 }
 
 impl super::Runnable for ListArgs {
     fn run(&self) -> anyhow::Result<()> {
-        list_all_pr_templates()
+        let templates = list_all_pr_templates(&templates_base(&self.source)?)?;
+
+        if !self.format.is_text() {
+            return print_records(&templates, self.format.format, TemplateRecord::COLUMNS);
+        }
+
+        if templates.is_empty() {
+            println!("No pull request templates found.");
+        } else {
+            println!("{} Available pull request templates:", "✓".green());
+            for template in templates {
+                match template.description {
+                    Some(description) => {
+                        println!("  {} {:<12} - {}", ">".green(), template.name, description)
+                    }
+                    None => println!("  {}", template.name),
+                }
+            }
+        }
+        Ok(())
     }
 }
 
-fn list_all_pr_templates() -> anyhow::Result<()> {
+pub(super) fn list_all_pr_templates(base: &str) -> anyhow::Result<Vec<TemplateRecord>> {
     let fetcher = Fetcher::new();
 
-    let manifest_url = format!("{}/pr-templates/manifest.yml", GITHUB_RAW_BASE);
+    let manifest_url = format!("{}/manifest.yml", base);
     let manifest_navigator = ManifestNavigator::new(&manifest_url)?;
     let template_entries = manifest_navigator.list_entries()?;
 
-    if template_entries.is_empty() {
-        println!("No pull request templates found.");
-    } else {
-        println!("{} Available pull request templates:", "✓".green());
-        for entry in template_entries {
-            let file_url = &entry.full_url;
-            let extension = std::path::Path::new(file_url)
-                .extension()
-                .and_then(|ext| ext.to_str())
-                .unwrap_or("");
-            let comment = match fetcher.fetch_content(file_url) {
-                Ok(text) => text
-                    .lines()
-                    .next()
-                    .and_then(|line| get_comment::extract_comment(line, extension)),
-                _ => None,
-            };
-
-            match comment {
-                Some(description) => {
-                    println!("  {} {:<12} - {}", ">".green(), entry.name, description)
-                }
-                None => println!("  {}", entry.name),
-            }
-        }
+    let mut templates = Vec::new();
+    for entry in template_entries {
+        let file_url = &entry.full_url;
+        let extension = std::path::Path::new(file_url)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("");
+        let comment = match fetcher.fetch_content(file_url) {
+            Ok(text) => text
+                .lines()
+                .next()
+                .and_then(|line| get_comment::extract_comment(line, extension)),
+            _ => None,
+        };
+
+        templates.push(TemplateRecord {
+            name: entry.name.clone(),
+            category: TemplateCategory::PR.config_key(),
+            url: file_url.clone(),
+            description: comment,
+        });
     }
-    Ok(())
+    Ok(templates)
 }
@@ -1,8 +1,14 @@
 use clap::Subcommand;
 
-use crate::commands::base::Runnable;
+use crate::commands::base::{Runnable, TemplateCategory};
+use crate::utils::picker::Candidate;
+use crate::utils::source::SourceArgs;
 
-mod add;
+pub(crate) mod add;
+mod chooser;
+mod compose;
+mod link;
+mod lint;
 mod list;
 mod preview;
 
@@ -14,6 +20,12 @@ const GITHUB_RAW_BASE: &str =
 pub enum Command {
     /// Add one or more PR templates to the repository
     Add(add::AddArgs),
+    /// Build a PR template from reusable section fragments
+    Compose(compose::ComposeArgs),
+    /// Print the URL that opens a pull request with an installed template
+    Link(link::LinkArgs),
+    /// Check installed PR templates for common mistakes
+    Lint(lint::LintArgs),
     /// List available PR templates
     List(list::ListArgs),
     /// Preview a specific PR template
@@ -24,8 +36,34 @@ impl Command {
     pub fn execute(&self) -> anyhow::Result<()> {
         match self {
             Command::Add(args) => args.run(),
+            Command::Compose(args) => args.run(),
+            Command::Link(args) => args.run(),
+            Command::Lint(args) => args.run(),
             Command::List(args) => args.run(),
             Command::Preview(args) => args.run(),
         }
     }
 }
+
+/// Base URL of the pr-templates directory for the selected source
+fn templates_base(source: &SourceArgs) -> anyhow::Result<String> {
+    source.resolve(
+        TemplateCategory::PR,
+        &format!("{}/pr-templates", GITHUB_RAW_BASE),
+    )
+}
+
+/// Templates offered by `pick` and by `pr add` without arguments
+pub(crate) fn pick_candidates(source: &SourceArgs) -> anyhow::Result<Vec<Candidate>> {
+    let records = list::list_all_pr_templates(&templates_base(source)?)?;
+
+    Ok(records
+        .into_iter()
+        .map(|mut record| {
+            if let Some(name) = record.name.strip_suffix(".md") {
+                record.name = name.to_string();
+            }
+            Candidate::from_record(record, "md")
+        })
+        .collect())
+}
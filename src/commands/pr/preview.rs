@@ -1,13 +1,17 @@
 use crate::utils::pretty_print;
 use crate::utils::progress;
 use crate::utils::remote::Fetcher;
+use crate::utils::source::SourceArgs;
 
-use super::GITHUB_RAW_BASE;
+use super::templates_base;
 
 #[derive(clap::Args)]
 pub struct PreviewArgs {
     #[arg(help = "PR template names to preview")]
     pub args: Vec<String>,
+
+    #[command(flatten)]
+    pub source: SourceArgs,
 }
 
 impl super::Runnable for PreviewArgs {
@@ -18,17 +22,18 @@ impl super::Runnable for PreviewArgs {
             ));
         }
 
+        let base = templates_base(&self.source)?;
         for template_name in &self.args {
-            preview_single_template(template_name)?;
+            preview_single_template(&base, template_name)?;
         }
 
         Ok(())
     }
 }
 
-fn preview_single_template(template: &str) -> anyhow::Result<()> {
+fn preview_single_template(base: &str, template: &str) -> anyhow::Result<()> {
     let fetcher = Fetcher::new();
-    let url = format!("{}/pr-templates/{}.md", GITHUB_RAW_BASE, template);
+    let url = format!("{}/{}.md", base, template);
 
     let pb = progress::spinner(&format!("Fetching PR template: {}", template));
     let content = fetcher.fetch_content(&url)?;
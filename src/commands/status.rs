@@ -0,0 +1,49 @@
+use anyhow::Result;
+use colored::*;
+
+use crate::commands::base::Runnable;
+use crate::utils::file;
+use crate::utils::lockfile::{self, FileState, Lockfile, LOCKFILE};
+
+// Command to report drift between installed templates and upstream
+
+#[derive(clap::Args, Debug)]
+pub struct StatusArgs {}
+
+impl Runnable for StatusArgs {
+    fn run(&self) -> Result<()> {
+        let root = file::find_repo_root()?;
+        let lockfile = Lockfile::load(&root)?;
+
+        if lockfile.files.is_empty() {
+            println!(
+                "No templates are tracked yet. Files added with `issue add`, `pr add` or `ci add` are recorded in {}.",
+                LOCKFILE
+            );
+            return Ok(());
+        }
+
+        for status in lockfile::check(&root, &lockfile) {
+            match status {
+                Ok(status) => println!(
+                    "  {} {:<48} {}",
+                    ">".green(),
+                    status.path,
+                    colored_label(status.state)
+                ),
+                Err(e) => eprintln!("  {} {}", "✗".red(), e),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn colored_label(state: FileState) -> ColoredString {
+    match state {
+        FileState::Unchanged => state.label().green(),
+        FileState::Modified => state.label().yellow(),
+        FileState::Outdated => state.label().cyan(),
+        FileState::Diverged | FileState::Missing => state.label().red(),
+    }
+}
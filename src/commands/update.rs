@@ -0,0 +1,183 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use colored::*;
+
+use crate::commands::base::Runnable;
+use crate::commands::status::colored_label;
+use crate::utils::file;
+use crate::utils::lockfile::{self, FileState, FileStatus, Lockfile};
+
+// Command to refresh installed templates from upstream
+
+#[derive(clap::Args, Debug)]
+pub struct UpdateArgs {
+    /// Only update these files (paths relative to the repository root)
+    #[arg(value_name = "PATH")]
+    pub paths: Vec<String>,
+
+    /// Show a diff instead of merging upstream changes into locally modified files
+    #[arg(long)]
+    pub no_merge: bool,
+}
+
+impl Runnable for UpdateArgs {
+    fn run(&self) -> Result<()> {
+        let root = file::find_repo_root()?;
+        let mut lockfile = Lockfile::load(&root)?;
+
+        if let Some(path) = self
+            .paths
+            .iter()
+            .find(|path| !lockfile.files.contains_key(path.as_str()))
+        {
+            return Err(anyhow!(
+                "'{}' is not tracked. Run `gh-templates status` to see tracked files.",
+                path
+            ));
+        }
+
+        let mut updated = 0;
+        let mut conflicts = Vec::new();
+        for status in lockfile::check(&root, &lockfile) {
+            let status = match status {
+                Ok(status) => status,
+                Err(e) => {
+                    eprintln!("  {} {}", "✗".red(), e);
+                    continue;
+                }
+            };
+
+            if !self.paths.is_empty() && !self.paths.contains(&status.path) {
+                continue;
+            }
+
+            // Files filled in from their template are merged, so the values are kept
+            let filled_in = lockfile
+                .files
+                .get(&status.path)
+                .is_some_and(|entry| entry.base.is_some());
+            let mut keeps_values = false;
+
+            let new_content = match status.state {
+                FileState::Outdated if filled_in => {
+                    let Some(merged) = merge(&root, &lockfile, &status)? else {
+                        continue;
+                    };
+                    match merged {
+                        Ok(merged) => {
+                            println!("{} {} - has been updated.", "✓".green(), status.path);
+                            keeps_values = true;
+                            merged
+                        }
+                        Err(conflicted) => {
+                            println!("{} {} - merged with conflicts.", "⚠".yellow(), status.path);
+                            conflicts.push(status.path.clone());
+                            conflicted
+                        }
+                    }
+                }
+                FileState::Outdated => {
+                    println!("{} {} - has been updated.", "✓".green(), status.path);
+                    status.upstream.clone()
+                }
+                FileState::Diverged if !self.no_merge => {
+                    let Some(merged) = merge(&root, &lockfile, &status)? else {
+                        continue;
+                    };
+                    match merged {
+                        Ok(merged) => {
+                            println!("{} {} - merged upstream changes.", "✓".green(), status.path);
+                            merged
+                        }
+                        Err(conflicted) => {
+                            println!("{} {} - merged with conflicts.", "⚠".yellow(), status.path);
+                            conflicts.push(status.path.clone());
+                            conflicted
+                        }
+                    }
+                }
+                FileState::Diverged => {
+                    print_refusal(&root, &status, "merging disabled")?;
+                    continue;
+                }
+                FileState::Modified | FileState::Missing => {
+                    println!(
+                        "{} {} - skipped ({})",
+                        "⚠".yellow(),
+                        status.path,
+                        colored_label(status.state)
+                    );
+                    continue;
+                }
+                FileState::Unchanged => continue,
+            };
+
+            file::overwrite_file(&new_content, &root.join(&status.path))?;
+            if let Some(entry) = lockfile.files.get_mut(&status.path) {
+                let written = if keeps_values {
+                    &new_content
+                } else {
+                    &status.upstream
+                };
+                entry.set_installed(&status.upstream, written);
+            }
+            updated += 1;
+        }
+
+        lockfile.save(&root)?;
+
+        if !conflicts.is_empty() {
+            return Err(anyhow!(
+                "Merge conflicts in: {}. Resolve the conflict markers, then review the result.",
+                conflicts.join(", ")
+            ));
+        }
+
+        if updated == 0 {
+            println!("All tracked templates are up to date.");
+        }
+
+        Ok(())
+    }
+}
+
+/// Three-way merge of local edits and upstream changes against the installed version.
+/// `Ok(None)` means the installed version is unknown and the file was left untouched.
+fn merge(
+    root: &Path,
+    lockfile: &Lockfile,
+    status: &FileStatus,
+) -> Result<Option<std::result::Result<String, String>>> {
+    let Some(base) = lockfile
+        .files
+        .get(&status.path)
+        .and_then(|entry| lockfile::load_base(entry.base_hash()))
+    else {
+        print_refusal(root, status, "no installed version to merge against")?;
+        return Ok(None);
+    };
+
+    let local = fs::read_to_string(root.join(&status.path))?;
+    Ok(Some(diffy::merge(&base, &local, &status.upstream)))
+}
+
+/// Leave a diverged file alone and show how it differs from upstream
+fn print_refusal(root: &Path, status: &FileStatus, reason: &str) -> Result<()> {
+    let local = fs::read_to_string(root.join(&status.path))?;
+    let patch = diffy::create_patch(&local, &status.upstream);
+
+    println!(
+        "{} {} - skipped ({}; {}). Differences from upstream:",
+        "⚠".yellow(),
+        status.path,
+        colored_label(status.state),
+        reason
+    );
+    println!(
+        "{}",
+        diffy::PatchFormatter::new().with_color().fmt_patch(&patch)
+    );
+    Ok(())
+}
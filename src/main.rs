@@ -14,6 +14,18 @@ struct Cli {
     #[command(subcommand)]
     category: Option<commands::CategoryCommand>,
 
+    /// Serve every template from the local cache instead of the network
+    #[arg(long)]
+    offline: bool,
+
+    /// Fetch first-party templates from GitHub instead of the copies built into the binary
+    #[arg(long, conflicts_with = "offline")]
+    remote: bool,
+
+    /// Print what would be written, with diffs, without touching any files
+    #[arg(long)]
+    dry_run: bool,
+
     /// Show detailed version information
     #[arg(long = "build-info", help = "Display detailed build information")]
     build_info: bool,
@@ -27,6 +39,10 @@ fn main() -> anyhow::Result<()> {
         return Ok(());
     }
 
+    utils::remote::set_offline(cli.offline);
+    utils::remote::set_remote(cli.remote);
+    utils::file::set_dry_run(cli.dry_run);
+
     match cli.category {
         Some(category) => category.execute(),
         None => {
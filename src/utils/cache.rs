@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
@@ -119,7 +120,7 @@ impl<T> Cache<T> {
     pub fn filter_by_metadata(&self, key: &str, value: &str) -> Vec<(&String, &T)> {
         self.entries
             .iter()
-            .filter(|(_, entry)| entry.metadata.get(key).map_or(false, |v| v == value))
+            .filter(|(_, entry)| entry.metadata.get(key).is_some_and(|v| v == value))
             .map(|(k, entry)| (k, &entry.data))
             .collect()
     }
@@ -133,6 +134,10 @@ impl<T> Cache<T> {
     }
 }
 
+/// Sub-directory of the cache holding raw template bodies, one file per URL
+const CONTENT_CACHE_DIR: &str = "content";
+const OBJECT_CACHE_DIR: &str = "objects";
+
 pub struct CacheManager {
     cache_dir: PathBuf,
 }
@@ -265,6 +270,50 @@ impl CacheManager {
         Ok(caches)
     }
 
+    /// Path of the cached body for `url` inside the content cache, named by
+    /// the SHA-256 of the URL
+    pub fn get_content_path(&self, url: &str) -> PathBuf {
+        let file_name = format!("{:x}", Sha256::digest(url.as_bytes()));
+        self.cache_dir.join(CONTENT_CACHE_DIR).join(file_name)
+    }
+
+    /// Load a previously fetched body for `url`, if one is cached
+    pub fn load_content(&self, url: &str) -> Option<String> {
+        fs::read_to_string(self.get_content_path(url)).ok()
+    }
+
+    /// Store the body fetched from `url` so it can be served offline
+    pub fn save_content(&self, url: &str, content: &str) -> Result<()> {
+        let content_file = self.get_content_path(url);
+        if let Some(parent) = content_file.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create cache directory: {:?}", parent))?;
+        }
+
+        fs::write(&content_file, content)
+            .with_context(|| format!("Failed to write cache file: {:?}", content_file))?;
+
+        Ok(())
+    }
+
+    /// Load installed template content by its content hash
+    pub fn load_object(&self, hash: &str) -> Option<String> {
+        fs::read_to_string(self.cache_dir.join(OBJECT_CACHE_DIR).join(hash)).ok()
+    }
+
+    /// Keep installed template content so later updates can merge against it
+    pub fn save_object(&self, hash: &str, content: &str) -> Result<()> {
+        let object_dir = self.cache_dir.join(OBJECT_CACHE_DIR);
+        fs::create_dir_all(&object_dir)
+            .with_context(|| format!("Failed to create cache directory: {:?}", object_dir))?;
+
+        let object_file = object_dir.join(hash);
+        fs::write(&object_file, content)
+            .with_context(|| format!("Failed to write cache file: {:?}", object_file))?;
+
+        Ok(())
+    }
+
     pub fn should_update_cache<T>(&self, cache_name: &str, max_age_seconds: u64) -> Result<bool>
     where
         T: for<'de> Deserialize<'de>,
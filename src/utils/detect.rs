@@ -0,0 +1,294 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+
+/// How deep below the repository root marker files are looked for
+const MAX_DEPTH: usize = 3;
+
+/// Directories that hold dependencies or build output rather than project sources
+const SKIPPED_DIRS: &[&str] = &[
+    ".git",
+    "node_modules",
+    "target",
+    "vendor",
+    "dist",
+    "build",
+    ".venv",
+    "venv",
+    "__pycache__",
+];
+
+/// A template suggested for the repository and the file that suggested it
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct Suggestion {
+    /// Command category, as in `.gh-templates.toml` (`gitignore`, `ci`, `license`)
+    pub category: &'static str,
+    /// Name to pass to `<category> add`
+    pub template: String,
+    pub reason: String,
+}
+
+impl Suggestion {
+    pub const COLUMNS: &'static [&'static str] = &["category", "template", "reason"];
+}
+
+/// A marker file or directory and the templates it implies
+struct Marker {
+    /// File name, directory name (with a trailing `/`) or `*.ext` suffix
+    name: &'static str,
+    gitignore: &'static [&'static str],
+    ci: &'static [&'static str],
+}
+
+const MARKERS: &[Marker] = &[
+    Marker {
+        name: "Cargo.toml",
+        gitignore: &["rust"],
+        ci: &[],
+    },
+    Marker {
+        name: "pyproject.toml",
+        gitignore: &["python"],
+        ci: &["python/lint/ruff", "python/test/pytest"],
+    },
+    Marker {
+        name: "requirements.txt",
+        gitignore: &["python"],
+        ci: &["python/lint/ruff", "python/test/pytest"],
+    },
+    Marker {
+        name: "setup.py",
+        gitignore: &["python"],
+        ci: &["python/lint/ruff", "python/test/pytest"],
+    },
+    Marker {
+        name: "poetry.lock",
+        gitignore: &["python"],
+        ci: &["python/cache/poetry"],
+    },
+    Marker {
+        name: "package.json",
+        gitignore: &["node"],
+        ci: &[],
+    },
+    Marker {
+        name: "go.mod",
+        gitignore: &["go"],
+        ci: &[],
+    },
+    Marker {
+        name: ".idea/",
+        gitignore: &["global-jetbrains"],
+        ci: &[],
+    },
+    Marker {
+        name: ".vscode/",
+        gitignore: &["global-visualstudiocode"],
+        ci: &[],
+    },
+    Marker {
+        name: "*.sln",
+        gitignore: &["visualstudio"],
+        ci: &[],
+    },
+];
+
+/// Scan `root` for marker files and the host OS, and suggest gitignore, CI
+/// and license templates for them. Suggestions are unique and in a stable
+/// order: gitignore, then CI, then license.
+pub fn detect(root: &Path) -> Result<Vec<Suggestion>> {
+    let mut found = Vec::new();
+    scan(root, root, 0, &mut found)?;
+
+    let mut suggestions = Vec::new();
+    for category in ["gitignore", "ci"] {
+        for (marker, path) in &found {
+            let templates = if category == "gitignore" {
+                marker.gitignore
+            } else {
+                marker.ci
+            };
+            for template in templates {
+                push_unique(
+                    &mut suggestions,
+                    category,
+                    template,
+                    format!("found {}", path),
+                );
+            }
+        }
+
+        if category == "gitignore" {
+            if let Some(template) = os_gitignore(std::env::consts::OS) {
+                push_unique(
+                    &mut suggestions,
+                    "gitignore",
+                    template,
+                    format!("host OS is {}", std::env::consts::OS),
+                );
+            }
+        }
+    }
+
+    if let Some(suggestion) = license_suggestion(root) {
+        suggestions.push(suggestion);
+    }
+
+    Ok(suggestions)
+}
+
+/// Record every marker under `dir`, with its path relative to `root`
+fn scan(
+    root: &Path,
+    dir: &Path,
+    depth: usize,
+    found: &mut Vec<(&'static Marker, String)>,
+) -> Result<()> {
+    let mut entries: Vec<_> = fs::read_dir(dir)?.filter_map(|e| e.ok()).collect();
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        let is_dir = path.is_dir();
+        let relative = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        for marker in MARKERS {
+            let matched = match marker.name.strip_suffix('/') {
+                Some(dir_name) => is_dir && name == dir_name,
+                None => match marker.name.strip_prefix('*') {
+                    Some(suffix) => !is_dir && name.ends_with(suffix),
+                    None => !is_dir && name == marker.name,
+                },
+            };
+            if matched {
+                let shown = if is_dir {
+                    format!("{}/", relative)
+                } else {
+                    relative.clone()
+                };
+                found.push((marker, shown));
+            }
+        }
+
+        if is_dir && depth + 1 < MAX_DEPTH && !SKIPPED_DIRS.contains(&name.as_str()) {
+            // Editor settings are only meaningful at the root, don't look inside them
+            if name != ".idea" && name != ".vscode" {
+                scan(root, &path, depth + 1, found)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn push_unique(
+    suggestions: &mut Vec<Suggestion>,
+    category: &'static str,
+    template: &str,
+    reason: String,
+) {
+    let exists = suggestions
+        .iter()
+        .any(|s| s.category == category && s.template == template);
+    if !exists {
+        suggestions.push(Suggestion {
+            category,
+            template: template.to_string(),
+            reason,
+        });
+    }
+}
+
+/// Gitignore cache key for the files the host OS leaves behind
+fn os_gitignore(os: &str) -> Option<&'static str> {
+    match os {
+        "macos" => Some("global-macos"),
+        "linux" => Some("global-linux"),
+        "windows" => Some("global-windows"),
+        _ => None,
+    }
+}
+
+/// Suggest the license declared in the project manifest when the repository has no license file
+fn license_suggestion(root: &Path) -> Option<Suggestion> {
+    let has_license = fs::read_dir(root)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .any(|entry| {
+            let name = entry.file_name().to_string_lossy().to_uppercase();
+            name.starts_with("LICENSE")
+                || name.starts_with("LICENCE")
+                || name.starts_with("COPYING")
+        });
+    if has_license {
+        return None;
+    }
+
+    let (license, file) = declared_license(root)?;
+    // `MIT OR Apache-2.0` offers a choice; suggest the first one
+    let id = license
+        .split(" OR ")
+        .next()
+        .unwrap_or(&license)
+        .trim_matches(|c| c == '(' || c == ')' || c == ' ');
+
+    Some(Suggestion {
+        category: "license",
+        template: id.to_lowercase(),
+        reason: format!("declared in {}", file),
+    })
+}
+
+/// The SPDX expression declared in `Cargo.toml`, `package.json` or `pyproject.toml`
+pub fn declared_license(root: &Path) -> Option<(String, &'static str)> {
+    if let Some(manifest) = read_toml(&root.join("Cargo.toml")) {
+        if let Some(license) = manifest
+            .get("package")
+            .and_then(|p| p.get("license"))
+            .and_then(|l| l.as_str())
+        {
+            return Some((license.to_string(), "Cargo.toml"));
+        }
+    }
+
+    if let Ok(content) = fs::read_to_string(root.join("package.json")) {
+        if let Ok(manifest) = serde_json::from_str::<serde_json::Value>(&content) {
+            if let Some(license) = manifest.get("license").and_then(|l| l.as_str()) {
+                return Some((license.to_string(), "package.json"));
+            }
+        }
+    }
+
+    if let Some(manifest) = read_toml(&root.join("pyproject.toml")) {
+        let project = manifest.get("project").and_then(|p| p.get("license"));
+        // PEP 621 allows `license = "MIT"` or `license = { text = "MIT" }`
+        let license = project
+            .and_then(|l| {
+                l.as_str()
+                    .or_else(|| l.get("text").and_then(|t| t.as_str()))
+            })
+            .or_else(|| {
+                manifest
+                    .get("tool")
+                    .and_then(|t| t.get("poetry"))
+                    .and_then(|p| p.get("license"))
+                    .and_then(|l| l.as_str())
+            });
+        if let Some(license) = license {
+            return Some((license.to_string(), "pyproject.toml"));
+        }
+    }
+
+    None
+}
+
+fn read_toml(path: &Path) -> Option<toml::Value> {
+    let content = fs::read_to_string(path).ok()?;
+    toml::from_str(&content).ok()
+}
@@ -0,0 +1,20 @@
+// Templates from this repository's `templates/` directory, bundled by build.rs
+include!(concat!(env!("OUT_DIR"), "/embedded_templates.rs"));
+
+/// Upstream location of the embedded templates; URLs below it are served from the binary
+pub const EMBEDDED_RAW_BASE: &str =
+    "https://raw.githubusercontent.com/rafaeljohn9/gh-templates/main/templates";
+
+/// Map a URL to its path relative to `templates/`, if it points at an embedded location
+pub fn relative_path(url: &str) -> Option<&str> {
+    url.strip_prefix(EMBEDDED_RAW_BASE)
+        .and_then(|rest| rest.strip_prefix('/'))
+}
+
+/// Look up an embedded template by its path relative to `templates/`
+pub fn get(path: &str) -> Option<&'static str> {
+    EMBEDDED_TEMPLATES
+        .iter()
+        .find(|(embedded_path, _)| *embedded_path == path)
+        .map(|(_, content)| *content)
+}
@@ -1,10 +1,22 @@
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use anyhow::Result;
 use colored::*;
 
+/// Set by the global `--dry-run` flag; writes are then printed as a plan instead
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+
+pub fn set_dry_run(dry_run: bool) {
+    DRY_RUN.store(dry_run, Ordering::Relaxed);
+}
+
+pub fn is_dry_run() -> bool {
+    DRY_RUN.load(Ordering::Relaxed)
+}
+
 /// Save content to a file with path resolution middleware
 pub fn save_file(content: &str, filepath: &Path, force: bool) -> Result<()> {
     let resolved_path = resolve_output_path(filepath)?;
@@ -12,12 +24,15 @@ pub fn save_file(content: &str, filepath: &Path, force: bool) -> Result<()> {
     // Create parent directories only if the path starts with .github
     if filepath.starts_with(".github") {
         if let Some(parent) = resolved_path.parent() {
-            fs::create_dir_all(parent)?;
+            if !is_dry_run() {
+                fs::create_dir_all(parent)?;
+            }
         }
     } else {
         // Check if parent directory exists, error if it doesn't
         if let Some(parent) = resolved_path.parent() {
-            if !parent.exists() {
+            // A bare file name has an empty parent, the current directory
+            if !parent.as_os_str().is_empty() && !parent.exists() {
                 return Err(anyhow::anyhow!(
                     "Directory '{}/' does not exist. \nPlease run this command from within a git repository or create the missing dir.",
                     parent.display()
@@ -48,12 +63,49 @@ pub fn save_file(content: &str, filepath: &Path, force: bool) -> Result<()> {
         ));
     }
 
+    if is_dry_run() {
+        let action = if resolved_path.exists() {
+            "overwrite"
+        } else {
+            "create"
+        };
+        return print_plan(&resolved_path, &display_path, action, content);
+    }
+
     fs::write(resolved_path, content)?;
 
     println!("{} {} - has been added.", "✓".green(), display_path);
     Ok(())
 }
 
+/// Replace the content of an existing file, or print the change in dry-run mode
+pub fn overwrite_file(content: &str, filepath: &Path) -> Result<()> {
+    if is_dry_run() {
+        return print_plan(
+            filepath,
+            &filepath.display().to_string(),
+            "overwrite",
+            content,
+        );
+    }
+
+    fs::write(filepath, content)?;
+    Ok(())
+}
+
+/// Print the path, the action and a unified diff of what a write would change
+fn print_plan(resolved_path: &Path, display_path: &str, action: &str, content: &str) -> Result<()> {
+    let existing = fs::read_to_string(resolved_path).unwrap_or_default();
+    let patch = diffy::create_patch(&existing, content);
+
+    println!("{} Would {} {}", "~".cyan(), action, display_path);
+    println!(
+        "{}",
+        diffy::PatchFormatter::new().with_color().fmt_patch(&patch)
+    );
+    Ok(())
+}
+
 /// Append content (including multi-line) to a file with path resolution middleware
 /// By default, appends at the end of the file. If `line_position` is Some(line_num), inserts at the specified line.
 pub fn append_file(content: &str, filepath: &Path, line_position: Option<usize>) -> Result<()> {
@@ -65,7 +117,7 @@ pub fn append_file(content: &str, filepath: &Path, line_position: Option<usize>)
     }
 
     // Display the resolved path relative to the repo root or current directory
-    let _display_path = if let Ok(repo_root) = find_repo_root() {
+    let display_path = if let Ok(repo_root) = find_repo_root() {
         match resolved_path.strip_prefix(&repo_root) {
             Ok(rel_path) => rel_path.display().to_string(),
             Err(_) => resolved_path.display().to_string(),
@@ -79,6 +131,15 @@ pub fn append_file(content: &str, filepath: &Path, line_position: Option<usize>)
         resolved_path.display().to_string()
     };
 
+    if is_dry_run() {
+        let existing = fs::read_to_string(&resolved_path)?;
+        let appended = match line_position {
+            None => format!("{}{}", existing, content),
+            Some(line_num) => insert_lines(&existing, content, line_num),
+        };
+        return print_plan(&resolved_path, &display_path, "append to", &appended);
+    }
+
     match line_position {
         None => {
             // Simple append at the end
@@ -96,25 +157,30 @@ pub fn append_file(content: &str, filepath: &Path, line_position: Option<usize>)
                 String::new()
             };
 
-            let mut lines: Vec<&str> = existing.lines().collect();
-            let insert_pos = line_num.min(lines.len());
-
-            // Insert content lines at the specified position
-            for (i, line) in content.lines().enumerate() {
-                lines.insert(insert_pos + i, line);
-            }
-
             // Write the modified content back to file
-            fs::write(&resolved_path, lines.join("\n"))?;
+            fs::write(&resolved_path, insert_lines(&existing, content, line_num))?;
         }
     }
 
     Ok(())
 }
 
+/// Insert the lines of `content` into `existing` at `line_num`
+fn insert_lines(existing: &str, content: &str, line_num: usize) -> String {
+    let mut lines: Vec<&str> = existing.lines().collect();
+    let insert_pos = line_num.min(lines.len());
+
+    // Insert content lines at the specified position
+    for (i, line) in content.lines().enumerate() {
+        lines.insert(insert_pos + i, line);
+    }
+
+    lines.join("\n")
+}
+
 /// Middleware function to resolve the output path
 /// If the path starts with .github, it finds the repo root and prepends it
-fn resolve_output_path(filepath: &Path) -> Result<PathBuf> {
+pub fn resolve_output_path(filepath: &Path) -> Result<PathBuf> {
     if filepath.starts_with(".github") {
         // Find repository root by looking for .git directory
         let repo_root = find_repo_root()?;
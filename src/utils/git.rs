@@ -0,0 +1,76 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{anyhow, Result};
+
+/// Run `git` in `root` and return its trimmed output, or `None` when it fails
+fn git(root: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(args)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!stdout.is_empty()).then_some(stdout)
+}
+
+/// Web URL of the repository behind `remote`, e.g. `https://github.com/owner/repo`
+pub fn remote_web_url(root: &Path, remote: &str) -> Result<String> {
+    let url = git(root, &["remote", "get-url", remote])
+        .ok_or_else(|| anyhow!("No git remote named '{}'.", remote))?;
+    web_url(&url).ok_or_else(|| {
+        anyhow!(
+            "Cannot build a web URL from the '{}' remote ({}).",
+            remote,
+            url
+        )
+    })
+}
+
+/// Turn an SSH or HTTPS clone URL into the repository's web URL
+pub fn web_url(clone_url: &str) -> Option<String> {
+    let url = clone_url.trim().trim_end_matches('/');
+    let url = url.strip_suffix(".git").unwrap_or(url);
+
+    let (host, path) = if let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .or_else(|| url.strip_prefix("ssh://"))
+        .or_else(|| url.strip_prefix("git://"))
+    {
+        let (host, path) = rest.split_once('/')?;
+        // Drop credentials and ports: `git@host:22` or `user:token@host`
+        let host = host.rsplit('@').next()?;
+        (host.split(':').next()?, path)
+    } else {
+        // scp-like syntax: `git@github.com:owner/repo`
+        let (host, path) = url.split_once(':')?;
+        (host.rsplit('@').next()?, path)
+    };
+
+    let path = path.trim_matches('/');
+    if host.is_empty() || path.split('/').filter(|p| !p.is_empty()).count() < 2 {
+        return None;
+    }
+    Some(format!("https://{}/{}", host, path))
+}
+
+/// Branch checked out in `root`; `None` on a detached HEAD
+pub fn current_branch(root: &Path) -> Option<String> {
+    git(root, &["symbolic-ref", "--short", "HEAD"])
+}
+
+/// Compare page of `web_url` that opens a pull request pre-filled with
+/// `template`. `refs` is `head` or `base...head`; without it, GitHub asks
+/// for the branches to compare.
+pub fn compare_url(web_url: &str, refs: Option<&str>, template: &str) -> String {
+    let refs = refs.map(|refs| format!("/{}", refs)).unwrap_or_default();
+    format!(
+        "{}/compare{}?quick_pull=1&template={}",
+        web_url, refs, template
+    )
+}
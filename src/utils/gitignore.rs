@@ -0,0 +1,591 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+/// Banner written above every template section by `gitignore add`
+pub fn section_banner(name: &str) -> String {
+    format!("# ===== {} =====", name)
+}
+
+/// Name of the template section opened by `line`, if it is a banner
+pub fn parse_banner(line: &str) -> Option<&str> {
+    line.trim_end()
+        .strip_prefix("# ===== ")
+        .and_then(|rest| rest.strip_suffix(" ====="))
+        .filter(|name| !name.is_empty())
+}
+
+/// Normalise a section or template name so `Rust.gitignore`, `rust` and
+/// `global/windows` find the sections written for them
+pub fn section_key(name: &str) -> String {
+    let name = name.trim().to_lowercase();
+    let name = name.strip_suffix(".gitignore").unwrap_or(&name);
+    name.replace('/', "-")
+}
+
+/// One line of a `.gitignore` file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    Blank,
+    Comment(String),
+    Rule(Rule),
+}
+
+/// A pattern line, split into the parts that decide what it matches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    /// The line as written in the file
+    pub raw: String,
+    /// Starts with `!` and re-includes what earlier rules ignored
+    pub negated: bool,
+    /// Contains a `/` before its last character, so it matches from the base directory only
+    pub anchored: bool,
+    /// Ends with `/` and only matches directories
+    pub dir_only: bool,
+    /// The glob without `!`, the leading and trailing `/` or a redundant leading `**/`
+    pub pattern: String,
+}
+
+impl Rule {
+    pub fn parse(line: &str) -> Option<Self> {
+        let raw = line.trim_end_matches('\r').to_string();
+        let mut body = trim_trailing_spaces(line);
+        if body.is_empty() || body.starts_with('#') {
+            return None;
+        }
+
+        let negated = body.starts_with('!');
+        if negated {
+            body = &body[1..];
+        }
+
+        let dir_only = body.len() > 1 && body.ends_with('/') && !body.ends_with("\\/");
+        if dir_only {
+            body = &body[..body.len() - 1];
+        }
+
+        let anchored = body.contains('/');
+        let mut pattern = body.strip_prefix('/').unwrap_or(body);
+
+        // `**/name` matches at any depth, exactly like a pattern without a slash
+        let mut unanchored = !anchored;
+        if let Some(rest) = pattern.strip_prefix("**/") {
+            if !rest.contains('/') {
+                pattern = rest;
+                unanchored = true;
+            }
+        }
+
+        Some(Self {
+            raw,
+            negated,
+            anchored: !unanchored,
+            dir_only,
+            pattern: pattern.to_string(),
+        })
+    }
+
+    /// Whether the rule matches `path`, given relative to the directory of
+    /// the `.gitignore` with `/` separators
+    pub fn matches(&self, path: &str, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+
+        // Patterns without a slash match the name at any depth
+        let text = if self.anchored {
+            path
+        } else {
+            path.rsplit('/').next().unwrap_or(path)
+        };
+
+        let pattern: Vec<char> = self.pattern.chars().collect();
+        let text: Vec<char> = text.chars().collect();
+        glob(&pattern, &text, true)
+    }
+
+    /// Rules with the same key match exactly the same paths
+    fn key(&self) -> (String, bool, bool) {
+        (self.pattern.clone(), self.anchored, self.dir_only)
+    }
+}
+
+/// Match `text` against a gitignore glob: `*`, `?` and `[...]` never match
+/// `/`, a leading `**/` or inner `/**/` matches any number of directories and
+/// a trailing `/**` matches everything inside
+fn glob(pattern: &[char], text: &[char], segment_start: bool) -> bool {
+    if pattern.is_empty() {
+        return text.is_empty();
+    }
+
+    if segment_start && pattern.starts_with(&['*', '*']) && pattern.get(2).is_none_or(|c| *c == '/')
+    {
+        if pattern.len() == 2 {
+            return true;
+        }
+        let rest = &pattern[3..];
+        return (0..=text.len())
+            .filter(|&i| i == 0 || text[i - 1] == '/')
+            .any(|i| glob(rest, &text[i..], true));
+    }
+
+    match pattern[0] {
+        '*' => (0..=text.len())
+            .take_while(|&i| i == 0 || text[i - 1] != '/')
+            .any(|i| glob(&pattern[1..], &text[i..], false)),
+        '?' => !text.is_empty() && text[0] != '/' && glob(&pattern[1..], &text[1..], false),
+        '[' => match match_class(pattern, text.first().copied()) {
+            Some((matched, len)) => matched && glob(&pattern[len..], &text[1..], false),
+            // An unclosed bracket is a literal `[`
+            None => text.first() == Some(&'[') && glob(&pattern[1..], &text[1..], false),
+        },
+        '\\' if pattern.len() > 1 => {
+            text.first() == Some(&pattern[1]) && glob(&pattern[2..], &text[1..], pattern[1] == '/')
+        }
+        c => text.first() == Some(&c) && glob(&pattern[1..], &text[1..], c == '/'),
+    }
+}
+
+/// Match `c` against the bracket expression at the start of `pattern`,
+/// returning whether it matched and the length of the expression
+fn match_class(pattern: &[char], c: Option<char>) -> Option<(bool, usize)> {
+    let mut i = 1;
+    let negated = matches!(pattern.get(i), Some('!') | Some('^'));
+    if negated {
+        i += 1;
+    }
+
+    let mut matched = false;
+    let mut first = true;
+    loop {
+        let start = match pattern.get(i) {
+            None => return None,
+            Some(']') if !first => break,
+            Some('\\') => {
+                i += 1;
+                *pattern.get(i)?
+            }
+            Some(ch) => *ch,
+        };
+        first = false;
+
+        if pattern.get(i + 1) == Some(&'-') && pattern.get(i + 2).is_some_and(|ch| *ch != ']') {
+            let end = pattern[i + 2];
+            matched |= c.is_some_and(|c| start <= c && c <= end);
+            i += 3;
+        } else {
+            matched |= c == Some(start);
+            i += 1;
+        }
+    }
+
+    let matched = c.is_some_and(|c| c != '/') && matched != negated;
+    Some((matched, i + 1))
+}
+
+/// Strip trailing spaces that are not escaped with a backslash
+fn trim_trailing_spaces(line: &str) -> &str {
+    let mut end = line.trim_end_matches(['\r', '\n']).len();
+    let bytes = line.as_bytes();
+    while end > 0 && bytes[end - 1] == b' ' {
+        if end > 1 && bytes[end - 2] == b'\\' {
+            break;
+        }
+        end -= 1;
+    }
+    &line[..end]
+}
+
+impl Line {
+    pub fn parse(line: &str) -> Self {
+        if line.trim().is_empty() {
+            Line::Blank
+        } else if line.starts_with('#') {
+            Line::Comment(line.trim_end().to_string())
+        } else {
+            match Rule::parse(line) {
+                Some(rule) => Line::Rule(rule),
+                None => Line::Blank,
+            }
+        }
+    }
+
+    fn text(&self) -> &str {
+        match self {
+            Line::Blank => "",
+            Line::Comment(text) => text,
+            Line::Rule(rule) => &rule.raw,
+        }
+    }
+}
+
+/// A run of lines, opened by a `# ===== name =====` banner unless it is
+/// text outside any template section
+#[derive(Debug, Clone)]
+pub struct Section {
+    pub name: Option<String>,
+    /// 0-based index of the first line after the banner in the parsed file
+    pub body_start: usize,
+    pub lines: Vec<Line>,
+}
+
+impl Section {
+    /// Line range of the section in the parsed file, banner included
+    pub fn range(&self) -> Range<usize> {
+        let start = if self.name.is_some() {
+            self.body_start - 1
+        } else {
+            self.body_start
+        };
+        start..self.body_start + self.lines.len()
+    }
+}
+
+/// A parsed `.gitignore` file
+#[derive(Debug, Clone, Default)]
+pub struct Gitignore {
+    pub sections: Vec<Section>,
+}
+
+impl Gitignore {
+    pub fn parse(content: &str) -> Self {
+        let mut sections = vec![Section {
+            name: None,
+            body_start: 0,
+            lines: Vec::new(),
+        }];
+
+        for (index, line) in content.lines().enumerate() {
+            if let Some(name) = parse_banner(line) {
+                sections.push(Section {
+                    name: Some(name.to_string()),
+                    body_start: index + 1,
+                    lines: Vec::new(),
+                });
+            } else {
+                sections.last_mut().unwrap().lines.push(Line::parse(line));
+            }
+        }
+
+        if sections[0].lines.is_empty() {
+            sections.remove(0);
+        }
+
+        Self { sections }
+    }
+
+    /// The section written for template `name`, if any
+    pub fn find(&self, name: &str) -> Option<&Section> {
+        let key = section_key(name);
+        self.sections.iter().find(|section| {
+            section
+                .name
+                .as_deref()
+                .is_some_and(|section_name| section_key(section_name) == key)
+        })
+    }
+
+    /// The rule that decides whether `path` is ignored, if any rule matches.
+    ///
+    /// Like git, a path inside an excluded directory stays ignored even when
+    /// a later rule re-includes it, so parent directories are checked first.
+    pub fn check(&self, path: &str, is_dir: bool) -> Option<Verdict<'_>> {
+        let components: Vec<&str> = path.split('/').collect();
+        for depth in 1..components.len() {
+            let parent = components[..depth].join("/");
+            if let Some(verdict) = self.last_match(&parent, true) {
+                if !verdict.rule.negated {
+                    return Some(verdict);
+                }
+            }
+        }
+
+        self.last_match(path, is_dir)
+    }
+
+    /// The last rule matching `path` itself; gitignore rules are last-match-wins
+    fn last_match(&self, path: &str, is_dir: bool) -> Option<Verdict<'_>> {
+        let mut verdict = None;
+        for section in &self.sections {
+            for (index, line) in section.lines.iter().enumerate() {
+                if let Line::Rule(rule) = line {
+                    if rule.matches(path, is_dir) {
+                        verdict = Some(Verdict {
+                            rule,
+                            line_number: section.body_start + index + 1,
+                            section: section.name.as_deref(),
+                            matched_path: path.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+        verdict
+    }
+
+    fn rules(&self) -> impl Iterator<Item = &Rule> {
+        self.sections
+            .iter()
+            .flat_map(|section| &section.lines)
+            .filter_map(|line| match line {
+                Line::Rule(rule) => Some(rule),
+                _ => None,
+            })
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for section in &self.sections {
+            if let Some(name) = &section.name {
+                out.push_str(&section_banner(name));
+                out.push('\n');
+            }
+
+            let body: Vec<&str> = section.lines.iter().map(Line::text).collect();
+            let body = body.join("\n");
+            let body = body.trim_matches('\n');
+            if !body.is_empty() {
+                out.push_str(body);
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// The rule that decided whether a path is ignored
+pub struct Verdict<'a> {
+    pub rule: &'a Rule,
+    /// 1-based line number of the rule in the parsed file
+    pub line_number: usize,
+    /// Template section the rule belongs to, if any
+    pub section: Option<&'a str>,
+    /// The path the rule matched: the checked path or one of its parent directories
+    pub matched_path: String,
+}
+
+impl Verdict<'_> {
+    pub fn is_ignored(&self) -> bool {
+        !self.rule.negated
+    }
+}
+
+/// Result of merging templates into a file
+pub struct Merged {
+    pub content: String,
+    /// Rules dropped because an earlier rule already matched the same paths
+    pub removed: usize,
+    /// Templates whose section was already in the file and was left alone
+    pub skipped: Vec<String>,
+    /// Templates whose existing section was rewritten
+    pub replaced: Vec<String>,
+}
+
+/// Merge templates into banner-separated sections, dropping rules that
+/// duplicate or are shadowed by an earlier rule and comment blocks that
+/// only described dropped rules.
+///
+/// Gitignore rules are last-match-wins, so a repeated rule is only dropped
+/// when no rule of the opposite sign appears between the two occurrences;
+/// the merged file therefore ignores exactly the same paths.
+///
+/// # Arguments
+/// * `templates` - `(section name, template content)` pairs, in output order.
+pub fn merge(templates: &[(String, String)]) -> Merged {
+    append("", templates, false)
+}
+
+/// Add templates to an existing `.gitignore` without repeating sections.
+///
+/// Templates whose `# ===== name =====` section is already present are
+/// skipped, or rewritten in place when `replace` is set. The remaining
+/// templates are appended as new sections, deduplicated against every rule
+/// already in the file as in [`merge`]. Text outside those sections is kept
+/// byte for byte, so without replacements the result starts with `existing`.
+pub fn append(existing: &str, templates: &[(String, String)], replace: bool) -> Merged {
+    let mut lines: Vec<String> = existing.lines().map(String::from).collect();
+    let mut skipped = Vec::new();
+    let mut replaced = Vec::new();
+    let mut new_templates = Vec::new();
+
+    for (name, content) in templates {
+        let file = Gitignore::parse(&lines.join("\n"));
+        match file.find(name) {
+            Some(section) if replace => {
+                let mut section_lines = vec![section_banner(name)];
+                section_lines.extend(content.trim_end().lines().map(String::from));
+                section_lines.push(String::new());
+                lines.splice(section.range(), section_lines);
+                replaced.push(name.clone());
+            }
+            Some(_) => skipped.push(name.clone()),
+            None => new_templates.push((name, content)),
+        }
+    }
+
+    let mut dedup = Dedup::default();
+    let mut content = if replaced.is_empty() {
+        existing.to_string()
+    } else {
+        format!("{}\n", lines.join("\n").trim_end())
+    };
+    for rule in Gitignore::parse(&content).rules() {
+        dedup.record(rule);
+    }
+
+    let mut seen_comment_blocks: HashSet<Vec<String>> = HashSet::new();
+    let mut added = Gitignore::default();
+
+    for (name, template) in new_templates {
+        let mut lines = Vec::new();
+
+        for block in blocks(template) {
+            let has_rules = block.iter().any(|line| matches!(line, Line::Rule(_)));
+            let kept: Vec<Line> = block
+                .into_iter()
+                .filter(|line| match line {
+                    Line::Rule(rule) => dedup.keep(rule),
+                    _ => true,
+                })
+                .collect();
+
+            if has_rules {
+                // Comments above rules that were all dropped no longer describe anything
+                if !kept.iter().any(|line| matches!(line, Line::Rule(_))) {
+                    continue;
+                }
+            } else {
+                let text: Vec<String> = kept.iter().map(|l| l.text().to_string()).collect();
+                if !seen_comment_blocks.insert(text) {
+                    continue;
+                }
+            }
+
+            if !lines.is_empty() {
+                lines.push(Line::Blank);
+            }
+            lines.extend(kept);
+        }
+
+        added.sections.push(Section {
+            name: Some(name.clone()),
+            body_start: 0,
+            lines,
+        });
+    }
+
+    if !added.sections.is_empty() {
+        // Leave one blank line between the existing text and the first new banner
+        if !content.is_empty() && !content.ends_with("\n\n") {
+            content.push_str(if content.ends_with('\n') {
+                "\n"
+            } else {
+                "\n\n"
+            });
+        }
+        content.push_str(&added.render());
+    }
+
+    Merged {
+        content,
+        removed: dedup.removed,
+        skipped,
+        replaced,
+    }
+}
+
+/// Remove the sections written for `names` from `existing`.
+///
+/// Returns the new content and the names that had no section in the file.
+pub fn remove(existing: &str, names: &[String]) -> (String, Vec<String>) {
+    let mut lines: Vec<String> = existing.lines().map(String::from).collect();
+    let mut missing = Vec::new();
+
+    for name in names {
+        let file = Gitignore::parse(&lines.join("\n"));
+        match file.find(name) {
+            Some(section) => {
+                lines.drain(section.range());
+            }
+            None => missing.push(name.clone()),
+        }
+    }
+
+    let content = lines.join("\n");
+    let content = content.trim_end();
+    if content.is_empty() {
+        (String::new(), missing)
+    } else {
+        (format!("{}\n", content), missing)
+    }
+}
+
+/// Split a template into blank-line separated blocks of comments and rules
+fn blocks(content: &str) -> Vec<Vec<Line>> {
+    let mut blocks = vec![Vec::new()];
+    for line in content.lines() {
+        match Line::parse(line) {
+            Line::Blank => {
+                if !blocks.last().unwrap().is_empty() {
+                    blocks.push(Vec::new());
+                }
+            }
+            line => blocks.last_mut().unwrap().push(line),
+        }
+    }
+    blocks.retain(|block| !block.is_empty());
+    blocks
+}
+
+/// Tracks the rules kept so far and how many of each sign preceded them
+#[derive(Default)]
+struct Dedup {
+    ignores: usize,
+    negations: usize,
+    /// Rule key and sign -> number of opposite-sign rules seen when it was last kept
+    kept: HashMap<((String, bool, bool), bool), usize>,
+    removed: usize,
+}
+
+impl Dedup {
+    fn keep(&mut self, rule: &Rule) -> bool {
+        let opposite = if rule.negated {
+            self.ignores
+        } else {
+            self.negations
+        };
+
+        let mut candidates = vec![rule.key()];
+        if rule.dir_only {
+            // `name` already matches the directory `name/` would
+            candidates.push((rule.pattern.clone(), rule.anchored, false));
+        }
+
+        let shadowed = candidates
+            .into_iter()
+            .any(|key| self.kept.get(&(key, rule.negated)) == Some(&opposite));
+        if shadowed {
+            self.removed += 1;
+            return false;
+        }
+
+        self.record(rule);
+        true
+    }
+
+    /// Count `rule` as kept without checking it, for rules already in a file
+    fn record(&mut self, rule: &Rule) {
+        let opposite = if rule.negated {
+            self.ignores
+        } else {
+            self.negations
+        };
+
+        self.kept.insert((rule.key(), rule.negated), opposite);
+        if rule.negated {
+            self.negations += 1;
+        } else {
+            self.ignores += 1;
+        }
+    }
+}
@@ -0,0 +1,509 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+
+/// A GitHub issue form (`.github/ISSUE_TEMPLATE/*.yml`)
+///
+/// See <https://docs.github.com/en/communities/using-templates-to-encourage-useful-issues-and-pull-requests/syntax-for-issue-forms>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct IssueForm {
+    pub name: String,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<StringList>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignees: Option<StringList>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projects: Option<StringList>,
+    /// Issue type of the organization, e.g. `Bug`
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
+    pub body: Vec<BodyItem>,
+}
+
+/// A list that GitHub accepts either as a YAML array or a comma-separated string
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum StringList {
+    List(Vec<String>),
+    Joined(String),
+}
+
+impl StringList {
+    /// The entries, with a joined string split on commas
+    pub fn items(&self) -> Vec<String> {
+        match self {
+            StringList::List(items) => items.clone(),
+            StringList::Joined(joined) => joined
+                .split(',')
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect(),
+        }
+    }
+}
+
+/// One element of the form `body`, tagged by its `type`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum BodyItem {
+    Markdown(MarkdownItem),
+    Textarea(TextareaItem),
+    Input(InputItem),
+    Dropdown(DropdownItem),
+    Checkboxes(CheckboxesItem),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MarkdownItem {
+    pub attributes: MarkdownAttributes,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MarkdownAttributes {
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TextareaItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub attributes: TextareaAttributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validations: Option<Validations>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TextareaAttributes {
+    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Language the content is rendered as a code block in, e.g. `shell`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub render: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct InputItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub attributes: InputAttributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validations: Option<Validations>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct InputAttributes {
+    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DropdownItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub attributes: DropdownAttributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validations: Option<Validations>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DropdownAttributes {
+    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multiple: Option<bool>,
+    pub options: Vec<String>,
+    /// Index of the preselected option
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CheckboxesItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub attributes: CheckboxesAttributes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validations: Option<Validations>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CheckboxesAttributes {
+    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub options: Vec<CheckboxOption>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CheckboxOption {
+    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Validations {
+    #[serde(default)]
+    pub required: bool,
+}
+
+impl BodyItem {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            BodyItem::Markdown(_) => "markdown",
+            BodyItem::Textarea(_) => "textarea",
+            BodyItem::Input(_) => "input",
+            BodyItem::Dropdown(_) => "dropdown",
+            BodyItem::Checkboxes(_) => "checkboxes",
+        }
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            BodyItem::Markdown(_) => None,
+            BodyItem::Textarea(item) => item.id.as_deref(),
+            BodyItem::Input(item) => item.id.as_deref(),
+            BodyItem::Dropdown(item) => item.id.as_deref(),
+            BodyItem::Checkboxes(item) => item.id.as_deref(),
+        }
+    }
+
+    /// Whether the field must be filled in before the issue can be submitted
+    pub fn is_required(&self) -> bool {
+        let validations = match self {
+            BodyItem::Markdown(_) => None,
+            BodyItem::Textarea(item) => item.validations.as_ref(),
+            BodyItem::Input(item) => item.validations.as_ref(),
+            BodyItem::Dropdown(item) => item.validations.as_ref(),
+            BodyItem::Checkboxes(item) => item.validations.as_ref(),
+        };
+        validations.is_some_and(|v| v.required)
+    }
+
+    /// Label of the field; markdown blocks have none
+    pub fn label(&self) -> Option<&str> {
+        match self {
+            BodyItem::Markdown(_) => None,
+            BodyItem::Textarea(item) => Some(&item.attributes.label),
+            BodyItem::Input(item) => Some(&item.attributes.label),
+            BodyItem::Dropdown(item) => Some(&item.attributes.label),
+            BodyItem::Checkboxes(item) => Some(&item.attributes.label),
+        }
+    }
+}
+
+/// A problem found in an issue form, with the 1-based line it was found on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Parse an issue form, reporting YAML and schema errors with their line.
+///
+/// Body items are checked one by one so that every broken item is reported
+/// against its own position, not just the first error in the file.
+pub fn parse(content: &str) -> Result<IssueForm, Vec<Problem>> {
+    let mut value: Value = serde_yaml::from_str(content).map_err(|e| {
+        vec![Problem {
+            line: e.location().map(|location| location.line()),
+            message: error_message(&e),
+        }]
+    })?;
+
+    let total_lines = content.lines().count();
+    let item_lines = body_item_lines(content);
+    let mut problems = Vec::new();
+
+    let body = value
+        .get_mut("body")
+        .and_then(Value::as_sequence_mut)
+        .map(std::mem::take);
+    if let Some(body) = &body {
+        for (index, item) in body.iter().enumerate() {
+            if let Err(e) = serde_yaml::from_value::<BodyItem>(item.clone()) {
+                let start = item_lines.get(index).copied().unwrap_or(0);
+                let end = item_lines.get(index + 1).copied().unwrap_or(total_lines);
+                problems.push(Problem {
+                    line: error_line(content, start..end, &e).or(Some(start + 1)),
+                    message: format!("body[{}]: {}", index, error_message(&e)),
+                });
+            }
+        }
+    }
+
+    // The remaining top-level keys, with an empty body standing in for the items
+    if let Err(e) = serde_yaml::from_value::<IssueForm>(value.clone()) {
+        problems.push(Problem {
+            line: error_line(content, 0..total_lines, &e),
+            message: error_message(&e),
+        });
+    }
+
+    if !problems.is_empty() {
+        problems.sort_by_key(|problem| problem.line);
+        return Err(problems);
+    }
+
+    if let (Some(body), Value::Mapping(mapping)) = (body, &mut value) {
+        mapping.insert(Value::from("body"), Value::Sequence(body));
+    }
+    serde_yaml::from_value(value).map_err(|e| {
+        vec![Problem {
+            line: None,
+            message: error_message(&e),
+        }]
+    })
+}
+
+/// The error without the ` at line N column M` suffix, which is reported separately
+fn error_message(error: &serde_yaml::Error) -> String {
+    let message = error.to_string();
+    message
+        .split(" at line ")
+        .next()
+        .unwrap_or(&message)
+        .to_string()
+}
+
+/// Best guess at the line of a schema error within `range`: the unknown
+/// field or variant it names, falling back to the error's own location
+fn error_line(content: &str, range: Range<usize>, error: &serde_yaml::Error) -> Option<usize> {
+    let message = error.to_string();
+    let named = |prefix: &str| {
+        message
+            .split(prefix)
+            .nth(1)
+            .and_then(|rest| rest.split('`').next())
+            .map(str::to_string)
+    };
+
+    if let Some(field) = named("unknown field `") {
+        return key_line(content, range, &field);
+    }
+    if message.contains("unknown variant `") {
+        return key_line(content, range, "type");
+    }
+    error.location().map(|location| location.line())
+}
+
+/// Parse and check an issue form against the rules GitHub applies on top of
+/// the YAML schema: non-empty metadata, unique ids and labels, usable
+/// options and at least one input field.
+pub fn validate(content: &str) -> Vec<Problem> {
+    let form = match parse(content) {
+        Ok(form) => form,
+        Err(problems) => return problems,
+    };
+
+    let item_lines = body_item_lines(content);
+    let mut problems = Vec::new();
+
+    for (key, value) in [("name", &form.name), ("description", &form.description)] {
+        if value.trim().is_empty() {
+            problems.push(Problem {
+                line: key_line(content, 0..content.lines().count(), key),
+                message: format!("`{}` must not be empty", key),
+            });
+        }
+    }
+
+    if form
+        .body
+        .iter()
+        .all(|item| matches!(item, BodyItem::Markdown(_)))
+    {
+        problems.push(Problem {
+            line: key_line(content, 0..content.lines().count(), "body"),
+            message: "`body` must contain at least one non-markdown field".to_string(),
+        });
+    }
+
+    let mut ids = HashSet::new();
+    let mut labels = HashSet::new();
+
+    for (index, item) in form.body.iter().enumerate() {
+        let start = item_lines.get(index).copied().unwrap_or(0);
+        let end = item_lines
+            .get(index + 1)
+            .copied()
+            .unwrap_or(content.lines().count());
+        let line_of = |key: &str| key_line(content, start..end, key).or(Some(start + 1));
+        let mut report = |line: Option<usize>, message: String| {
+            problems.push(Problem {
+                line,
+                message: format!("body[{}] ({}): {}", index, item.kind(), message),
+            })
+        };
+
+        if let Some(id) = item.id() {
+            if id.is_empty()
+                || !id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            {
+                report(
+                    line_of("id"),
+                    format!("id `{}` may only contain letters, digits, `-` and `_`", id),
+                );
+            } else if !ids.insert(id.to_string()) {
+                report(line_of("id"), format!("duplicate id `{}`", id));
+            }
+        }
+
+        if let Some(label) = item.label() {
+            if label.trim().is_empty() {
+                report(line_of("label"), "`label` must not be empty".to_string());
+            } else if !labels.insert(label.trim().to_lowercase()) {
+                report(line_of("label"), format!("duplicate label `{}`", label));
+            }
+        }
+
+        match item {
+            BodyItem::Markdown(item) if item.attributes.value.trim().is_empty() => {
+                report(line_of("value"), "`value` must not be empty".to_string());
+            }
+            BodyItem::Dropdown(item) => {
+                let options = &item.attributes.options;
+                if options.is_empty() {
+                    report(
+                        line_of("options"),
+                        "`options` must not be empty".to_string(),
+                    );
+                }
+                let mut seen = HashSet::new();
+                for option in options {
+                    if option.trim().is_empty() {
+                        report(line_of("options"), "options must not be empty".to_string());
+                    } else if !seen.insert(option.trim()) {
+                        report(line_of("options"), format!("duplicate option `{}`", option));
+                    }
+                    if option.trim().eq_ignore_ascii_case("none") {
+                        report(
+                            line_of("options"),
+                            "`None` is reserved by GitHub and cannot be an option".to_string(),
+                        );
+                    }
+                }
+                if let Some(default) = item.attributes.default {
+                    if default >= options.len() {
+                        report(
+                            line_of("default"),
+                            format!(
+                                "`default` is {} but there are only {} option(s)",
+                                default,
+                                options.len()
+                            ),
+                        );
+                    }
+                }
+            }
+            BodyItem::Checkboxes(item) => {
+                if item.attributes.options.is_empty() {
+                    report(
+                        line_of("options"),
+                        "`options` must not be empty".to_string(),
+                    );
+                }
+                if item
+                    .attributes
+                    .options
+                    .iter()
+                    .any(|o| o.label.trim().is_empty())
+                {
+                    report(
+                        line_of("options"),
+                        "option labels must not be empty".to_string(),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    problems
+}
+
+/// 0-based line index of every `- ` item of the top-level `body` list
+fn body_item_lines(content: &str) -> Vec<usize> {
+    let lines: Vec<&str> = content.lines().collect();
+    let Some(body) = lines.iter().position(|line| line.trim_end() == "body:") else {
+        return Vec::new();
+    };
+
+    let mut items = Vec::new();
+    let mut item_indent = None;
+    for (index, line) in lines.iter().enumerate().skip(body + 1) {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let indent = line.len() - trimmed.len();
+
+        match item_indent {
+            None if trimmed.starts_with('-') => {
+                item_indent = Some(indent);
+                items.push(index);
+            }
+            None => break,
+            Some(expected) if indent == expected && trimmed.starts_with('-') => items.push(index),
+            // The next top-level key ends the list
+            Some(expected) if indent < expected || (indent == 0 && !trimmed.starts_with('-')) => {
+                break;
+            }
+            Some(_) => {}
+        }
+    }
+    items
+}
+
+/// 1-based line of the first `key:` within the 0-based line `range`
+fn key_line(content: &str, range: Range<usize>, key: &str) -> Option<usize> {
+    let prefix = format!("{}:", key);
+    content
+        .lines()
+        .enumerate()
+        .take(range.end)
+        .skip(range.start)
+        .find(|(_, line)| line.trim_start_matches([' ', '-']).starts_with(&prefix))
+        .map(|(index, _)| index + 1)
+}
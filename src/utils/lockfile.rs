@@ -0,0 +1,241 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::commands::base::TemplateCategory;
+use crate::utils::cache::CacheManager;
+use crate::utils::file;
+use crate::utils::remote::Fetcher;
+
+/// Name of the lockfile at the repository root
+pub const LOCKFILE: &str = ".gh-templates.lock";
+
+/// Which template produced each installed file, keyed by path relative to the repository root
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Lockfile {
+    #[serde(default)]
+    pub files: BTreeMap<String, LockEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockEntry {
+    pub category: String,
+    pub template: String,
+    /// URL the template was fetched from
+    pub url: String,
+    /// SHA-256 of the content written at install time
+    pub hash: String,
+    /// SHA-256 of the upstream template, when the file was filled in from it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+}
+
+impl LockEntry {
+    /// Hash of the upstream template the installed file came from
+    pub fn base_hash(&self) -> &str {
+        self.base.as_deref().unwrap_or(&self.hash)
+    }
+
+    /// Record `written` as installed from `upstream`, keeping `upstream` as the merge base
+    pub fn set_installed(&mut self, upstream: &str, written: &str) {
+        let base = store_base(upstream);
+        self.hash = hash(written);
+        self.base = (base != self.hash).then_some(base);
+    }
+}
+
+/// How an installed file compares with its lock entry and the upstream template
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileState {
+    Unchanged,
+    Modified,
+    Outdated,
+    /// Edited locally and changed upstream
+    Diverged,
+    Missing,
+}
+
+impl FileState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Unchanged => "unchanged",
+            Self::Modified => "locally modified",
+            Self::Outdated => "upstream updated",
+            Self::Diverged => "locally modified, upstream updated",
+            Self::Missing => "missing",
+        }
+    }
+}
+
+/// Result of comparing one lock entry against the working tree and upstream
+pub struct FileStatus {
+    pub path: String,
+    pub state: FileState,
+    /// Current upstream content, kept so `update` does not fetch twice
+    pub upstream: String,
+}
+
+impl Lockfile {
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(LOCKFILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read lockfile: {:?}", path))?;
+
+        toml::from_str(&content).map_err(|e| {
+            anyhow!(
+                "Failed to parse lockfile: {:?}\n\nCaused by:\n    {}",
+                path,
+                e
+            )
+        })
+    }
+
+    /// Write the lockfile; a no-op in dry-run mode
+    pub fn save(&self, root: &Path) -> Result<()> {
+        if file::is_dry_run() {
+            return Ok(());
+        }
+
+        let path = root.join(LOCKFILE);
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| anyhow!("Failed to serialize lockfile: {}", e))?;
+
+        fs::write(&path, content).with_context(|| format!("Failed to write lockfile: {:?}", path))
+    }
+}
+
+pub fn hash(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+/// Record that `path` was written with `written`, filled in from the template
+/// `upstream` at `url`. Files outside a git repository, and dry runs, are not tracked.
+pub fn record(
+    path: &Path,
+    category: TemplateCategory,
+    template: &str,
+    url: &str,
+    upstream: &str,
+    written: &str,
+) -> Result<()> {
+    if file::is_dry_run() {
+        return Ok(());
+    }
+    let Ok(root) = file::find_repo_root() else {
+        return Ok(());
+    };
+    let Some(relative) = relative_to_root(&root, path)? else {
+        return Ok(());
+    };
+
+    let mut entry = LockEntry {
+        category: category.config_key().to_string(),
+        template: template.to_string(),
+        url: url.to_string(),
+        hash: String::new(),
+        base: None,
+    };
+    entry.set_installed(upstream, written);
+
+    let mut lockfile = Lockfile::load(&root)?;
+    lockfile.files.insert(relative, entry);
+    lockfile.save(&root)
+}
+
+/// Record that the tracked file at `path` (relative to `root`) was rewritten
+/// from `previous` to `written` by gh-templates itself, so the change is not
+/// reported as a local modification. Files edited by hand stay modified.
+pub fn record_rewrite(root: &Path, path: &str, previous: &str, written: &str) -> Result<()> {
+    if file::is_dry_run() {
+        return Ok(());
+    }
+
+    let mut lockfile = Lockfile::load(root)?;
+    let Some(entry) = lockfile.files.get_mut(path) else {
+        return Ok(());
+    };
+    if entry.hash != hash(previous) {
+        return Ok(());
+    }
+
+    let base = entry.base_hash().to_string();
+    entry.hash = hash(written);
+    entry.base = (base != entry.hash).then_some(base);
+    lockfile.save(root)
+}
+
+/// Keep `content` as the merge base for later updates and return its hash.
+/// Storing is best effort: without a base, `update` falls back to showing a diff.
+pub fn store_base(content: &str) -> String {
+    let hash = hash(content);
+    if let Ok(cache_manager) = CacheManager::new() {
+        let _ = cache_manager.save_object(&hash, content);
+    }
+    hash
+}
+
+/// Content recorded when the file with this hash was installed
+pub fn load_base(hash: &str) -> Option<String> {
+    CacheManager::new().ok()?.load_object(hash)
+}
+
+/// Compare every locked file with its recorded hash and the upstream template
+pub fn check(root: &Path, lockfile: &Lockfile) -> Vec<Result<FileStatus>> {
+    let fetcher = Fetcher::new();
+
+    lockfile
+        .files
+        .iter()
+        .map(|(path, entry)| {
+            let upstream = fetcher
+                .fetch_content(&entry.url)
+                .map_err(|e| anyhow!("{}: {}", path, e))?;
+            let upstream_changed = hash(&upstream) != entry.base_hash();
+
+            let state = match fs::read_to_string(root.join(path)) {
+                Err(_) => FileState::Missing,
+                Ok(local) => match (hash(&local) != entry.hash, upstream_changed) {
+                    (false, false) => FileState::Unchanged,
+                    (true, false) => FileState::Modified,
+                    (false, true) => FileState::Outdated,
+                    (true, true) => FileState::Diverged,
+                },
+            };
+
+            Ok(FileStatus {
+                path: path.clone(),
+                state,
+                upstream,
+            })
+        })
+        .collect()
+}
+
+/// Path of `path` relative to `root` with `/` separators, or `None` if it lies outside
+fn relative_to_root(root: &Path, path: &Path) -> Result<Option<String>> {
+    let resolved = file::resolve_output_path(path)?;
+    let absolute: PathBuf = if resolved.is_absolute() {
+        resolved
+    } else {
+        std::env::current_dir()?.join(resolved)
+    };
+
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    let absolute = absolute.canonicalize().unwrap_or(absolute);
+
+    Ok(absolute.strip_prefix(&root).ok().map(|relative| {
+        relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }))
+}
@@ -1,9 +1,9 @@
-use reqwest::blocking::Client;
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
 
 use crate::utils::progress;
+use crate::utils::remote::{self, Fetcher};
 
 // Custom error types
 #[derive(Debug)]
@@ -31,7 +31,7 @@ impl Error for ManifestError {}
 pub struct ManifestNavigator {
     url: String,
     base_url: String,
-    client: Client,
+    fetcher: Fetcher,
 }
 
 #[derive(Debug, Clone)]
@@ -61,7 +61,7 @@ impl ManifestNavigator {
         Ok(Self {
             url: url.to_string(),
             base_url,
-            client: Client::new(),
+            fetcher: Fetcher::new(),
         })
     }
 
@@ -69,26 +69,17 @@ impl ManifestNavigator {
     pub fn fetch_manifest(&self) -> Result<HashMap<String, String>, ManifestError> {
         let pb = progress::spinner("Fetching manifest...");
 
-        let response = self
-            .client
-            .get(&self.url)
-            .send()
-            .map_err(|e| ManifestError::HttpError(e.to_string()))?;
-
-        if !response.status().is_success() {
+        let content = self.fetcher.fetch_content(&self.url).map_err(|e| {
             pb.finish_and_clear();
-            return Err(ManifestError::NotFound(format!(
-                "Manifest not found at: {}",
-                self.url
-            )));
-        }
+            if remote::is_offline() {
+                ManifestError::NotFound(e.to_string())
+            } else {
+                ManifestError::HttpError(e.to_string())
+            }
+        })?;
         pb.set_message("Manifest fetched successfully");
         pb.finish_and_clear();
 
-        let content = response
-            .text()
-            .map_err(|e| ManifestError::HttpError(e.to_string()))?;
-
         self.parse_manifest(&content)
     }
 
@@ -114,11 +105,13 @@ impl ManifestNavigator {
 
                 if leading_spaces == 0 {
                     // Top-level key
-                    if key == "templates" {
+                    if value.is_empty() {
+                        // Section like "templates:"; only templates are listed
                         current_section = key.to_string();
+                        current_subsection.clear();
                         indent_level = leading_spaces;
                         continue;
-                    } else if !value.is_empty() {
+                    } else {
                         // Simple top-level key-value pair like "type: issue"
                         manifest.insert(
                             key.to_string(),
@@ -140,8 +133,11 @@ impl ManifestNavigator {
                 }
             }
             // Handle list items
-            else if trimmed.starts_with("- ") {
-                let filename = trimmed[2..].trim().trim_matches('"').trim_matches('\'');
+            else if let Some(item) = trimmed
+                .strip_prefix("- ")
+                .filter(|_| current_section.is_empty() || current_section == "templates")
+            {
+                let filename = item.trim().trim_matches('"').trim_matches('\'');
                 if !filename.is_empty() {
                     if current_subsection.is_empty() {
                         // Direct template list
@@ -206,4 +202,31 @@ impl ManifestNavigator {
 
         Ok(entries)
     }
+
+    /// List all files reachable from this manifest, descending into the
+    /// `manifest.yml` of every directory entry. File names are returned
+    /// relative to this manifest (e.g. `python/lint/ruff.yml`).
+    pub fn list_entries_recursive(&self) -> Result<Vec<FileEntry>, ManifestError> {
+        let mut files = Vec::new();
+
+        for entry in self.list_entries()? {
+            if entry.is_directory {
+                let nested_url = format!("{}/manifest.yml", entry.full_url);
+                let nested = ManifestNavigator::new(&nested_url)?;
+
+                for child in nested.list_entries_recursive()? {
+                    files.push(FileEntry {
+                        name: format!("{}/{}", entry.name, child.name),
+                        is_directory: false,
+                        full_url: child.full_url,
+                    });
+                }
+            } else {
+                files.push(entry);
+            }
+        }
+
+        files.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(files)
+    }
 }
@@ -1,8 +1,23 @@
 pub mod cache;
+pub mod detect;
+pub mod embedded;
 pub mod file;
 pub mod get_comment;
+pub mod git;
+pub mod gitignore;
+pub mod issue_form;
+pub mod lockfile;
 pub mod manifest_navigator;
+pub mod output;
 pub mod pattern;
+pub mod picker;
+pub mod placeholder;
 pub mod pretty_print;
 pub mod progress;
+pub mod project_config;
+pub mod prompt;
 pub mod remote;
+pub mod source;
+pub mod spdx;
+pub mod templating;
+pub mod yaml_keys;
@@ -0,0 +1,76 @@
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Output format of the `list` commands
+#[derive(clap::ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Coloured listing for humans
+    #[default]
+    Text,
+    Json,
+    Yaml,
+    /// Tab-separated values with a header row
+    Tsv,
+}
+
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct FormatArgs {
+    /// Output format for scripting (json, yaml or tsv)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+impl FormatArgs {
+    pub fn is_text(&self) -> bool {
+        self.format == OutputFormat::Text
+    }
+}
+
+/// A template listed from a manifest-backed source (issue, PR, CI, code of conduct)
+#[derive(Debug, Serialize)]
+pub struct TemplateRecord {
+    pub name: String,
+    pub category: &'static str,
+    pub url: String,
+    /// Comment on the first line of the template, if any
+    pub description: Option<String>,
+}
+
+impl TemplateRecord {
+    pub const COLUMNS: &'static [&'static str] = &["name", "category", "url", "description"];
+}
+
+/// Print `records` in a structured format. `columns` sets the TSV column order;
+/// JSON and YAML keep every serialized field.
+pub fn print_records<T: Serialize>(
+    records: &[T],
+    format: OutputFormat,
+    columns: &[&str],
+) -> Result<()> {
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(records)?),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(records)?),
+        OutputFormat::Tsv => {
+            println!("{}", columns.join("\t"));
+            for record in records {
+                let value = serde_json::to_value(record)?;
+                let row: Vec<String> = columns
+                    .iter()
+                    .map(|column| tsv_field(value.get(column).unwrap_or(&Value::Null)))
+                    .collect();
+                println!("{}", row.join("\t"));
+            }
+        }
+        OutputFormat::Text => unreachable!("text output is printed by each command"),
+    }
+    Ok(())
+}
+
+fn tsv_field(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.replace(['\t', '\n', '\r'], " "),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,306 @@
+use std::collections::{BTreeSet, HashMap};
+use std::io::IsTerminal;
+
+use anyhow::{anyhow, Result};
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::DefaultTerminal;
+use ratatui::Frame;
+
+use crate::utils::output::TemplateRecord;
+use crate::utils::pretty_print;
+use crate::utils::remote::Fetcher;
+
+/// A template offered by the picker
+pub struct Candidate {
+    /// Category key (e.g. `issue`), shown when several categories are mixed
+    pub group: &'static str,
+    /// Name passed on to the category's `add` command
+    pub name: String,
+    /// Short description shown next to the name
+    pub detail: String,
+    /// Extension used to highlight the preview
+    pub ext: &'static str,
+    /// Fetches the template content; only called when the candidate is highlighted
+    pub preview: Box<dyn Fn() -> Result<String>>,
+}
+
+impl Candidate {
+    /// Candidate for a manifest template, previewed by fetching its URL
+    pub fn from_record(record: TemplateRecord, ext: &'static str) -> Self {
+        let url = record.url;
+        Self {
+            group: record.category,
+            name: record.name,
+            detail: record.description.unwrap_or_default(),
+            ext,
+            preview: Box::new(move || Fetcher::new().fetch_content(&url)),
+        }
+    }
+}
+
+/// Whether a picker can be shown, i.e. both stdin and stdout are terminals
+pub fn is_interactive() -> bool {
+    std::io::stdin().is_terminal() && std::io::stdout().is_terminal()
+}
+
+/// Let the user fuzzy-filter and select candidates.
+/// Returns the selected indices in candidate order; empty if the picker was cancelled.
+pub fn pick(title: &str, candidates: &[Candidate]) -> Result<Vec<usize>> {
+    if candidates.is_empty() {
+        return Err(anyhow!("No templates available to pick from."));
+    }
+
+    let mut terminal = ratatui::init();
+    let result = Picker::new(title, candidates).run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+struct Picker<'a> {
+    title: &'a str,
+    candidates: &'a [Candidate],
+    show_group: bool,
+    matcher: SkimMatcherV2,
+    query: String,
+    /// Indices of candidates matching the query, best match first
+    matches: Vec<usize>,
+    list_state: ListState,
+    selected: BTreeSet<usize>,
+    previews: HashMap<usize, Text<'static>>,
+    scroll: u16,
+}
+
+impl<'a> Picker<'a> {
+    fn new(title: &'a str, candidates: &'a [Candidate]) -> Self {
+        let groups: BTreeSet<_> = candidates.iter().map(|c| c.group).collect();
+        let mut picker = Self {
+            title,
+            candidates,
+            show_group: groups.len() > 1,
+            matcher: SkimMatcherV2::default(),
+            query: String::new(),
+            matches: Vec::new(),
+            list_state: ListState::default(),
+            selected: BTreeSet::new(),
+            previews: HashMap::new(),
+            scroll: 0,
+        };
+        picker.filter();
+        picker
+    }
+
+    fn run(mut self, terminal: &mut DefaultTerminal) -> Result<Vec<usize>> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+            match key.code {
+                KeyCode::Esc => return Ok(Vec::new()),
+                KeyCode::Char('c') if ctrl => return Ok(Vec::new()),
+                KeyCode::Enter => {
+                    if self.selected.is_empty() {
+                        return Ok(self.current().into_iter().collect());
+                    }
+                    return Ok(self.selected.into_iter().collect());
+                }
+                KeyCode::Tab => {
+                    if let Some(index) = self.current() {
+                        if !self.selected.remove(&index) {
+                            self.selected.insert(index);
+                        }
+                    }
+                    self.move_cursor(1);
+                }
+                KeyCode::Up => self.move_cursor(-1),
+                KeyCode::Char('p') if ctrl => self.move_cursor(-1),
+                KeyCode::Down => self.move_cursor(1),
+                KeyCode::Char('n') if ctrl => self.move_cursor(1),
+                KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+                KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+                KeyCode::Backspace => {
+                    self.query.pop();
+                    self.filter();
+                }
+                KeyCode::Char(c) if !ctrl => {
+                    self.query.push(c);
+                    self.filter();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn current(&self) -> Option<usize> {
+        self.list_state.selected().map(|i| self.matches[i])
+    }
+
+    fn move_cursor(&mut self, delta: isize) {
+        if self.matches.is_empty() {
+            return;
+        }
+        let last = self.matches.len() as isize - 1;
+        let next = self.list_state.selected().unwrap_or(0) as isize + delta;
+        self.list_state.select(Some(next.clamp(0, last) as usize));
+        self.scroll = 0;
+    }
+
+    /// Recompute `matches` for the current query, best score first
+    fn filter(&mut self) {
+        let mut scored: Vec<(i64, usize)> = self
+            .candidates
+            .iter()
+            .enumerate()
+            .filter_map(|(index, candidate)| {
+                if self.query.is_empty() {
+                    return Some((0, index));
+                }
+                let haystack = if self.show_group {
+                    format!("{} {}", candidate.group, candidate.name)
+                } else {
+                    candidate.name.clone()
+                };
+                self.matcher
+                    .fuzzy_match(&haystack, &self.query)
+                    .map(|score| (score, index))
+            })
+            .collect();
+        // Stable sort keeps candidate order among equal scores
+        scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+
+        self.matches = scored.into_iter().map(|(_, index)| index).collect();
+        self.list_state.select(if self.matches.is_empty() {
+            None
+        } else {
+            Some(0)
+        });
+        self.scroll = 0;
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [query_area, body_area, help_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+        let [list_area, preview_area] =
+            Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+                .areas(body_area);
+
+        let query = Paragraph::new(format!("> {}", self.query)).block(
+            Block::default().borders(Borders::ALL).title(format!(
+                " {} ({}/{}) ",
+                self.title,
+                self.matches.len(),
+                self.candidates.len()
+            )),
+        );
+        frame.render_widget(query, query_area);
+
+        let items: Vec<ListItem> = self
+            .matches
+            .iter()
+            .map(|&index| {
+                let candidate = &self.candidates[index];
+                let marker = if self.selected.contains(&index) {
+                    "[x] "
+                } else {
+                    "[ ] "
+                };
+                let mut spans = vec![Span::raw(marker)];
+                if self.show_group {
+                    spans.push(Span::styled(
+                        format!("{:<10} ", candidate.group),
+                        Style::default().fg(Color::Cyan),
+                    ));
+                }
+                spans.push(Span::styled(
+                    candidate.name.clone(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ));
+                if !candidate.detail.is_empty() {
+                    spans.push(Span::styled(
+                        format!("  {}", candidate.detail),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" Templates ({} selected) ", self.selected.len())),
+            )
+            .highlight_style(Style::default().bg(Color::DarkGray));
+        frame.render_stateful_widget(list, list_area, &mut self.list_state);
+
+        let (title, preview) = match self.current() {
+            Some(index) => {
+                let candidate = &self.candidates[index];
+                let preview = self
+                    .previews
+                    .entry(index)
+                    .or_insert_with(|| render_preview(candidate))
+                    .clone();
+                (format!(" {} ", candidate.name), preview)
+            }
+            None => (" Preview ".to_string(), Text::raw("No matching templates.")),
+        };
+        let preview = Paragraph::new(preview)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .scroll((self.scroll, 0));
+        frame.render_widget(preview, preview_area);
+
+        let help = Paragraph::new(
+            "type to filter  ↑/↓ move  tab select  enter confirm  pgup/pgdn scroll  esc cancel",
+        )
+        .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(help, help_area);
+    }
+}
+
+/// Fetch and highlight a candidate's content for the preview pane
+fn render_preview(candidate: &Candidate) -> Text<'static> {
+    let content = match (candidate.preview)() {
+        Ok(content) => content,
+        Err(e) => {
+            return Text::styled(
+                format!("Preview unavailable: {}", e),
+                Style::default().fg(Color::Red),
+            );
+        }
+    };
+
+    pretty_print::highlight(candidate.ext, &content)
+        .into_iter()
+        .map(|ranges| {
+            Line::from(
+                ranges
+                    .into_iter()
+                    .map(|(style, text)| {
+                        let fg = style.foreground;
+                        Span::styled(
+                            text.trim_end_matches(['\n', '\r']).to_string(),
+                            Style::default().fg(Color::Rgb(fg.r, fg.g, fg.b)),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,174 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use colored::*;
+use regex::Regex;
+
+use crate::utils::prompt;
+
+/// Parse `--param KEY=VALUE` arguments into a map keyed by lowercase KEY
+pub fn parse_params(params: &[String]) -> Result<HashMap<String, String>> {
+    let mut placeholder_params = HashMap::new();
+    for param in params {
+        if let Some((key, value)) = param.split_once('=') {
+            placeholder_params.insert(key.trim().to_lowercase(), value.trim().to_string());
+        } else {
+            return Err(anyhow!(
+                "Invalid parameter format: '{}'. Use KEY=VALUE",
+                param
+            ));
+        }
+    }
+    Ok(placeholder_params)
+}
+
+/// Fill the placeholders in `text` from `placeholder_params`, prompting for
+/// the missing ones when `interactive` is set.
+///
+/// # Arguments
+/// * `text` - The template text containing placeholders.
+/// * `kind` - What the text is (e.g., "License"), used in the printed messages.
+/// * `patterns` - Regexes whose first capture group is the placeholder name.
+/// * `interactive` - Prompt on stdin for placeholders without a parameter.
+/// * `placeholder_params` - Values keyed by placeholder name.
+pub fn process_placeholders(
+    text: &str,
+    kind: &str,
+    patterns: &[Regex],
+    interactive: bool,
+    placeholder_params: &HashMap<String, String>,
+) -> Result<String> {
+    // Collect all unique placeholders
+    let mut placeholders = HashSet::new();
+    for re in patterns {
+        for caps in re.captures_iter(text) {
+            if let Some(m) = caps.get(1) {
+                placeholders.insert(m.as_str().to_string());
+            }
+        }
+    }
+
+    if placeholders.is_empty() {
+        println!(
+            "{}",
+            format!("✓ No placeholders found in {} text.", kind.to_lowercase()).green()
+        );
+
+        // Warn about unused parameters when no placeholders exist
+        if !placeholder_params.is_empty() {
+            println!(
+                "{} {} parameter(s) provided but no placeholders found:",
+                "⚠".yellow(),
+                placeholder_params.len()
+            );
+            for key in placeholder_params.keys() {
+                println!("  - {}", key);
+            }
+        }
+
+        return Ok(text.to_string());
+    } else if !interactive && placeholder_params.is_empty() {
+        println!(
+            "{} {} contains placeholders. Use --interactive or --param PLACEHOLDER=VALUE to fill them.",
+            "⚠".yellow(),
+            kind
+        );
+    }
+
+    // Prepare normalized params for matching
+    let normalized_params: HashMap<String, &String> = placeholder_params
+        .iter()
+        .map(|(k, v)| (prompt::normalize_key(k), v))
+        .collect();
+
+    // Track which parameters are actually used
+    let mut used_params = HashSet::new();
+    let mut unfilled_placeholders = Vec::new();
+
+    let mut result = text.to_string();
+    for ph in &placeholders {
+        let norm_ph = prompt::normalize_key(ph);
+
+        let replacement = if let Some(val) = normalized_params.get(&norm_ph) {
+            used_params.insert(norm_ph.clone());
+            val.to_string()
+        } else if interactive {
+            let user_input = prompt::ask(&format!("Enter value for '{}'", ph));
+            if user_input.is_empty() {
+                unfilled_placeholders.push(ph.clone());
+                format!("[{}]", ph)
+            } else {
+                user_input
+            }
+        } else {
+            // Keep original placeholder and track as unfilled
+            unfilled_placeholders.push(ph.clone());
+            format!("[{}]", ph)
+        };
+
+        // Replace every syntax the placeholder may appear in
+        for re in patterns {
+            result = re
+                .replace_all(&result, |caps: &regex::Captures| {
+                    if prompt::normalize_key(&caps[1]) == norm_ph {
+                        replacement.clone()
+                    } else {
+                        caps[0].to_string()
+                    }
+                })
+                .to_string();
+        }
+    }
+
+    // Warning for unused parameters
+    let unused_params: Vec<&String> = placeholder_params
+        .keys()
+        .filter(|k| !used_params.contains(&prompt::normalize_key(k)))
+        .collect();
+
+    if !unused_params.is_empty() {
+        println!(
+            "{} Warning: {} unused parameter(s):",
+            "⚠".yellow(),
+            unused_params.len()
+        );
+        for param in unused_params {
+            println!("  - {}", param);
+        }
+        println!(
+            "  Double-check parameter names match placeholders in the {}.",
+            kind.to_lowercase()
+        );
+    }
+
+    // Warning for unfilled placeholders
+    if !unfilled_placeholders.is_empty() {
+        println!(
+            "{} Warning: {} placeholder(s) remain unfilled:",
+            "⚠".yellow(),
+            unfilled_placeholders.len()
+        );
+        for ph in &unfilled_placeholders {
+            println!("  - [{}]", ph);
+        }
+        println!("  Use --interactive or --param to provide values for these placeholders.");
+    }
+
+    // Summary message for user verification
+    let filled_count = placeholders.len() - unfilled_placeholders.len();
+    if filled_count > 0 {
+        println!(
+            "{} Filled {} out of {} placeholder(s).",
+            "✓".green(),
+            filled_count,
+            placeholders.len()
+        );
+        println!(
+            "{} Please carefully review the {} text above for any missed or incorrect placeholders.",
+            "⚠".yellow(),
+            kind.to_lowercase()
+        );
+    }
+
+    Ok(result)
+}
@@ -1,5 +1,7 @@
+use std::sync::OnceLock;
+
 use syntect::easy::HighlightLines;
-use syntect::highlighting::{Style, ThemeSet};
+use syntect::highlighting::{Style, Theme, ThemeSet};
 use syntect::parsing::SyntaxSet;
 use syntect::util::{LinesWithEndings, as_24_bit_terminal_escaped};
 
@@ -9,9 +11,17 @@ use syntect::util::{LinesWithEndings, as_24_bit_terminal_escaped};
 /// * `ext` - The file extension (e.g., "rs", "py").
 /// * `content` - The source code/content to highlight.
 pub fn print_highlighted(ext: &str, content: &str) {
-    let ps = SyntaxSet::load_defaults_newlines();
-    let ts = ThemeSet::load_defaults();
-    let theme = &ts.themes["base16-ocean.dark"];
+    for ranges in highlight(ext, content) {
+        let ranges: Vec<(Style, &str)> = ranges.iter().map(|(s, t)| (*s, t.as_str())).collect();
+        print!("{}", as_24_bit_terminal_escaped(&ranges[..], false));
+    }
+    println!(); // Ensure the cursor moves to the next line after printing
+}
+
+/// Highlights content line by line, returning styled fragments for each line.
+/// Fragments keep their trailing newline, as produced by `syntect`.
+pub fn highlight(ext: &str, content: &str) -> Vec<Vec<(Style, String)>> {
+    let ps = syntax_set();
 
     // Use "txt" as the default extension if ext is empty
     let ext = if ext.is_empty() { "txt" } else { ext };
@@ -19,11 +29,25 @@ pub fn print_highlighted(ext: &str, content: &str) {
     let syntax = ps
         .find_syntax_by_extension(ext)
         .unwrap_or_else(|| ps.find_syntax_plain_text());
-    let mut h = HighlightLines::new(syntax, theme);
+    let mut h = HighlightLines::new(syntax, theme());
 
-    for line in LinesWithEndings::from(content) {
-        let ranges: Vec<(Style, &str)> = h.highlight_line(line, &ps).unwrap();
-        print!("{}", as_24_bit_terminal_escaped(&ranges[..], false));
-    }
-    println!(); // Ensure the cursor moves to the next line after printing
+    LinesWithEndings::from(content)
+        .map(|line| {
+            h.highlight_line(line, ps)
+                .unwrap()
+                .into_iter()
+                .map(|(style, text)| (style, text.to_string()))
+                .collect()
+        })
+        .collect()
+}
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAX_SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn theme() -> &'static Theme {
+    static THEME: OnceLock<Theme> = OnceLock::new();
+    THEME.get_or_init(|| ThemeSet::load_defaults().themes["base16-ocean.dark"].clone())
 }
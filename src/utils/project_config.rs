@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+
+use crate::utils::file;
+
+/// Name of the project config file at the repository root
+pub const PROJECT_CONFIG_FILE: &str = ".gh-templates.toml";
+
+/// Templates a repository should have, read from `.gh-templates.toml`
+///
+/// ```toml
+/// [issue]
+/// templates = ["bug", "feature"]
+///
+/// [pr]
+/// templates = ["detailed"]
+/// params = { project_name = "Acme", security_checklist = "true" }
+///
+/// [license]
+/// id = "mit"
+/// params = { year = "2025", fullname = "Jane Doe" }
+///
+/// [gitignore]
+/// templates = ["rust", "node"]
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectConfig {
+    pub issue: Option<TemplateList>,
+    pub pr: Option<PrConfig>,
+    pub license: Option<LicenseConfig>,
+    pub gitignore: Option<TemplateList>,
+    pub ci: Option<TemplateList>,
+    #[serde(rename = "code-of-conduct")]
+    pub code_of_conduct: Option<CodeOfConductConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateList {
+    pub templates: Vec<String>,
+    /// Named source from the user config (see `--source`)
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PrConfig {
+    #[serde(default)]
+    pub templates: Vec<String>,
+    /// Values for the templates' variables and conditional sections
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LicenseConfig {
+    pub id: String,
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CodeOfConductConfig {
+    pub template: Option<String>,
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+    /// Write to `.github/CODE_OF_CONDUCT.md` instead of the repository root
+    #[serde(default)]
+    pub github: bool,
+}
+
+impl ProjectConfig {
+    /// Load the project config from the root of the current repository
+    pub fn find() -> Result<(PathBuf, Self)> {
+        let root = file::find_repo_root()?;
+        let config = Self::load(&root)?;
+        Ok((root, config))
+    }
+
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(PROJECT_CONFIG_FILE);
+        if !path.exists() {
+            return Err(anyhow!(
+                "No {} found in {}. Create one listing the templates this repository should have.",
+                PROJECT_CONFIG_FILE,
+                root.display()
+            ));
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read project config: {:?}", path))?;
+
+        toml::from_str(&content).map_err(|e| {
+            anyhow!(
+                "Failed to parse project config: {:?}\n\nCaused by:\n    {}",
+                path,
+                e
+            )
+        })
+    }
+}
+
+/// Convert config params into the `KEY=VALUE` form taken by `--param`
+pub fn to_param_args(params: &BTreeMap<String, String>) -> Vec<String> {
+    params
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect()
+}
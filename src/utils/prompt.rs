@@ -0,0 +1,30 @@
+use std::io::{self, Write};
+
+/// Print `question` and read one line from stdin, trimmed.
+/// An unreadable stdin counts as an empty answer.
+pub fn ask(question: &str) -> String {
+    print!("{}: ", question);
+    let _ = io::stdout().flush();
+    let mut input = String::new();
+    let _ = io::stdin().read_line(&mut input);
+    input.trim().to_string()
+}
+
+/// Ask a yes/no question; an empty or unknown answer gives `default`
+pub fn confirm(question: &str, default: bool) -> bool {
+    let hint = if default { "[Y/n]" } else { "[y/N]" };
+    match ask(&format!("{} {}", question, hint))
+        .to_lowercase()
+        .as_str()
+    {
+        "y" | "yes" => true,
+        "n" | "no" => false,
+        _ => default,
+    }
+}
+
+/// Key under which a parameter or placeholder is matched:
+/// `Project Name`, `project-name` and `project_name` are the same key
+pub fn normalize_key(key: &str) -> String {
+    key.trim().to_lowercase().replace(['-', ' '], "_")
+}
@@ -1,10 +1,37 @@
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 use anyhow::anyhow;
 use reqwest::blocking::Client;
 
+use crate::utils::cache::CacheManager;
+use crate::utils::embedded;
+
+/// Set by the global `--offline` flag; every fetch is then served from the content cache
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Set by the global `--remote` flag; first-party templates are then fetched from GitHub
+static REMOTE: AtomicBool = AtomicBool::new(false);
+
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+pub fn set_remote(remote: bool) {
+    REMOTE.store(remote, Ordering::Relaxed);
+}
+
+pub fn is_remote() -> bool {
+    REMOTE.load(Ordering::Relaxed)
+}
+
 pub struct Fetcher {
     client: Client,
+    cache_manager: Option<CacheManager>,
 }
 
 impl Fetcher {
@@ -15,11 +42,31 @@ impl Fetcher {
                 .user_agent("gh-templates-fetcher")
                 .build()
                 .unwrap(),
+            cache_manager: CacheManager::new().ok(),
         }
     }
 
     /// Fetch raw content from a URL
     pub fn fetch_content(&self, url: &str) -> anyhow::Result<String> {
+        if let Some(path) = url.strip_prefix("file://") {
+            return read_local(path);
+        }
+
+        if !is_remote() {
+            if let Some(path) = embedded::relative_path(url) {
+                return embedded::get(path).map(str::to_string).ok_or_else(|| {
+                    anyhow!(
+                        "Not Found: '{}' was not found in the embedded templates. Use --remote to fetch the latest templates from GitHub.",
+                        path
+                    )
+                });
+            }
+        }
+
+        if is_offline() {
+            return self.cached_content(url);
+        }
+
         let response = self
             .client
             .get(url)
@@ -34,13 +81,22 @@ impl Fetcher {
             ));
         }
 
-        response
+        let content = response
             .text()
-            .map_err(|e| anyhow!("Failed to read response: {}", e))
+            .map_err(|e| anyhow!("Failed to read response: {}", e))?;
+
+        self.store_content(url, &content);
+        Ok(content)
     }
 
     /// Fetch and parse JSON from a URL
     pub fn fetch_json(&self, url: &str) -> anyhow::Result<serde_json::Value> {
+        if is_offline() || url.starts_with("file://") {
+            let content = self.fetch_content(url)?;
+            return serde_json::from_str(&content)
+                .map_err(|e| anyhow!("Failed to parse JSON: {}", e));
+        }
+
         let response = self
             .client
             .get(url)
@@ -55,8 +111,43 @@ impl Fetcher {
             ));
         }
 
-        response
-            .json()
-            .map_err(|e| anyhow!("Failed to parse JSON: {}", e))
+        let content = response
+            .text()
+            .map_err(|e| anyhow!("Failed to read response: {}", e))?;
+        let json =
+            serde_json::from_str(&content).map_err(|e| anyhow!("Failed to parse JSON: {}", e))?;
+
+        self.store_content(url, &content);
+        Ok(json)
     }
+
+    fn cached_content(&self, url: &str) -> anyhow::Result<String> {
+        self.cache_manager
+            .as_ref()
+            .and_then(|cache_manager| cache_manager.load_content(url))
+            .ok_or_else(|| {
+                anyhow!(
+                    "'{}' is not cached. Run the command once without --offline to cache it.",
+                    url
+                )
+            })
+    }
+
+    // Caching is best effort: a read-only cache must not fail an online fetch
+    fn store_content(&self, url: &str, content: &str) {
+        if let Some(cache_manager) = &self.cache_manager {
+            let _ = cache_manager.save_content(url, content);
+        }
+    }
+}
+
+/// Read a template from a `file://` source
+fn read_local(path: &str) -> anyhow::Result<String> {
+    std::fs::read_to_string(path).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            anyhow!("Not Found: {} does not exist", path)
+        } else {
+            anyhow!("Failed to read {}: {}", path, e)
+        }
+    })
 }
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+
+use crate::commands::base::TemplateCategory;
+
+/// Environment variable overriding the location of the user config file
+const CONFIG_ENV_VAR: &str = "GH_TEMPLATES_CONFIG";
+
+/// User configuration, read from `~/.config/gh-templates/config.toml`
+///
+/// ```toml
+/// [sources.issue]
+/// acme = "https://raw.githubusercontent.com/acme/templates/main/issue-templates"
+///
+/// [sources.pr]
+/// local = "file:///home/me/templates/pr-templates"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct UserConfig {
+    /// Named template sources per category (`issue`, `pr`, `ci`, `gitignore`, `license`)
+    #[serde(default)]
+    pub sources: HashMap<String, HashMap<String, String>>,
+}
+
+impl UserConfig {
+    pub fn load() -> Result<Self> {
+        let path = match Self::path() {
+            Some(path) if path.exists() => path,
+            _ => return Ok(Self::default()),
+        };
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file: {:?}", path))?;
+
+        toml::from_str(&content).map_err(|e| {
+            anyhow!(
+                "Failed to parse config file: {:?}\n\nCaused by:\n    {}",
+                path,
+                e
+            )
+        })
+    }
+
+    fn path() -> Option<PathBuf> {
+        if let Ok(path) = std::env::var(CONFIG_ENV_VAR) {
+            return Some(PathBuf::from(path));
+        }
+
+        dirs::home_dir().map(|home| {
+            home.join(".config")
+                .join("gh-templates")
+                .join("config.toml")
+        })
+    }
+}
+
+/// Shared `--source` option for commands that fetch templates
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct SourceArgs {
+    /// Named template source from the config file (default: the built-in upstream)
+    #[arg(long, value_name = "NAME")]
+    pub source: Option<String>,
+}
+
+impl SourceArgs {
+    /// Resolve the base URL for `category`, falling back to `default` without `--source`
+    pub fn resolve(&self, category: TemplateCategory, default: &str) -> Result<String> {
+        match &self.source {
+            Some(name) => resolve_named(category, name),
+            None => Ok(default.to_string()),
+        }
+    }
+
+    /// Cache name for indexes built from this source
+    pub fn cache_name(&self, base_name: &str) -> String {
+        match &self.source {
+            Some(name) => format!("{}_{}", base_name, name),
+            None => base_name.to_string(),
+        }
+    }
+}
+
+fn resolve_named(category: TemplateCategory, name: &str) -> Result<String> {
+    let config = UserConfig::load()?;
+    let key = category.config_key();
+
+    config
+        .sources
+        .get(key)
+        .and_then(|sources| sources.get(name))
+        .map(|url| url.trim_end_matches('/').to_string())
+        .ok_or_else(|| {
+            anyhow!(
+                "Unknown {} source '{}'. Define it under [sources.{}] in the config file.",
+                key,
+                name,
+                key
+            )
+        })
+}
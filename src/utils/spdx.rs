@@ -0,0 +1,398 @@
+use serde::Serialize;
+
+/// A parsed SPDX license expression, e.g. `MIT OR Apache-2.0`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expression {
+    License {
+        id: String,
+        exception: Option<String>,
+    },
+    And(Vec<Expression>),
+    Or(Vec<Expression>),
+}
+
+/// Parse an SPDX expression. The old Cargo separator `MIT/Apache-2.0` is read as `OR`.
+pub fn parse(expression: &str) -> Option<Expression> {
+    let spaced = expression
+        .replace('/', " OR ")
+        .replace('(', " ( ")
+        .replace(')', " ) ");
+    let tokens: Vec<&str> = spaced.split_whitespace().collect();
+
+    let mut parser = ExpressionParser { tokens, pos: 0 };
+    let parsed = parser.or()?;
+    (parser.pos == parser.tokens.len()).then_some(parsed)
+}
+
+struct ExpressionParser<'a> {
+    tokens: Vec<&'a str>,
+    pos: usize,
+}
+
+impl ExpressionParser<'_> {
+    fn next_is(&self, keyword: &str) -> bool {
+        self.tokens
+            .get(self.pos)
+            .is_some_and(|t| t.eq_ignore_ascii_case(keyword))
+    }
+
+    fn or(&mut self) -> Option<Expression> {
+        let mut terms = vec![self.and()?];
+        while self.next_is("OR") {
+            self.pos += 1;
+            terms.push(self.and()?);
+        }
+        Some(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Expression::Or(terms)
+        })
+    }
+
+    fn and(&mut self) -> Option<Expression> {
+        let mut terms = vec![self.license()?];
+        while self.next_is("AND") {
+            self.pos += 1;
+            terms.push(self.license()?);
+        }
+        Some(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Expression::And(terms)
+        })
+    }
+
+    fn license(&mut self) -> Option<Expression> {
+        let token = *self.tokens.get(self.pos)?;
+        self.pos += 1;
+
+        if token == "(" {
+            let inner = self.or()?;
+            if !self.next_is(")") {
+                return None;
+            }
+            self.pos += 1;
+            return Some(inner);
+        }
+        if matches!(token.to_uppercase().as_str(), ")" | "AND" | "OR" | "WITH") {
+            return None;
+        }
+
+        let exception = if self.next_is("WITH") {
+            self.pos += 1;
+            let exception = *self.tokens.get(self.pos)?;
+            self.pos += 1;
+            Some(exception.to_string())
+        } else {
+            None
+        };
+        Some(Expression::License {
+            id: token.to_string(),
+            exception,
+        })
+    }
+}
+
+impl Expression {
+    /// Every license named in the expression
+    pub fn licenses(&self) -> Vec<&str> {
+        match self {
+            Expression::License { id, .. } => vec![id.as_str()],
+            Expression::And(terms) | Expression::Or(terms) => {
+                terms.iter().flat_map(Expression::licenses).collect()
+            }
+        }
+    }
+}
+
+/// How a dependency's license combines with the project's, best first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Review,
+    Incompatible,
+}
+
+/// The status of a dependency and why
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verdict {
+    pub status: Status,
+    pub reason: Option<String>,
+}
+
+impl Verdict {
+    fn ok() -> Self {
+        Self {
+            status: Status::Ok,
+            reason: None,
+        }
+    }
+
+    fn review(reason: String) -> Self {
+        Self {
+            status: Status::Review,
+            reason: Some(reason),
+        }
+    }
+
+    fn incompatible(reason: String) -> Self {
+        Self {
+            status: Status::Incompatible,
+            reason: Some(reason),
+        }
+    }
+}
+
+/// License families, with the obligations choosealicense.com lists as
+/// `same-license`, `same-license--library` and `network-use-disclose`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Family {
+    Permissive,
+    /// File-level copyleft (`same-license--file`): MPL, EPL, CDDL
+    FileCopyleft,
+    /// Library copyleft (`same-license--library`)
+    Lgpl {
+        version: u8,
+        or_later: bool,
+    },
+    /// Strong copyleft (`same-license`)
+    Gpl {
+        version: u8,
+        or_later: bool,
+    },
+    /// Strong copyleft with `network-use-disclose`
+    Agpl,
+    /// Proprietary, custom (`LicenseRef-…`) or not recognised
+    Other,
+}
+
+const PERMISSIVE: &[&str] = &[
+    "0BSD",
+    "AFL-3.0",
+    "APACHE-2.0",
+    "BLUEOAK-1.0.0",
+    "BSD-1-CLAUSE",
+    "BSD-2-CLAUSE",
+    "BSD-3-CLAUSE",
+    "BSD-3-CLAUSE-CLEAR",
+    "BSL-1.0",
+    "CC-BY-4.0",
+    "CC0-1.0",
+    "ISC",
+    "MIT",
+    "MIT-0",
+    "NCSA",
+    "POSTGRESQL",
+    "PSF-2.0",
+    "PYTHON-2.0",
+    "UNICODE-3.0",
+    "UNICODE-DFS-2016",
+    "UNLICENSE",
+    "UPL-1.0",
+    "WTFPL",
+    "X11",
+    "ZLIB",
+];
+
+const FILE_COPYLEFT: &[&str] = &[
+    "CDDL-1.0", "CDDL-1.1", "EPL-1.0", "EPL-2.0", "MPL-1.1", "MPL-2.0",
+];
+
+/// Licenses the GPL does not allow to be combined with it
+const GPL_INCOMPATIBLE: &[&str] = &["CDDL-1.0", "CDDL-1.1", "EPL-1.0", "MPL-1.1"];
+
+fn family(id: &str) -> Family {
+    let id = id.to_uppercase();
+    if PERMISSIVE.contains(&id.as_str()) {
+        return Family::Permissive;
+    }
+    if FILE_COPYLEFT.contains(&id.as_str()) {
+        return Family::FileCopyleft;
+    }
+
+    // GPL-3.0-only, GPL-3.0-or-later and the deprecated GPL-3.0 / GPL-3.0+
+    let (base, or_later) = match id.strip_suffix('+') {
+        Some(base) => (base, true),
+        None => match id.strip_suffix("-OR-LATER") {
+            Some(base) => (base, true),
+            None => (id.strip_suffix("-ONLY").unwrap_or(&id), false),
+        },
+    };
+    let version = match base.rsplit_once('-').map(|(_, v)| v) {
+        Some("2.0") | Some("2.1") => 2,
+        Some("3.0") => 3,
+        _ => return Family::Other,
+    };
+    if base.starts_with("AGPL-") {
+        Family::Agpl
+    } else if base.starts_with("LGPL-") {
+        Family::Lgpl { version, or_later }
+    } else if base.starts_with("GPL-") {
+        Family::Gpl { version, or_later }
+    } else {
+        Family::Other
+    }
+}
+
+/// Check a dependency licensed under `dependency` against the project
+/// license expression. The dependency's alternatives (`OR`) give the best
+/// status, its conjunctions (`AND`) the worst; every alternative of the
+/// project must work, since users may pick any of them.
+pub fn check(project: &Expression, dependency: &Expression) -> Verdict {
+    project
+        .licenses()
+        .into_iter()
+        .map(|project_id| check_against(project_id, dependency))
+        .max_by_key(|verdict| verdict.status)
+        .unwrap_or_else(Verdict::ok)
+}
+
+fn check_against(project_id: &str, dependency: &Expression) -> Verdict {
+    match dependency {
+        Expression::License { id, exception } => {
+            let verdict = compare(project_id, id);
+            match exception {
+                // Linking exceptions such as Classpath-exception-2.0 lift the copyleft
+                Some(exception) if verdict.status == Status::Incompatible => {
+                    Verdict::review(format!(
+                        "{} WITH {}: check that the exception covers this use",
+                        id, exception
+                    ))
+                }
+                _ => verdict,
+            }
+        }
+        Expression::Or(terms) => terms
+            .iter()
+            .map(|term| check_against(project_id, term))
+            .min_by_key(|verdict| verdict.status)
+            .unwrap_or_else(Verdict::ok),
+        Expression::And(terms) => terms
+            .iter()
+            .map(|term| check_against(project_id, term))
+            .max_by_key(|verdict| verdict.status)
+            .unwrap_or_else(Verdict::ok),
+    }
+}
+
+fn compare(project_id: &str, dependency_id: &str) -> Verdict {
+    let project = family(project_id);
+    let gpl_project = matches!(project, Family::Gpl { .. } | Family::Agpl);
+
+    match family(dependency_id) {
+        Family::Permissive => {
+            let gpl2_only = project
+                == Family::Gpl {
+                    version: 2,
+                    or_later: false,
+                };
+            if gpl2_only && dependency_id.eq_ignore_ascii_case("Apache-2.0") {
+                Verdict::incompatible(format!(
+                    "Apache-2.0 is not compatible with {} (patent terms)",
+                    project_id
+                ))
+            } else {
+                Verdict::ok()
+            }
+        }
+        Family::FileCopyleft => {
+            if gpl_project && GPL_INCOMPATIBLE.contains(&dependency_id.to_uppercase().as_str()) {
+                Verdict::incompatible(format!(
+                    "{} cannot be combined with {}",
+                    dependency_id, project_id
+                ))
+            } else {
+                Verdict::review(format!(
+                    "{} is file-level copyleft: changes to its files must stay under {}",
+                    dependency_id, dependency_id
+                ))
+            }
+        }
+        Family::Lgpl { version, or_later } => match project {
+            Family::Gpl {
+                version: 2,
+                or_later: false,
+            } if version == 3 => Verdict::incompatible(format!(
+                "{} cannot be combined with {}",
+                dependency_id, project_id
+            )),
+            Family::Gpl { .. } | Family::Agpl => Verdict::ok(),
+            _ => {
+                let later = if or_later { " or later" } else { "" };
+                Verdict::review(format!(
+                    "{} (LGPL {}{}) requires that users can replace the library; check how it is linked",
+                    dependency_id, version, later
+                ))
+            }
+        },
+        Family::Gpl { version, or_later } => match project {
+            Family::Gpl {
+                version: project_version,
+                or_later: project_or_later,
+            } => {
+                if version == project_version || (or_later && project_version > version) {
+                    Verdict::ok()
+                } else if project_or_later && version > project_version {
+                    Verdict::review(format!(
+                        "{} makes the combined work GPL-{}.0",
+                        dependency_id, version
+                    ))
+                } else {
+                    Verdict::incompatible(format!(
+                        "{} cannot be combined with {}",
+                        dependency_id, project_id
+                    ))
+                }
+            }
+            Family::Agpl if version == 3 || or_later => Verdict::ok(),
+            _ => Verdict::incompatible(format!(
+                "{} is strong copyleft: the project would have to be released under the GPL",
+                dependency_id
+            )),
+        },
+        Family::Agpl => match project {
+            Family::Agpl => Verdict::ok(),
+            Family::Gpl { version, or_later } if version == 3 || or_later => {
+                Verdict::review(format!(
+                    "{} adds network-use source disclosure to the combined work",
+                    dependency_id
+                ))
+            }
+            _ => Verdict::incompatible(format!(
+                "{} is strong copyleft, including network use: the project would have to be released under the AGPL",
+                dependency_id
+            )),
+        },
+        Family::Other => Verdict::review(format!("unrecognised license '{}'", dependency_id)),
+    }
+}
+
+/// SPDX identifier for a `License ::` trove classifier of a Python package
+pub fn from_classifier(classifier: &str) -> Option<&'static str> {
+    let name = classifier.rsplit("::").next()?.trim();
+    let id = match name {
+        "MIT License" => "MIT",
+        "MIT No Attribution License (MIT-0)" => "MIT-0",
+        "Apache Software License" => "Apache-2.0",
+        "BSD License" => "BSD-3-Clause",
+        "ISC License (ISCL)" => "ISC",
+        "The Unlicense (Unlicense)" => "Unlicense",
+        "zlib/libpng License" => "Zlib",
+        "Python Software Foundation License" => "PSF-2.0",
+        "Mozilla Public License 2.0 (MPL 2.0)" => "MPL-2.0",
+        "Eclipse Public License 2.0 (EPL-2.0)" => "EPL-2.0",
+        "GNU Lesser General Public License v2 (LGPLv2)" => "LGPL-2.1-only",
+        "GNU Lesser General Public License v2 or later (LGPLv2+)" => "LGPL-2.1-or-later",
+        "GNU Lesser General Public License v3 (LGPLv3)" => "LGPL-3.0-only",
+        "GNU Lesser General Public License v3 or later (LGPLv3+)" => "LGPL-3.0-or-later",
+        "GNU General Public License v2 (GPLv2)" => "GPL-2.0-only",
+        "GNU General Public License v2 or later (GPLv2+)" => "GPL-2.0-or-later",
+        "GNU General Public License v3 (GPLv3)" => "GPL-3.0-only",
+        "GNU General Public License v3 or later (GPLv3+)" => "GPL-3.0-or-later",
+        "GNU Affero General Public License v3" => "AGPL-3.0-only",
+        "GNU Affero General Public License v3 or later (AGPLv3+)" => "AGPL-3.0-or-later",
+        _ => return None,
+    };
+    Some(id)
+}
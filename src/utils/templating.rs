@@ -0,0 +1,221 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use colored::*;
+use regex::{Captures, Regex};
+
+use crate::utils::prompt;
+
+/// Render the variables and conditional blocks of a template:
+///
+/// ```text
+/// {{ project_name }}              value of `project_name`
+/// {{ reviewers | @org/team }}     value of `reviewers`, or `@org/team` when it has none
+/// {{#if security}} … {{/if}}      kept when `security` is set and not false/no/0/off
+/// {{#if ticket_url}} … {{else}} … {{/if}}
+/// ```
+///
+/// Names are matched like `--param` keys (case-insensitively, `-` and `_`
+/// alike). Missing values are prompted for when `interactive` is set;
+/// otherwise conditions are false and variables without a default are
+/// left as `[name]`. Tags on a line of their own take the line with them.
+pub fn render(text: &str, params: &HashMap<String, String>, interactive: bool) -> Result<String> {
+    let mut values: HashMap<String, String> = params
+        .iter()
+        .map(|(key, value)| (prompt::normalize_key(key), value.clone()))
+        .collect();
+    let mut used = HashSet::new();
+
+    let text = render_blocks(text, &values, &mut used, interactive)?;
+    let (text, unfilled) = render_variables(&text, &mut values, &mut used, interactive);
+
+    let unused: Vec<&String> = params
+        .keys()
+        .filter(|key| !used.contains(&prompt::normalize_key(key)))
+        .collect();
+    if !unused.is_empty() {
+        println!(
+            "{} Warning: {} unused parameter(s):",
+            "⚠".yellow(),
+            unused.len()
+        );
+        for key in unused {
+            println!("  - {}", key);
+        }
+    }
+    if !unfilled.is_empty() {
+        println!(
+            "{} Warning: {} variable(s) remain unfilled:",
+            "⚠".yellow(),
+            unfilled.len()
+        );
+        for name in &unfilled {
+            println!("  - [{}]", name);
+        }
+        println!("  Use --interactive or --param to provide values for these variables.");
+    }
+
+    Ok(text)
+}
+
+/// Resolve `{{#if}}`/`{{else}}`/`{{/if}}`, innermost state last on the stack
+fn render_blocks(
+    text: &str,
+    values: &HashMap<String, String>,
+    used: &mut HashSet<String>,
+    interactive: bool,
+) -> Result<String> {
+    let tag = Regex::new(
+        r"(?m)(^[ \t]*)?\{\{\s*(?:#if\s+([A-Za-z][\w-]*)|(else)|(/if))\s*\}\}([ \t]*(?:\r?\n|$))?",
+    )
+    .unwrap();
+
+    struct Block {
+        name: String,
+        /// Whether the enclosing blocks are all being kept
+        parent_active: bool,
+        condition: bool,
+        in_else: bool,
+    }
+    let is_active = |stack: &[Block]| {
+        stack
+            .last()
+            .is_none_or(|b| b.parent_active && (b.condition != b.in_else))
+    };
+
+    let mut out = String::new();
+    let mut stack: Vec<Block> = Vec::new();
+    let mut answers = HashMap::new();
+    let mut last = 0;
+
+    for caps in tag.captures_iter(text) {
+        let whole = caps.get(0).unwrap();
+        let standalone = caps.get(1).is_some() && caps.get(5).is_some();
+
+        // Text up to the tag, keeping the indentation of an inline tag
+        let end = if standalone {
+            whole.start()
+        } else {
+            caps.get(1).map_or(whole.start(), |m| m.end())
+        };
+        if is_active(&stack) {
+            out.push_str(&text[last..end]);
+        }
+        last = if standalone {
+            whole.end()
+        } else {
+            whole.end() - caps.get(5).map_or(0, |m| m.len())
+        };
+
+        if let Some(name) = caps.get(2) {
+            let parent_active = is_active(&stack);
+            let condition = if parent_active {
+                condition(
+                    name.as_str(),
+                    values,
+                    used,
+                    interactive.then_some(&mut answers),
+                )
+            } else {
+                false
+            };
+            stack.push(Block {
+                name: name.as_str().to_string(),
+                parent_active,
+                condition,
+                in_else: false,
+            });
+        } else if caps.get(3).is_some() {
+            match stack.last_mut() {
+                Some(block) if !block.in_else => block.in_else = true,
+                Some(block) => {
+                    return Err(anyhow!(
+                        "Second {{{{else}}}} in {{{{#if {}}}}}.",
+                        block.name
+                    ));
+                }
+                None => return Err(anyhow!("{{{{else}}}} outside of an {{{{#if}}}} block.")),
+            }
+        } else if stack.pop().is_none() {
+            return Err(anyhow!("{{{{/if}}}} without a matching {{{{#if}}}}."));
+        }
+    }
+
+    if let Some(block) = stack.last() {
+        return Err(anyhow!(
+            "{{{{#if {}}}}} is not closed with {{{{/if}}}}.",
+            block.name
+        ));
+    }
+    out.push_str(&text[last..]);
+    Ok(out)
+}
+
+/// Whether the block of `name` is kept. Prompted answers are kept apart
+/// from `values`, so a variable used as a condition is still asked for.
+fn condition(
+    name: &str,
+    values: &HashMap<String, String>,
+    used: &mut HashSet<String>,
+    answers: Option<&mut HashMap<String, bool>>,
+) -> bool {
+    let key = prompt::normalize_key(name);
+    if let Some(value) = values.get(&key) {
+        used.insert(key);
+        return is_truthy(value);
+    }
+    match answers {
+        Some(answers) => *answers
+            .entry(key)
+            .or_insert_with(|| prompt::confirm(&format!("Include the '{}' section?", name), false)),
+        None => false,
+    }
+}
+
+fn render_variables(
+    text: &str,
+    values: &mut HashMap<String, String>,
+    used: &mut HashSet<String>,
+    interactive: bool,
+) -> (String, Vec<String>) {
+    let variable = Regex::new(r"\{\{\s*([A-Za-z][\w-]*)\s*(?:\|\s*(.*?)\s*)?\}\}").unwrap();
+    let mut unfilled: Vec<String> = Vec::new();
+
+    let result = variable.replace_all(text, |caps: &Captures| {
+        let name = &caps[1];
+        let key = prompt::normalize_key(name);
+        let default = caps.get(2).map(|m| m.as_str());
+
+        if let Some(value) = values.get(&key) {
+            used.insert(key);
+            return value.clone();
+        }
+
+        let entered = if interactive {
+            match default {
+                Some(default) => prompt::ask(&format!("Enter value for '{}' [{}]", name, default)),
+                None => prompt::ask(&format!("Enter value for '{}'", name)),
+            }
+        } else {
+            String::new()
+        };
+        let value = match default {
+            _ if !entered.is_empty() => entered,
+            Some(default) => default.to_string(),
+            None => {
+                unfilled.push(name.to_string());
+                format!("[{}]", name)
+            }
+        };
+        // Later uses of the variable get the same value without asking again
+        values.insert(key, value.clone());
+        value
+    });
+
+    (result.to_string(), unfilled)
+}
+
+fn is_truthy(value: &str) -> bool {
+    let value = value.trim().to_lowercase();
+    !matches!(value.as_str(), "" | "false" | "no" | "0" | "off")
+}
@@ -0,0 +1,88 @@
+use serde_yaml::Value;
+
+/// Set a top-level key of a YAML mapping by editing its lines only.
+///
+/// The lines of an existing `key:` (the key line and its indented or `- `
+/// continuation lines) are replaced; a missing key is inserted before the
+/// top-level `before` key (and the comments heading it), or appended.
+/// Every other line, comments and quoting included, is kept as written.
+pub fn set_key(
+    content: &str,
+    key: &str,
+    value: &Value,
+    before: Option<&str>,
+) -> anyhow::Result<String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let rendered = render(key, value)?;
+
+    match find_key(&lines, key) {
+        Some(start) => {
+            let end = block_end(&lines, start);
+            lines.splice(start..end, rendered.lines());
+        }
+        None => {
+            let at = before
+                .and_then(|before| find_key(&lines, before))
+                .map(|index| leading_comments(&lines, index))
+                .unwrap_or(lines.len());
+            lines.splice(at..at, rendered.lines());
+        }
+    }
+
+    let mut result = lines.join("\n");
+    result.push('\n');
+    Ok(result)
+}
+
+/// `key: value` for scalars and empty collections, a block indented by two
+/// spaces otherwise
+fn render(key: &str, value: &Value) -> anyhow::Result<String> {
+    let serialized = serde_yaml::to_string(value)?;
+    let is_block = match value {
+        Value::Sequence(items) => !items.is_empty(),
+        Value::Mapping(entries) => !entries.is_empty(),
+        _ => false,
+    };
+
+    if !is_block {
+        return Ok(format!("{}: {}", key, serialized.trim_end()));
+    }
+    let block: String = serialized
+        .lines()
+        .map(|line| format!("\n  {}", line))
+        .collect();
+    Ok(format!("{}:{}", key, block))
+}
+
+fn find_key(lines: &[&str], key: &str) -> Option<usize> {
+    lines.iter().position(|line| {
+        line.strip_prefix(key)
+            .is_some_and(|rest| rest.trim_start().starts_with(':'))
+    })
+}
+
+/// One past the last line belonging to the key at `start`; blank lines and
+/// comments after the value stay with what follows
+fn block_end(lines: &[&str], start: usize) -> usize {
+    let mut end = start + 1;
+    for (index, line) in lines.iter().enumerate().skip(start + 1) {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with([' ', '\t', '-']) && !line.starts_with("---") {
+            end = index + 1;
+        } else {
+            break;
+        }
+    }
+    end
+}
+
+/// The first line of the comment block directly above `index`
+fn leading_comments(lines: &[&str], index: usize) -> usize {
+    let mut start = index;
+    while start > 0 && lines[start - 1].starts_with('#') {
+        start -= 1;
+    }
+    start
+}
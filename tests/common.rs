@@ -1,2 +1,3 @@
+#[allow(dead_code)]
 #[path = "common/test_utils.rs"]
 mod test_utils;
@@ -1,11 +1,14 @@
-use std::path::PathBuf;
+use assert_cmd::Command as AssertCommand;
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 
+#[allow(clippy::let_and_return)]
 pub fn setup_test_env() -> TempDir {
     let temp_dir = TempDir::new().unwrap();
     temp_dir
 }
 
+#[allow(clippy::needless_borrows_for_generic_args)]
 pub fn create_git_repo(path: &PathBuf) {
     std::process::Command::new("git")
         .args(&["init"])
@@ -22,3 +25,48 @@ pub fn assert_file_contains(file_path: &PathBuf, content: &str) {
 pub fn assert_file_exists(file_path: &PathBuf) {
     assert!(file_path.exists(), "File {:?} should exist", file_path);
 }
+
+/// `gh-templates` run in `temp_path`, with `HOME` pointing there so caches stay in the test directory
+pub fn gh_templates(temp_path: &Path) -> AssertCommand {
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(temp_path).env("HOME", temp_path);
+    cmd
+}
+
+/// Like `gh_templates`, with template sources read from the config at `config_path`
+pub fn gh_templates_with_config(temp_path: &Path, config_path: &Path) -> AssertCommand {
+    let mut cmd = gh_templates(temp_path);
+    cmd.env("GH_TEMPLATES_CONFIG", config_path);
+    cmd
+}
+
+/// Write `content` to `path`, creating its parent directories
+pub fn write_file(path: &Path, content: &str) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+/// Write a registry at `registry` whose manifest of type `kind` lists the
+/// given `(file name, content)` templates
+pub fn write_registry<N: AsRef<str>>(registry: &Path, kind: &str, templates: &[(N, &str)]) {
+    let mut manifest = format!("type: {}\ntemplates:\n", kind);
+    for (name, content) in templates {
+        manifest.push_str(&format!("  - {}\n", name.as_ref()));
+        write_file(&registry.join(name.as_ref()), content);
+    }
+    write_file(&registry.join("manifest.yml"), &manifest);
+}
+
+/// Write a config file defining `acme` for `category` and return its path
+pub fn write_config(root: &Path, category: &str, registry: &Path) -> PathBuf {
+    let config_path = root.join("config.toml");
+    write_file(
+        &config_path,
+        &format!(
+            "[sources.{}]\nacme = \"file://{}\"\n",
+            category,
+            registry.display()
+        ),
+    );
+    config_path
+}
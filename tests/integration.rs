@@ -5,14 +5,84 @@ mod common;
 
 // Integration tests
 
+#[allow(clippy::needless_borrows_for_generic_args)]
 #[path = "integration/license_tests.rs"]
 mod license_tests;
 
+#[allow(clippy::needless_borrows_for_generic_args)]
 #[path = "integration/gitignore_tests.rs"]
 mod gitignore_tests;
 
+#[allow(clippy::needless_borrows_for_generic_args)]
 #[path = "integration/issue_tests.rs"]
 mod issue_tests;
 
+#[allow(clippy::needless_borrows_for_generic_args, clippy::useless_format)]
 #[path = "integration/pr_tests.rs"]
 mod pr_tests;
+
+#[path = "integration/ci_tests.rs"]
+mod ci_tests;
+
+#[path = "integration/code_of_conduct_tests.rs"]
+mod code_of_conduct_tests;
+
+#[path = "integration/offline_tests.rs"]
+mod offline_tests;
+
+#[path = "integration/embedded_tests.rs"]
+mod embedded_tests;
+
+#[path = "integration/source_tests.rs"]
+mod source_tests;
+
+#[path = "integration/apply_tests.rs"]
+mod apply_tests;
+
+#[path = "integration/status_tests.rs"]
+mod status_tests;
+
+#[path = "integration/dry_run_tests.rs"]
+mod dry_run_tests;
+
+#[path = "integration/format_tests.rs"]
+mod format_tests;
+
+#[path = "integration/pick_tests.rs"]
+mod pick_tests;
+
+#[path = "integration/gitignore_merge_tests.rs"]
+mod gitignore_merge_tests;
+
+#[path = "integration/gitignore_append_tests.rs"]
+mod gitignore_append_tests;
+
+#[path = "integration/gitignore_check_tests.rs"]
+mod gitignore_check_tests;
+
+#[path = "integration/detect_tests.rs"]
+mod detect_tests;
+
+#[path = "integration/issue_validate_tests.rs"]
+mod issue_validate_tests;
+
+#[path = "integration/issue_config_tests.rs"]
+mod issue_config_tests;
+
+#[path = "integration/issue_convert_tests.rs"]
+mod issue_convert_tests;
+
+#[path = "integration/pr_params_tests.rs"]
+mod pr_params_tests;
+
+#[path = "integration/pr_link_tests.rs"]
+mod pr_link_tests;
+
+#[path = "integration/pr_lint_tests.rs"]
+mod pr_lint_tests;
+
+#[path = "integration/pr_compose_tests.rs"]
+mod pr_compose_tests;
+
+#[path = "integration/license_check_tests.rs"]
+mod license_check_tests;
@@ -0,0 +1,162 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+
+/**
+Integration tests for the `apply` command and `.gh-templates.toml`.
+
+This test suite covers the following scenarios:
+
+- `test_apply_installs_declared_templates`: Verifies that issue, PR, CI and code of conduct templates are installed.
+- `test_apply_gitignore_from_source`: Verifies that gitignore stacks honour the section's `source`.
+- `test_apply_existing_files_require_force`: Ensures that existing files are only overwritten with `--force`.
+- `test_apply_missing_config`: Ensures that a repository without `.gh-templates.toml` fails with a helpful error.
+- `test_apply_unknown_section`: Ensures that unknown keys in `.gh-templates.toml` are rejected.
+
+First-party templates are served from the copies built into the binary, so no network access is needed.
+*/
+// Import utility functions
+use crate::common::test_utils::{
+    assert_file_contains, assert_file_exists, create_git_repo, gh_templates, setup_test_env,
+};
+
+fn write_project_config(root: &Path, content: &str) {
+    fs::write(root.join(".gh-templates.toml"), content).unwrap();
+}
+
+#[test]
+fn test_apply_installs_declared_templates() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    write_project_config(
+        &temp_path,
+        r#"
+[issue]
+templates = ["bug", "feature"]
+
+[pr]
+templates = ["default"]
+
+[ci]
+templates = ["python/lint/ruff"]
+
+[code-of-conduct]
+params = { contact-email = "conduct@example.com", enforcement-team = "Core Team" }
+"#,
+    );
+
+    gh_templates(&temp_path)
+        .arg("apply")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Applied all templates"));
+
+    assert_file_exists(&temp_path.join(".github/ISSUE_TEMPLATE/bug.yml"));
+    assert_file_exists(&temp_path.join(".github/ISSUE_TEMPLATE/feature.yml"));
+    assert_file_exists(&temp_path.join(".github/pull_request_template.md"));
+    assert_file_exists(&temp_path.join(".github/workflows/python-lint-ruff.yml"));
+    assert_file_contains(&temp_path.join("CODE_OF_CONDUCT.md"), "conduct@example.com");
+}
+
+#[test]
+fn test_apply_gitignore_from_source() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let registry = temp_path.join("registry");
+    fs::create_dir_all(&registry).unwrap();
+    fs::write(
+        registry.join("manifest.yml"),
+        "type: gitignore\ntemplates:\n  - Rust.gitignore\n  - Node.gitignore\n",
+    )
+    .unwrap();
+    fs::write(registry.join("Rust.gitignore"), "/target\n").unwrap();
+    fs::write(registry.join("Node.gitignore"), "node_modules/\n").unwrap();
+
+    let config_path = temp_path.join("config.toml");
+    fs::write(
+        &config_path,
+        format!(
+            "[sources.gitignore]\nlocal = \"file://{}\"\n",
+            registry.display()
+        ),
+    )
+    .unwrap();
+
+    write_project_config(
+        &temp_path,
+        "[gitignore]\ntemplates = [\"rust\", \"node\"]\nsource = \"local\"\n",
+    );
+
+    gh_templates(&temp_path)
+        .env("GH_TEMPLATES_CONFIG", &config_path)
+        .arg("apply")
+        .assert()
+        .success();
+
+    assert_file_contains(&temp_path.join(".gitignore"), "/target");
+    assert_file_contains(&temp_path.join(".gitignore"), "node_modules/");
+}
+
+#[test]
+fn test_apply_existing_files_require_force() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    write_project_config(&temp_path, "[pr]\ntemplates = [\"default\"]\n");
+    fs::create_dir_all(temp_path.join(".github")).unwrap();
+    fs::write(temp_path.join(".github/pull_request_template.md"), "custom").unwrap();
+
+    gh_templates(&temp_path)
+        .arg("apply")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to apply pr"));
+
+    assert_file_contains(
+        &temp_path.join(".github/pull_request_template.md"),
+        "custom",
+    );
+
+    gh_templates(&temp_path)
+        .args(["apply", "--force"])
+        .assert()
+        .success();
+
+    assert_file_contains(
+        &temp_path.join(".github/pull_request_template.md"),
+        "## Description",
+    );
+}
+
+#[test]
+fn test_apply_missing_config() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .arg("apply")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No .gh-templates.toml found"));
+}
+
+#[test]
+fn test_apply_unknown_section() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    write_project_config(&temp_path, "[issues]\ntemplates = [\"bug\"]\n");
+
+    gh_templates(&temp_path)
+        .arg("apply")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to parse project config"));
+}
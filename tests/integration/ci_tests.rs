@@ -0,0 +1,197 @@
+use assert_cmd::Command as AssertCommand;
+use predicates::prelude::*;
+use std::fs;
+
+/**
+Integration tests for the `gh-templates` ci subcommand.
+
+This test suite covers the following scenarios:
+
+- `test_ci_add_ruff`: Verifies that adding a nested CI template writes it into `.github/workflows/`.
+- `test_ci_add_same_name_in_two_categories`: Ensures templates sharing a file name in different categories are written to separate workflows.
+- `test_ci_add_with_output`: Tests adding a CI template with an output name that does not have an extension.
+- `test_ci_add_force_overwrite`: Tests that an existing workflow file is not overwritten unless the `--force` flag is used.
+- `test_ci_add_invalid_template`: Confirms that an unknown CI template returns an appropriate error.
+- `test_ci_add_no_template`: Ensures that running the command without specifying a template results in an error.
+- `test_ci_add_uneven_templates_and_outputs`: Ensures that an error is raised when the number of templates does not match the number of output file names.
+- `test_ci_list`: Ensures the list command walks the nested manifests.
+- `test_ci_preview_ruff`: Validates that the preview command displays the content of a CI template.
+- `test_ci_help_command`: Validates that the help command displays usage information for the ci subcommands.
+
+Each test uses a temporary directory to avoid side effects and leverages `assert_cmd` and `predicates` for command-line assertions.
+*/
+// Import utility functions
+use crate::common::test_utils::{
+    assert_file_contains, assert_file_exists, create_git_repo, setup_test_env,
+};
+
+// --------     ADD COMMAND TESTS     --------
+
+#[test]
+fn test_ci_add_ruff() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args(["ci", "add", "python/lint/ruff"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("✓"));
+
+    assert_file_exists(&temp_path.join(".github/workflows/python-lint-ruff.yml"));
+    assert_file_contains(
+        &temp_path.join(".github/workflows/python-lint-ruff.yml"),
+        "Ruff",
+    );
+}
+
+#[test]
+fn test_ci_add_same_name_in_two_categories() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args(["ci", "add", "python/lint/black", "python/format/black"])
+        .assert()
+        .success();
+
+    assert_file_exists(&temp_path.join(".github/workflows/python-lint-black.yml"));
+    assert_file_exists(&temp_path.join(".github/workflows/python-format-black.yml"));
+}
+
+#[test]
+fn test_ci_add_with_output() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args(["ci", "add", "python/format/black", "-o", "format"])
+        .assert()
+        .success();
+
+    assert_file_exists(&temp_path.join(".github/workflows/format.yml"));
+}
+
+#[test]
+fn test_ci_add_force_overwrite() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+
+    let workflow_path = temp_path.join(".github/workflows/python-test-pytest.yml");
+    fs::create_dir_all(workflow_path.parent().unwrap()).unwrap();
+    fs::write(&workflow_path, "existing content").unwrap();
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args(["ci", "add", "python/test/pytest"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args(["ci", "add", "python/test/pytest", "--force"])
+        .assert()
+        .success();
+
+    assert_file_contains(&workflow_path, "pytest");
+}
+
+#[test]
+fn test_ci_add_invalid_template() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args(["ci", "add", "python/lint/nonexistent"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_ci_add_no_template() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args(["ci", "add"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No CI template specified"));
+}
+
+#[test]
+fn test_ci_add_uneven_templates_and_outputs() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args([
+        "ci",
+        "add",
+        "python/lint/ruff",
+        "python/test/pytest",
+        "-o",
+        "lint.yml",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "The number of templates and output file names must match.",
+    ));
+}
+
+// --------     LIST COMMAND TESTS     --------
+
+#[test]
+fn test_ci_list() {
+    let _temp_dir = setup_test_env();
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.args(["ci", "list", "python/lint"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("python/lint/ruff"));
+}
+
+// --------     PREVIEW COMMAND TESTS     --------
+
+#[test]
+fn test_ci_preview_ruff() {
+    let _temp_dir = setup_test_env();
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.args(["ci", "preview", "python/lint/ruff"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Ruff"));
+}
+
+#[test]
+fn test_ci_help_command() {
+    let _temp_dir = setup_test_env();
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.args(["ci", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("CI"))
+        .stdout(predicate::str::contains("add"))
+        .stdout(predicate::str::contains("preview"))
+        .stdout(predicate::str::contains("list"));
+}
@@ -0,0 +1,122 @@
+use assert_cmd::Command as AssertCommand;
+use predicates::prelude::*;
+
+/**
+Integration tests for the `gh-templates` code-of-conduct subcommand.
+
+This test suite covers the following scenarios:
+
+- `test_code_of_conduct_add_default`: Verifies that the code of conduct is written to the repository root.
+- `test_code_of_conduct_add_github_dir`: Ensures that `--github` writes the file into `.github/`.
+- `test_code_of_conduct_add_with_params`: Tests that `--param` fills the contact and enforcement placeholders.
+- `test_code_of_conduct_add_invalid_template`: Confirms that an unknown code of conduct returns an appropriate error.
+- `test_code_of_conduct_list`: Ensures the list command displays the available codes of conduct.
+- `test_code_of_conduct_preview`: Validates that the preview command displays the code of conduct.
+
+Each test uses a temporary directory to avoid side effects and leverages `assert_cmd` and `predicates` for command-line assertions.
+*/
+// Import utility functions
+use crate::common::test_utils::{
+    assert_file_contains, assert_file_exists, create_git_repo, setup_test_env,
+};
+
+// --------     ADD COMMAND TESTS     --------
+
+#[test]
+fn test_code_of_conduct_add_default() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args(["code-of-conduct", "add"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("contains placeholders"));
+
+    assert_file_exists(&temp_path.join("CODE_OF_CONDUCT.md"));
+    assert_file_contains(&temp_path.join("CODE_OF_CONDUCT.md"), "[CONTACT EMAIL]");
+}
+
+#[test]
+fn test_code_of_conduct_add_github_dir() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args(["code-of-conduct", "add", "--github"])
+        .assert()
+        .success();
+
+    assert_file_exists(&temp_path.join(".github/CODE_OF_CONDUCT.md"));
+}
+
+#[test]
+fn test_code_of_conduct_add_with_params() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args([
+        "code-of-conduct",
+        "add",
+        "--param",
+        "contact-email=conduct@example.com",
+        "--param",
+        "enforcement-team=the moderators",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Filled 2 out of 2"));
+
+    let content = std::fs::read_to_string(temp_path.join("CODE_OF_CONDUCT.md")).unwrap();
+    assert!(content.contains("conduct@example.com"));
+    assert!(content.contains("the moderators"));
+    // Markdown links are not placeholders
+    assert!(content.contains("[Contributor Covenant]"));
+}
+
+#[test]
+fn test_code_of_conduct_add_invalid_template() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args(["code-of-conduct", "add", "nonexistent"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not found"));
+}
+
+// --------     LIST COMMAND TESTS     --------
+
+#[test]
+fn test_code_of_conduct_list() {
+    let _temp_dir = setup_test_env();
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.args(["code-of-conduct", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("default"));
+}
+
+// --------     PREVIEW COMMAND TESTS     --------
+
+#[test]
+fn test_code_of_conduct_preview() {
+    let _temp_dir = setup_test_env();
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.args(["code-of-conduct", "preview"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Code of Conduct"));
+}
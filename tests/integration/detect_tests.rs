@@ -0,0 +1,195 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/**
+Integration tests for stack detection (`detect` and `gitignore add --auto`).
+
+This test suite covers the following scenarios:
+
+- `test_detect_markers`: Verifies that marker files map to gitignore and CI templates.
+- `test_detect_license_from_manifest`: Verifies that a declared license is suggested when no LICENSE file exists.
+- `test_detect_skips_dependency_dirs`: Ensures that markers inside `node_modules` are ignored.
+- `test_detect_json`: Verifies the machine-readable output of `detect --format json`.
+- `test_gitignore_add_auto`: Verifies that `gitignore add --auto` installs the detected gitignore templates.
+- `test_gitignore_add_auto_on_tty`: Ensures that `--auto` detects templates instead of opening the picker on a terminal.
+*/
+// Import utility functions
+use crate::common::test_utils::{
+    create_git_repo, gh_templates, setup_test_env, write_config, write_file,
+};
+
+#[test]
+fn test_detect_markers() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    write_file(
+        &temp_path.join("Cargo.toml"),
+        "[package]\nname = \"demo\"\n",
+    );
+    write_file(&temp_path.join("scripts/pyproject.toml"), "[project]\n");
+    fs::create_dir_all(temp_path.join(".vscode")).unwrap();
+
+    gh_templates(&temp_path)
+        .arg("detect")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("rust"))
+        .stdout(predicate::str::contains("found Cargo.toml"))
+        .stdout(predicate::str::contains("python"))
+        .stdout(predicate::str::contains("found scripts/pyproject.toml"))
+        .stdout(predicate::str::contains("global-visualstudiocode"))
+        .stdout(predicate::str::contains("python/lint/ruff"))
+        .stdout(predicate::str::contains(
+            "gh-templates ci add python/lint/ruff python/test/pytest",
+        ));
+}
+
+#[test]
+fn test_detect_license_from_manifest() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    write_file(
+        &temp_path.join("package.json"),
+        r#"{"name": "demo", "license": "MIT"}"#,
+    );
+
+    gh_templates(&temp_path)
+        .arg("detect")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("declared in package.json"))
+        .stdout(predicate::str::contains("gh-templates license add mit"));
+
+    write_file(&temp_path.join("LICENSE"), "MIT License\n");
+
+    gh_templates(&temp_path)
+        .arg("detect")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("license add").not());
+}
+
+#[test]
+fn test_detect_skips_dependency_dirs() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    write_file(&temp_path.join("package.json"), "{}");
+    write_file(&temp_path.join("node_modules/dep/go.mod"), "module dep\n");
+
+    gh_templates(&temp_path)
+        .args(["detect", "--format", "tsv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "gitignore\tnode\tfound package.json",
+        ))
+        .stdout(predicate::str::contains("\tgo\t").not());
+}
+
+#[test]
+fn test_detect_json() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    write_file(&temp_path.join("go.mod"), "module demo\n");
+
+    let output = gh_templates(&temp_path)
+        .args(["detect", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let records: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(records[0]["category"], "gitignore");
+    assert_eq!(records[0]["template"], "go");
+    assert_eq!(records[0]["reason"], "found go.mod");
+}
+
+/// Mark the repository as a Rust project and write a local gitignore source
+/// named `acme`; returns the config path
+fn setup_rust_project(temp_path: &Path) -> PathBuf {
+    write_file(
+        &temp_path.join("Cargo.toml"),
+        "[package]\nname = \"demo\"\n",
+    );
+
+    let registry = temp_path.join("registry");
+    write_file(
+        &registry.join("manifest.yml"),
+        "type: gitignore\ntemplates:\n  - Rust.gitignore\n  Global: Global/\n",
+    );
+    write_file(&registry.join("Rust.gitignore"), "/target\n");
+    write_file(
+        &registry.join("Global/manifest.yml"),
+        "type: gitignore\ntemplates:\n  - Linux.gitignore\n  - macOS.gitignore\n  - Windows.gitignore\n",
+    );
+    write_file(&registry.join("Global/Linux.gitignore"), "*~\n");
+    write_file(&registry.join("Global/macOS.gitignore"), ".DS_Store\n");
+    write_file(&registry.join("Global/Windows.gitignore"), "Thumbs.db\n");
+    write_config(temp_path, "gitignore", &registry)
+}
+
+#[test]
+fn test_gitignore_add_auto() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let config_path = setup_rust_project(&temp_path);
+
+    gh_templates(&temp_path)
+        .env("GH_TEMPLATES_CONFIG", &config_path)
+        .args(["gitignore", "add", "--auto", "--source", "acme"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Detected gitignore templates: rust",
+        ));
+
+    let content = fs::read_to_string(temp_path.join(".gitignore")).unwrap();
+    assert!(content.contains("# ===== rust.gitignore =====\n/target"));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_gitignore_add_auto_on_tty() {
+    use assert_cmd::cargo::cargo_bin;
+    use std::process::Command;
+
+    // util-linux `script` runs the command on a pseudo-terminal, where the picker
+    // would open; `timeout` stops it waiting for a selection
+    if Command::new("script").arg("--version").output().is_err() {
+        return;
+    }
+
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    let config_path = setup_rust_project(&temp_path);
+
+    let command = format!(
+        "{} gitignore add --auto --source acme",
+        cargo_bin("gh-templates").display()
+    );
+    let output = Command::new("timeout")
+        .args(["60", "script", "-qec", &command, "/dev/null"])
+        .current_dir(&temp_path)
+        .env("HOME", &temp_path)
+        .env("GH_TEMPLATES_CONFIG", &config_path)
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("Detected gitignore templates: rust"));
+    let content = fs::read_to_string(temp_path.join(".gitignore")).unwrap();
+    assert!(content.contains("/target"));
+}
@@ -0,0 +1,180 @@
+use predicates::prelude::*;
+use std::fs;
+
+/**
+Integration tests for the global `--dry-run` flag.
+
+This test suite covers the following scenarios:
+
+- `test_dry_run_create`: Verifies that a new file is reported as created with its content as a diff.
+- `test_dry_run_overwrite`: Verifies that `--force` over an existing file prints a diff and leaves it untouched.
+- `test_dry_run_append`: Verifies that `gitignore add --append` is reported as an append.
+- `test_dry_run_existing_without_force`: Ensures that a write that would fail still fails.
+- `test_dry_run_update`: Verifies that `update` prints the change without writing the file or the lockfile.
+
+First-party templates are served from the copies built into the binary, so no network access is needed.
+*/
+// Import utility functions
+use crate::common::test_utils::{
+    assert_file_contains, create_git_repo, gh_templates, setup_test_env,
+};
+
+#[test]
+fn test_dry_run_create() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["--dry-run", "issue", "add", "bug"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Would create .github/ISSUE_TEMPLATE/bug.yml",
+        ))
+        .stdout(predicate::str::contains(
+            "+description: Report unexpected behavior",
+        ));
+
+    assert!(!temp_path.join(".github").exists());
+    assert!(!temp_path.join(".gh-templates.lock").exists());
+}
+
+#[test]
+fn test_dry_run_overwrite() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let template = temp_path.join(".github/pull_request_template.md");
+    fs::create_dir_all(template.parent().unwrap()).unwrap();
+    fs::write(&template, "Our own template\n").unwrap();
+
+    gh_templates(&temp_path)
+        .args(["--dry-run", "pr", "add", "default", "--force"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Would overwrite .github/pull_request_template.md",
+        ))
+        .stdout(predicate::str::contains("-Our own template"));
+
+    assert_eq!(fs::read_to_string(&template).unwrap(), "Our own template\n");
+}
+
+#[test]
+fn test_dry_run_append() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let registry = temp_path.join("registry");
+    fs::create_dir_all(&registry).unwrap();
+    fs::write(
+        registry.join("manifest.yml"),
+        "type: gitignore\ntemplates:\n  - Rust.gitignore\n",
+    )
+    .unwrap();
+    fs::write(registry.join("Rust.gitignore"), "/target\n").unwrap();
+    let config_path = temp_path.join("config.toml");
+    fs::write(
+        &config_path,
+        format!(
+            "[sources.gitignore]\nlocal = \"file://{}\"\n",
+            registry.display()
+        ),
+    )
+    .unwrap();
+
+    fs::write(temp_path.join(".gitignore"), ".env\n").unwrap();
+
+    gh_templates(&temp_path)
+        .env("GH_TEMPLATES_CONFIG", &config_path)
+        .args([
+            "--dry-run",
+            "gitignore",
+            "add",
+            "rust",
+            "--append",
+            "--source",
+            "local",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Would append to"))
+        .stdout(predicate::str::contains("+/target"));
+
+    assert_eq!(
+        fs::read_to_string(temp_path.join(".gitignore")).unwrap(),
+        ".env\n"
+    );
+}
+
+#[test]
+fn test_dry_run_existing_without_force() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["pr", "add", "default"])
+        .assert()
+        .success();
+
+    gh_templates(&temp_path)
+        .args(["--dry-run", "pr", "add", "default"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+}
+
+#[test]
+fn test_dry_run_update() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let registry = temp_path.join("registry");
+    fs::create_dir_all(&registry).unwrap();
+    fs::write(
+        registry.join("manifest.yml"),
+        "type: issue\ntemplates:\n  - bug.yml\n",
+    )
+    .unwrap();
+    fs::write(registry.join("bug.yml"), "name: Bug\n").unwrap();
+    let config_path = temp_path.join("config.toml");
+    fs::write(
+        &config_path,
+        format!(
+            "[sources.issue]\nacme = \"file://{}\"\n",
+            registry.display()
+        ),
+    )
+    .unwrap();
+
+    gh_templates(&temp_path)
+        .env("GH_TEMPLATES_CONFIG", &config_path)
+        .args(["issue", "add", "bug", "--source", "acme"])
+        .assert()
+        .success();
+    let lockfile = fs::read_to_string(temp_path.join(".gh-templates.lock")).unwrap();
+
+    fs::write(registry.join("bug.yml"), "name: Bug Report\n").unwrap();
+
+    gh_templates(&temp_path)
+        .env("GH_TEMPLATES_CONFIG", &config_path)
+        .args(["--dry-run", "update"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Would overwrite"))
+        .stdout(predicate::str::contains("+name: Bug Report"));
+
+    assert_file_contains(
+        &temp_path.join(".github/ISSUE_TEMPLATE/bug.yml"),
+        "name: Bug\n",
+    );
+    assert_eq!(
+        fs::read_to_string(temp_path.join(".gh-templates.lock")).unwrap(),
+        lockfile
+    );
+}
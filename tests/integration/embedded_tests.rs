@@ -0,0 +1,68 @@
+use assert_cmd::Command as AssertCommand;
+use predicates::prelude::*;
+
+/**
+Integration tests for the templates embedded into the binary.
+
+This test suite covers the following scenarios:
+
+- `test_embedded_ci_list`: Verifies that the nested CI manifests are listed from the embedded copy.
+- `test_embedded_pr_add_all`: Ensures that `pr add --all` installs every embedded PR template.
+- `test_embedded_unknown_template`: Confirms that a template missing from the embedded copy points to `--remote`.
+- `test_remote_conflicts_with_offline`: Ensures that `--remote` and `--offline` cannot be combined.
+
+Each test uses a temporary directory to avoid side effects and leverages `assert_cmd` and `predicates` for command-line assertions.
+*/
+// Import utility functions
+use crate::common::test_utils::{assert_file_exists, create_git_repo, setup_test_env};
+
+#[test]
+fn test_embedded_ci_list() {
+    let _temp_dir = setup_test_env();
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.args(["ci", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("python/lint/ruff"))
+        .stdout(predicate::str::contains("Ruff Code Linter"));
+}
+
+#[test]
+fn test_embedded_pr_add_all() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args(["pr", "add", "--all"]).assert().success();
+
+    assert_file_exists(&temp_path.join(".github/pull_request_template.md"));
+    assert_file_exists(&temp_path.join(".github/PULL_REQUEST_TEMPLATE/security.md"));
+}
+
+#[test]
+fn test_embedded_unknown_template() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args(["issue", "add", "not-a-template"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--remote"));
+}
+
+#[test]
+fn test_remote_conflicts_with_offline() {
+    let _temp_dir = setup_test_env();
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.args(["--remote", "--offline", "issue", "list"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
@@ -0,0 +1,170 @@
+use assert_cmd::Command as AssertCommand;
+use predicates::prelude::*;
+
+/**
+Integration tests for structured `list` output (`--format json|yaml|tsv`).
+
+This test suite covers the following scenarios:
+
+- `test_issue_list_json`: Verifies that `issue list --format json` emits name, category, URL and description.
+- `test_pr_list_tsv`: Verifies that `pr list --format tsv` prints a header row and one row per template.
+- `test_code_of_conduct_list_yaml`: Verifies that `code-of-conduct list --format yaml` emits a YAML sequence.
+- `test_gitignore_list_json`: Verifies that gitignore templates include their path, category and URL.
+- `test_license_list_json`: Verifies that SPDX licenses include the OSI, FSF and deprecated flags.
+- `test_list_invalid_format`: Ensures that an unknown format is rejected.
+
+Issue, PR and code of conduct templates are built into the binary; gitignore and license
+templates come from `file://` sources so no network access is needed.
+*/
+// Import utility functions
+use crate::common::test_utils::{gh_templates, setup_test_env, write_config, write_file};
+
+fn stdout_json(cmd: &mut AssertCommand) -> serde_json::Value {
+    let output = cmd.assert().success().get_output().stdout.clone();
+    serde_json::from_slice(&output).expect("stdout is valid JSON")
+}
+
+#[test]
+fn test_issue_list_json() {
+    let temp_dir = setup_test_env();
+
+    let templates =
+        stdout_json(gh_templates(temp_dir.path()).args(["issue", "list", "--format", "json"]));
+    let bug = templates
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["name"] == "bug")
+        .expect("bug template is listed");
+
+    assert_eq!(bug["category"], "issue");
+    assert_eq!(bug["description"], "Bug Report Template");
+    assert!(bug["url"]
+        .as_str()
+        .unwrap()
+        .ends_with("issue-templates/bug.yml"));
+}
+
+#[test]
+fn test_pr_list_tsv() {
+    let temp_dir = setup_test_env();
+
+    gh_templates(temp_dir.path())
+        .args(["pr", "list", "--format", "tsv"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "name\tcategory\turl\tdescription\n",
+        ))
+        .stdout(predicate::str::is_match(r"\ndefault\.md\tpr\thttps://\S+/default\.md\t").unwrap())
+        .stdout(predicate::str::contains("Available").not());
+}
+
+#[test]
+fn test_code_of_conduct_list_yaml() {
+    let temp_dir = setup_test_env();
+
+    gh_templates(temp_dir.path())
+        .args(["code-of-conduct", "list", "--format", "yaml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("- name: default\n"))
+        .stdout(predicate::str::contains("  category: code-of-conduct\n"));
+}
+
+#[test]
+fn test_gitignore_list_json() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path();
+
+    let registry = temp_path.join("registry");
+    write_file(
+        &registry.join("manifest.yml"),
+        "type: gitignore\ntemplates:\n  - Rust.gitignore\n",
+    );
+    write_file(&registry.join("Rust.gitignore"), "/target\n");
+    let config_path = write_config(temp_path, "gitignore", &registry);
+
+    let templates = stdout_json(
+        gh_templates(temp_path)
+            .env("GH_TEMPLATES_CONFIG", &config_path)
+            .args(["gitignore", "list", "--source", "acme", "--format", "json"]),
+    );
+
+    assert_eq!(
+        templates,
+        serde_json::json!([{
+            "name": "rust",
+            "path": "Rust.gitignore",
+            "category": "popular",
+            "url": format!("file://{}/Rust.gitignore", registry.display()),
+        }])
+    );
+}
+
+#[test]
+fn test_license_list_json() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path();
+
+    let registry = temp_path.join("registry");
+    write_file(
+        &registry.join("json/licenses.json"),
+        r#"{"licenses": [
+            {"licenseId": "ACME-1.0", "name": "Acme License", "reference": "https://example.com/acme",
+             "isOsiApproved": true, "isFsfLibre": false, "isDeprecatedLicenseId": false},
+            {"licenseId": "OLD-1.0", "name": "Old License", "isOsiApproved": false,
+             "isDeprecatedLicenseId": true}
+        ]}"#,
+    );
+    let config_path = write_config(temp_path, "license", &registry);
+
+    let licenses = stdout_json(
+        gh_templates(temp_path)
+            .env("GH_TEMPLATES_CONFIG", &config_path)
+            .args([
+                "license",
+                "list",
+                "--source",
+                "acme",
+                "--include-deprecated",
+                "--format",
+                "json",
+            ]),
+    );
+
+    assert_eq!(
+        licenses,
+        serde_json::json!([
+            {
+                "id": "ACME-1.0",
+                "name": "Acme License",
+                "category": "spdx",
+                "url": "https://example.com/acme",
+                "osi_approved": true,
+                "fsf_libre": false,
+                "deprecated": false,
+            },
+            {
+                "id": "OLD-1.0",
+                "name": "Old License",
+                "category": "spdx",
+                "url": null,
+                "osi_approved": false,
+                "fsf_libre": null,
+                "deprecated": true,
+            }
+        ])
+    );
+}
+
+#[test]
+fn test_list_invalid_format() {
+    let temp_dir = setup_test_env();
+
+    gh_templates(temp_dir.path())
+        .args(["issue", "list", "--format", "xml"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'xml'"));
+}
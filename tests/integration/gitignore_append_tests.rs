@@ -0,0 +1,165 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/**
+Integration tests for section-aware `gitignore add --append` and `gitignore remove`.
+
+This test suite covers the following scenarios:
+
+- `test_gitignore_append_twice`: Verifies that appending the same template twice leaves one section.
+- `test_gitignore_append_force_replaces`: Verifies that `--append --force` rewrites an existing section in place.
+- `test_gitignore_append_keeps_existing_text`: Ensures that hand-written rules are kept and not repeated.
+- `test_gitignore_remove`: Verifies that `gitignore remove` strips a managed section and keeps the rest.
+- `test_gitignore_remove_missing`: Ensures that removing a template without a section fails.
+
+Each test writes a local registry and points `GH_TEMPLATES_CONFIG` at a config file naming it.
+*/
+// Import utility functions
+use crate::common::test_utils::{
+    create_git_repo, gh_templates_with_config, setup_test_env, write_config, write_registry,
+};
+
+/// Write a registry with `Rust.gitignore` and `Node.gitignore` and a config naming it `acme`
+fn write_source(root: &Path, rust: &str) -> PathBuf {
+    let registry = root.join("registry");
+    write_registry(
+        &registry,
+        "gitignore",
+        &[
+            ("Rust.gitignore", rust),
+            ("Node.gitignore", "node_modules/\n"),
+        ],
+    );
+    write_config(root, "gitignore", &registry)
+}
+
+#[test]
+fn test_gitignore_append_twice() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    let config_path = write_source(&temp_path, "/target\n");
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["gitignore", "add", "rust", "--append", "--source", "acme"])
+        .assert()
+        .success();
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["gitignore", "add", "rust", "--append", "--source", "acme"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("rust.gitignore is already in"));
+
+    let content = fs::read_to_string(temp_path.join(".gitignore")).unwrap();
+    assert_eq!(content.matches("# ===== rust.gitignore =====").count(), 1);
+    assert_eq!(content.matches("/target").count(), 1);
+}
+
+#[test]
+fn test_gitignore_append_force_replaces() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    let config_path = write_source(&temp_path, "/target\n");
+
+    fs::write(
+        temp_path.join(".gitignore"),
+        "# ===== rust.gitignore =====\n/old\n\n# ===== node.gitignore =====\nnode_modules/\n",
+    )
+    .unwrap();
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args([
+            "gitignore",
+            "add",
+            "rust",
+            "--append",
+            "--force",
+            "--source",
+            "acme",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Replaced rust.gitignore"));
+
+    assert_eq!(
+        fs::read_to_string(temp_path.join(".gitignore")).unwrap(),
+        "# ===== rust.gitignore =====\n/target\n\n# ===== node.gitignore =====\nnode_modules/\n"
+    );
+}
+
+#[test]
+fn test_gitignore_append_keeps_existing_text() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    let config_path = write_source(&temp_path, "/target\n*.rs.bk\n");
+
+    fs::write(temp_path.join(".gitignore"), ".env\n/target\n").unwrap();
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["gitignore", "add", "rust", "--append", "--source", "acme"])
+        .assert()
+        .success();
+
+    assert_eq!(
+        fs::read_to_string(temp_path.join(".gitignore")).unwrap(),
+        ".env\n/target\n\n# ===== rust.gitignore =====\n*.rs.bk\n\n"
+    );
+}
+
+#[test]
+fn test_gitignore_remove() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    let config_path = write_source(&temp_path, "/target\n");
+
+    fs::write(temp_path.join(".gitignore"), ".env\n").unwrap();
+    gh_templates_with_config(&temp_path, &config_path)
+        .args([
+            "gitignore",
+            "add",
+            "rust",
+            "node",
+            "--append",
+            "--source",
+            "acme",
+        ])
+        .assert()
+        .success();
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["gitignore", "remove", "Rust.gitignore"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed Rust.gitignore"));
+
+    assert_eq!(
+        fs::read_to_string(temp_path.join(".gitignore")).unwrap(),
+        ".env\n\n# ===== node.gitignore =====\nnode_modules/\n"
+    );
+}
+
+#[test]
+fn test_gitignore_remove_missing() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    let config_path = write_source(&temp_path, "/target\n");
+
+    fs::write(temp_path.join(".gitignore"), ".env\n").unwrap();
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["gitignore", "remove", "rust"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("section for rust"));
+
+    assert_eq!(
+        fs::read_to_string(temp_path.join(".gitignore")).unwrap(),
+        ".env\n"
+    );
+}
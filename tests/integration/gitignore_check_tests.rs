@@ -0,0 +1,122 @@
+use predicates::prelude::*;
+use std::fs;
+
+/**
+Integration tests for the `gh-templates gitignore check` subcommand.
+
+This test suite covers the following scenarios:
+
+- `test_gitignore_check_reports_rule_and_section`: Verifies that the matching line, its number and its template are printed.
+- `test_gitignore_check_negation`: Verifies that a path re-included by a `!` rule is reported as not ignored.
+- `test_gitignore_check_parent_directory`: Ensures that files inside an ignored directory stay ignored despite a later negation.
+- `test_gitignore_check_double_star_and_dir_only`: Verifies `**` patterns and directory-only rules.
+- `test_gitignore_check_outside_dir`: Ensures that paths outside the repository are rejected.
+
+Each test writes a `.gitignore` with `# ===== name =====` sections, as `gitignore add` does.
+*/
+// Import utility functions
+use crate::common::test_utils::{create_git_repo, gh_templates, setup_test_env};
+
+const GITIGNORE: &str = "\
+# ===== rust.gitignore =====
+/target
+**/*.rs.bk
+
+# ===== node.gitignore =====
+node_modules/
+*.log
+!keep.log
+docs/**/*.pdf
+";
+
+fn setup() -> tempfile::TempDir {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    fs::write(temp_path.join(".gitignore"), GITIGNORE).unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_gitignore_check_reports_rule_and_section() {
+    let temp_dir = setup();
+
+    gh_templates(temp_dir.path())
+        .args(["gitignore", "check", "target", "src/main.rs"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "target: ignored by .gitignore:2 `/target` (from rust.gitignore)",
+        ))
+        .stdout(predicate::str::contains("src/main.rs: not ignored"));
+}
+
+#[test]
+fn test_gitignore_check_negation() {
+    let temp_dir = setup();
+
+    gh_templates(temp_dir.path())
+        .args(["gitignore", "check", "server.log", "logs/keep.log"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "server.log: ignored by .gitignore:7 `*.log` (from node.gitignore)",
+        ))
+        .stdout(predicate::str::contains(
+            "logs/keep.log: not ignored, re-included by .gitignore:8 `!keep.log`",
+        ));
+}
+
+#[test]
+fn test_gitignore_check_parent_directory() {
+    let temp_dir = setup();
+
+    gh_templates(temp_dir.path())
+        .args(["gitignore", "check", "web/node_modules/keep.log"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "ignored by .gitignore:6 `node_modules/` via its directory web/node_modules",
+        ));
+}
+
+#[test]
+fn test_gitignore_check_double_star_and_dir_only() {
+    let temp_dir = setup();
+    let temp_path = temp_dir.path();
+    fs::create_dir_all(temp_path.join("src/node_modules")).unwrap();
+
+    gh_templates(temp_path)
+        .args([
+            "gitignore",
+            "check",
+            "src/lib.rs.bk",
+            "docs/a/b/guide.pdf",
+            "src/node_modules",
+            "node_modules",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "src/lib.rs.bk: ignored by .gitignore:3",
+        ))
+        .stdout(predicate::str::contains(
+            "docs/a/b/guide.pdf: ignored by .gitignore:9",
+        ))
+        .stdout(predicate::str::contains(
+            "src/node_modules: ignored by .gitignore:6",
+        ))
+        // Not a directory on disk, so the directory-only rule does not apply
+        .stdout(predicate::str::contains("node_modules: not ignored"));
+}
+
+#[test]
+fn test_gitignore_check_outside_dir() {
+    let temp_dir = setup();
+
+    gh_templates(temp_dir.path())
+        .args(["gitignore", "check", "../elsewhere.log"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is outside of"));
+}
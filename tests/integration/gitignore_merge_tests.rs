@@ -0,0 +1,138 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/**
+Integration tests for merging several gitignore templates into one file.
+
+This test suite covers the following scenarios:
+
+- `test_gitignore_merge_drops_duplicates`: Verifies that rules repeated across templates are written once.
+- `test_gitignore_merge_keeps_negated_repeats`: Ensures that a rule re-added after a negation is kept.
+- `test_gitignore_merge_drops_orphaned_comments`: Verifies that comments above dropped rules are removed with them.
+- `test_gitignore_merge_keeps_banners`: Verifies that every template keeps its `# ===== name =====` banner.
+
+Each test writes a local registry and points `GH_TEMPLATES_CONFIG` at a config file naming it.
+*/
+// Import utility functions
+use crate::common::test_utils::{
+    create_git_repo, gh_templates_with_config, setup_test_env, write_config, write_registry,
+};
+
+/// Write a registry with the given `(file name, content)` templates and a config naming it `acme`
+fn write_source(root: &Path, templates: &[(&str, &str)]) -> PathBuf {
+    let registry = root.join("registry");
+    write_registry(&registry, "gitignore", templates);
+    write_config(root, "gitignore", &registry)
+}
+
+#[test]
+fn test_gitignore_merge_drops_duplicates() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let config_path = write_source(
+        &temp_path,
+        &[
+            ("Python.gitignore", "__pycache__/\n.DS_Store\n.idea/\n"),
+            ("MacOS.gitignore", "**/.DS_Store\n.AppleDouble\n"),
+            ("JetBrains.gitignore", ".idea\n.idea/\n*.iml\n"),
+        ],
+    );
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args([
+            "gitignore",
+            "add",
+            "python",
+            "macos",
+            "jetbrains",
+            "--source",
+            "acme",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Skipped 2 rule(s)"));
+
+    let content = fs::read_to_string(temp_path.join(".gitignore")).unwrap();
+    assert_eq!(content.matches(".DS_Store").count(), 1);
+    assert_eq!(content.matches(".idea/").count(), 1);
+    assert!(content.contains("\n.idea\n"));
+    assert!(content.contains("*.iml"));
+}
+
+#[test]
+fn test_gitignore_merge_keeps_negated_repeats() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let config_path = write_source(
+        &temp_path,
+        &[
+            ("Base.gitignore", "*.log\n!keep.log\n"),
+            ("Logs.gitignore", "*.log\n"),
+        ],
+    );
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["gitignore", "add", "base", "logs", "--source", "acme"])
+        .assert()
+        .success();
+
+    let content = fs::read_to_string(temp_path.join(".gitignore")).unwrap();
+    assert_eq!(content.matches("*.log").count(), 2);
+}
+
+#[test]
+fn test_gitignore_merge_drops_orphaned_comments() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let config_path = write_source(
+        &temp_path,
+        &[
+            ("Node.gitignore", "# Logs\nlogs\n*.log\n"),
+            (
+                "Yarn.gitignore",
+                "# Log files\n*.log\n\n# Yarn\n.yarn/cache\n",
+            ),
+        ],
+    );
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["gitignore", "add", "node", "yarn", "--source", "acme"])
+        .assert()
+        .success();
+
+    let content = fs::read_to_string(temp_path.join(".gitignore")).unwrap();
+    assert!(!content.contains("# Log files"));
+    assert!(content.contains("# Yarn\n.yarn/cache"));
+}
+
+#[test]
+fn test_gitignore_merge_keeps_banners() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let config_path = write_source(
+        &temp_path,
+        &[
+            ("Rust.gitignore", "/target\n"),
+            ("Cargo.gitignore", "/target\n"),
+        ],
+    );
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["gitignore", "add", "rust", "cargo", "--source", "acme"])
+        .assert()
+        .success();
+
+    assert_eq!(
+        fs::read_to_string(temp_path.join(".gitignore")).unwrap(),
+        "# ===== rust.gitignore =====\n/target\n\n# ===== cargo.gitignore =====\n\n"
+    );
+}
@@ -0,0 +1,226 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+
+/**
+Integration tests for the `gh-templates issue config` subcommand.
+
+This test suite covers the following scenarios:
+
+- `test_issue_config_creates_file`: Verifies that blank issues and contact links are written to `.github/ISSUE_TEMPLATE/config.yml`.
+- `test_issue_config_preserves_entries`: Ensures that existing links and unknown keys are kept and a link with the same name is updated in place.
+- `test_issue_config_keeps_formatting`: Ensures that only the changed keys of an existing config.yml are rewritten, keeping comments and quoting elsewhere.
+- `test_issue_config_remove_link`: Verifies that `--remove-link` drops a link and fails for a missing one.
+- `test_issue_config_invalid_link`: Ensures that malformed contact links are rejected.
+- `test_issue_add_all_offers_config`: Verifies that `issue add --all` points at `issue config` when there is no config.yml.
+*/
+// Import utility functions
+use crate::common::test_utils::{create_git_repo, gh_templates, setup_test_env};
+
+fn read_config(temp_path: &Path) -> serde_yaml::Value {
+    let content = fs::read_to_string(temp_path.join(".github/ISSUE_TEMPLATE/config.yml")).unwrap();
+    serde_yaml::from_str(&content).unwrap()
+}
+
+#[test]
+fn test_issue_config_creates_file() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args([
+            "issue",
+            "config",
+            "--blank-issues",
+            "false",
+            "--contact-link",
+            "Discussions|https://github.com/org/repo/discussions|Ask questions here",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("config.yml - has been added."));
+
+    let config = read_config(&temp_path);
+    assert_eq!(config["blank_issues_enabled"], false);
+    assert_eq!(config["contact_links"][0]["name"], "Discussions");
+    assert_eq!(
+        config["contact_links"][0]["url"],
+        "https://github.com/org/repo/discussions"
+    );
+    assert_eq!(config["contact_links"][0]["about"], "Ask questions here");
+}
+
+#[test]
+fn test_issue_config_preserves_entries() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let dir = temp_path.join(".github/ISSUE_TEMPLATE");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("config.yml"),
+        "blank_issues_enabled: false
+contact_links:
+  - name: Security
+    url: https://example.com/security
+    about: Report vulnerabilities privately
+  - name: Chat
+    url: https://example.com/chat
+    about: Old chat
+custom_key: kept
+",
+    )
+    .unwrap();
+
+    gh_templates(&temp_path)
+        .args([
+            "issue",
+            "config",
+            "--contact-link",
+            "chat|https://example.com/new-chat|Talk to us",
+            "--contact-link",
+            "Docs|https://example.com/docs|Read the docs",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Updated"));
+
+    let config = read_config(&temp_path);
+    assert_eq!(config["blank_issues_enabled"], false);
+    assert_eq!(config["custom_key"], "kept");
+
+    let links = config["contact_links"].as_sequence().unwrap();
+    assert_eq!(links.len(), 3);
+    assert_eq!(links[0]["name"], "Security");
+    assert_eq!(links[1]["name"], "Chat");
+    assert_eq!(links[1]["url"], "https://example.com/new-chat");
+    assert_eq!(links[1]["about"], "Talk to us");
+    assert_eq!(links[2]["name"], "Docs");
+}
+
+#[test]
+fn test_issue_config_keeps_formatting() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let dir = temp_path.join(".github/ISSUE_TEMPLATE");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("config.yml"),
+        "# Managed by the platform team
+blank_issues_enabled: true # until the forms settle
+contact_links:
+  # Private reports only
+  - name: \"Security\"
+    url: https://example.com/security
+    about: 'Report vulnerabilities privately'
+",
+    )
+    .unwrap();
+
+    gh_templates(&temp_path)
+        .args(["issue", "config", "--blank-issues", "false"])
+        .assert()
+        .success();
+
+    let content = fs::read_to_string(dir.join("config.yml")).unwrap();
+    assert_eq!(
+        content,
+        "# Managed by the platform team
+blank_issues_enabled: false
+contact_links:
+  # Private reports only
+  - name: \"Security\"
+    url: https://example.com/security
+    about: 'Report vulnerabilities privately'
+"
+    );
+}
+
+#[test]
+fn test_issue_config_remove_link() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args([
+            "issue",
+            "config",
+            "--contact-link",
+            "Chat|https://example.com/chat|Talk to us",
+            "--contact-link",
+            "Docs|https://example.com/docs|Read the docs",
+        ])
+        .assert()
+        .success();
+
+    gh_templates(&temp_path)
+        .args(["issue", "config", "--remove-link", "Chat"])
+        .assert()
+        .success();
+
+    let config = read_config(&temp_path);
+    let links = config["contact_links"].as_sequence().unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0]["name"], "Docs");
+
+    gh_templates(&temp_path)
+        .args(["issue", "config", "--remove-link", "Chat"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No contact link named 'Chat'"));
+}
+
+#[test]
+fn test_issue_config_invalid_link() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args([
+            "issue",
+            "config",
+            "--contact-link",
+            "Chat|https://example.com",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Expected NAME|URL|ABOUT"));
+
+    gh_templates(&temp_path)
+        .args(["issue", "config", "--contact-link", "Chat|example.com|Talk"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must start with http"));
+
+    assert!(!temp_path.join(".github/ISSUE_TEMPLATE/config.yml").exists());
+}
+
+#[test]
+fn test_issue_add_all_offers_config() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["issue", "add", "--all"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("gh-templates issue config"));
+
+    gh_templates(&temp_path)
+        .args(["issue", "config", "--blank-issues", "true"])
+        .assert()
+        .success();
+
+    gh_templates(&temp_path)
+        .args(["issue", "add", "--all", "--force"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("issue config").not());
+}
@@ -0,0 +1,206 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+
+/**
+Integration tests for the `gh-templates issue convert` subcommand.
+
+This test suite covers the following scenarios:
+
+- `test_issue_convert_markdown_to_form`: Verifies that front matter, headed sections, HTML comments and task lists become a valid issue form.
+- `test_issue_convert_form_to_markdown`: Verifies that an installed issue form is written as a Markdown template with front matter and `###` sections.
+- `test_issue_convert_round_trip`: Ensures that converting a form to Markdown and back gives the same form.
+- `test_issue_convert_existing_output`: Ensures that an existing output file is only replaced with `--force`.
+- `test_issue_convert_unsupported_file`: Ensures that files other than `.md` and `.yml` are rejected.
+*/
+// Import utility functions
+use crate::common::test_utils::{create_git_repo, gh_templates, setup_test_env};
+
+const LEGACY_TEMPLATE: &str = "---
+name: Bug report
+about: Create a report to help us improve
+title: \"[BUG] \"
+labels: bug, triage
+assignees: ''
+---
+
+Thanks for taking the time to report a bug!
+
+**Describe the bug**
+A clear and concise description of what the bug is.
+
+## To Reproduce
+<!-- Steps to reproduce the behavior -->
+1. Go to '...'
+2. See error
+
+### Checklist
+- [ ] I searched existing issues
+- [x] I read the docs
+";
+
+fn write_template(temp_path: &Path, name: &str, content: &str) {
+    let dir = temp_path.join(".github/ISSUE_TEMPLATE");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(name), content).unwrap();
+}
+
+#[test]
+fn test_issue_convert_markdown_to_form() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    write_template(&temp_path, "bug_report.md", LEGACY_TEMPLATE);
+
+    gh_templates(&temp_path)
+        .args(["issue", "convert", ".github/ISSUE_TEMPLATE/bug_report.md"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("bug_report.yml - has been added."));
+
+    let content =
+        fs::read_to_string(temp_path.join(".github/ISSUE_TEMPLATE/bug_report.yml")).unwrap();
+    let form: serde_yaml::Value = serde_yaml::from_str(&content).unwrap();
+
+    assert_eq!(form["name"], "Bug report");
+    assert_eq!(form["description"], "Create a report to help us improve");
+    assert_eq!(form["title"], "[BUG] ");
+    assert_eq!(form["labels"][1], "triage");
+    assert!(form.get("assignees").is_none());
+
+    let body = &form["body"];
+    assert_eq!(body[0]["type"], "markdown");
+    assert_eq!(
+        body[0]["attributes"]["value"],
+        "Thanks for taking the time to report a bug!"
+    );
+    assert_eq!(body[1]["type"], "textarea");
+    assert_eq!(body[1]["id"], "describe-the-bug");
+    assert_eq!(body[1]["attributes"]["label"], "Describe the bug");
+    assert_eq!(
+        body[1]["attributes"]["placeholder"],
+        "A clear and concise description of what the bug is."
+    );
+    assert_eq!(
+        body[2]["attributes"]["description"],
+        "Steps to reproduce the behavior"
+    );
+    assert_eq!(
+        body[2]["attributes"]["placeholder"],
+        "1. Go to '...'\n2. See error"
+    );
+    assert_eq!(body[3]["type"], "checkboxes");
+    assert_eq!(
+        body[3]["attributes"]["options"][1]["label"],
+        "I read the docs"
+    );
+
+    gh_templates(&temp_path)
+        .args(["issue", "validate"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_issue_convert_form_to_markdown() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["issue", "add", "bug"])
+        .assert()
+        .success();
+
+    gh_templates(&temp_path)
+        .args(["issue", "convert", ".github/ISSUE_TEMPLATE/bug.yml"])
+        .assert()
+        .success();
+
+    let content = fs::read_to_string(temp_path.join(".github/ISSUE_TEMPLATE/bug.md")).unwrap();
+    assert!(content.starts_with("---\nname: 🐛 Bug Report\n"));
+    assert!(content.contains("about: Report unexpected behavior"));
+    assert!(content.contains("labels: bug\n"));
+    assert!(content.contains("### Bug Category\n"));
+    assert!(content.contains("<!-- Please select the most appropriate category"));
+    assert!(content.contains("- [ ] Regression (previously working, now broken)\n"));
+    assert!(content.contains("### Steps to Reproduce\n"));
+    assert!(content.contains("1. Go to '...'\n"));
+}
+
+#[test]
+fn test_issue_convert_round_trip() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    write_template(&temp_path, "bug_report.md", LEGACY_TEMPLATE);
+
+    gh_templates(&temp_path)
+        .args(["issue", "convert", ".github/ISSUE_TEMPLATE/bug_report.md"])
+        .assert()
+        .success();
+    gh_templates(&temp_path)
+        .args([
+            "issue",
+            "convert",
+            ".github/ISSUE_TEMPLATE/bug_report.yml",
+            "-o",
+            "back.md",
+        ])
+        .assert()
+        .success();
+    gh_templates(&temp_path)
+        .args(["issue", "convert", "back.md", "-o", "again.yml"])
+        .assert()
+        .success();
+
+    let first =
+        fs::read_to_string(temp_path.join(".github/ISSUE_TEMPLATE/bug_report.yml")).unwrap();
+    let second = fs::read_to_string(temp_path.join("again.yml")).unwrap();
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_issue_convert_existing_output() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    write_template(&temp_path, "bug_report.md", LEGACY_TEMPLATE);
+    write_template(&temp_path, "bug_report.yml", "existing");
+
+    gh_templates(&temp_path)
+        .args(["issue", "convert", ".github/ISSUE_TEMPLATE/bug_report.md"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+
+    gh_templates(&temp_path)
+        .args([
+            "issue",
+            "convert",
+            ".github/ISSUE_TEMPLATE/bug_report.md",
+            "--force",
+        ])
+        .assert()
+        .success();
+
+    let content =
+        fs::read_to_string(temp_path.join(".github/ISSUE_TEMPLATE/bug_report.yml")).unwrap();
+    assert!(content.starts_with("name: Bug report\n"));
+}
+
+#[test]
+fn test_issue_convert_unsupported_file() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    fs::write(temp_path.join("notes.txt"), "hello").unwrap();
+
+    gh_templates(&temp_path)
+        .args(["issue", "convert", "notes.txt"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "expected a .md template or a .yml issue form",
+        ));
+}
@@ -18,17 +18,23 @@ This test suite covers the following scenarios:
 - `test_issue_add_default_with_output_without_ext`: Tests adding a default issue template with an output name that does not have an extension.
 - `test_issue_add_default_with_output_with_ext`: Tests adding a default issue template with an output name that has an extension.
 - `test_issue_add_uneven_templates_and_outputs`: Ensures that an error is raised when the number of templates does not match the number of output file names
+- `test_issue_add_custom_metadata`: Verifies that `--label`, `--assignee`, `--title-prefix` and `--project` replace the template's metadata and keep it a valid issue form.
+- `test_issue_add_metadata_keeps_formatting`: Ensures that customising the metadata only rewrites those keys, keeping comments, quoting and key order elsewhere.
+- `test_issue_add_invalid_project`: Ensures that a project not in OWNER/NUMBER form is rejected before anything is written.
 - `test_issue_list`: Ensures the list command displays available issue templates.
 - `test_issue_preview_bug`: Validates that the preview command displays the content of the "bug" issue template.
 - `test_issue_preview_multiple`: Validates that the preview command displays the content of multiple issue templates.
 - `test_issue_preview_invalid_id`: Ensures that an invalid issue template name results in an error.
+- `test_issue_preview_render`: Validates that `--render` draws the form's title prefix, labels, fields, options and required markers instead of YAML.
+- `test_issue_preview_render_all_fields`: Validates that `--render` shows inputs, prefilled values, checkboxes and dropdown defaults of a form from a local source.
 - `test_issue_help_command`: Validates that the help command displays usage information for the issue subcommands.
 
 Each test uses a temporary directory to avoid side effects and leverages `assert_cmd` and `predicates` for command-line assertions.
 */
 // Import utility functions
 use crate::common::test_utils::{
-    assert_file_contains, assert_file_exists, create_git_repo, setup_test_env,
+    assert_file_contains, assert_file_exists, create_git_repo, gh_templates_with_config,
+    setup_test_env, write_config, write_registry,
 };
 
 // --------     ADD COMMAND TESTS     --------
@@ -258,6 +264,142 @@ fn test_issue_add_uneven_templates_and_outputs() {
         ));
 }
 
+#[test]
+fn test_issue_add_custom_metadata() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args([
+        "issue",
+        "add",
+        "bug",
+        "--label",
+        "type:bug",
+        "--label",
+        "needs-triage",
+        "--assignee",
+        "@triage",
+        "--title-prefix",
+        "BUG: ",
+        "--project",
+        "org/1",
+    ])
+    .assert()
+    .success();
+
+    let path = temp_path.join(".github/ISSUE_TEMPLATE/bug.yml");
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(content.starts_with("# Bug Report Template\n"));
+
+    let form: serde_yaml::Value = serde_yaml::from_str(&content).unwrap();
+    assert_eq!(form["title"], "BUG: ");
+    assert_eq!(form["labels"][0], "type:bug");
+    assert_eq!(form["labels"][1], "needs-triage");
+    assert_eq!(form["labels"].as_sequence().unwrap().len(), 2);
+    assert_eq!(form["assignees"][0], "triage");
+    assert_eq!(form["projects"][0], "org/1");
+    assert_eq!(form["body"][0]["id"], "bug-category");
+
+    // Metadata comes before the form body, as in GitHub's documentation
+    assert!(content.find("assignees:").unwrap() < content.find("body:").unwrap());
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path)
+        .args(["issue", "validate"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_issue_add_metadata_keeps_formatting() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+    let registry = temp_path.join("registry");
+    write_registry(
+        &registry,
+        "issue",
+        &[(
+            "incident.yml",
+            r#"# Incident report
+name: "Incident"
+description: 'Report a production incident'
+title: "[Incident]: "
+labels:
+  - incident # paged on-call
+  - triage
+
+# Keep the fields short, on-call fills this in
+body:
+  - type: input
+    id: service
+    attributes:
+      label: "Service"   # as named in the catalogue
+"#,
+        )],
+    );
+    let config_path = write_config(&temp_path, "issue", &registry);
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args([
+            "issue",
+            "add",
+            "incident",
+            "--source",
+            "acme",
+            "--label",
+            "sev",
+            "--assignee",
+            "@oncall",
+        ])
+        .assert()
+        .success();
+
+    let content =
+        fs::read_to_string(temp_path.join(".github/ISSUE_TEMPLATE/incident.yml")).unwrap();
+    assert_eq!(
+        content,
+        r#"# Incident report
+name: "Incident"
+description: 'Report a production incident'
+title: "[Incident]: "
+labels:
+  - sev
+
+assignees:
+  - oncall
+# Keep the fields short, on-call fills this in
+body:
+  - type: input
+    id: service
+    attributes:
+      label: "Service"   # as named in the catalogue
+"#
+    );
+}
+
+#[test]
+fn test_issue_add_invalid_project() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path);
+    cmd.args(["issue", "add", "bug", "--project", "org"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Expected OWNER/NUMBER"));
+
+    assert!(!temp_path.join(".github/ISSUE_TEMPLATE/bug.yml").exists());
+}
+
 // --------     LIST COMMAND TEST     --------
 
 #[test]
@@ -306,6 +448,97 @@ fn test_issue_preview_invalid_id() {
         );
 }
 
+#[test]
+fn test_issue_preview_render() {
+    let _temp_dir = setup_test_env();
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.args(["issue", "preview", "--render", "bug"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("🐛 Bug Report"))
+        .stdout(predicate::str::contains("│ [Bug]:"))
+        .stdout(predicate::str::contains("Labels     bug"))
+        .stdout(predicate::str::contains("Bug Category *"))
+        .stdout(predicate::str::contains(
+            "○ Regression (previously working, now broken)",
+        ))
+        .stdout(predicate::str::contains("│ 1. Go to '...'"))
+        .stdout(predicate::str::contains("validations:").not())
+        .stdout(predicate::str::contains("type: dropdown").not());
+}
+
+#[test]
+fn test_issue_preview_render_all_fields() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let registry = temp_path.join("registry");
+    fs::create_dir_all(&registry).unwrap();
+    fs::write(
+        registry.join("incident.yml"),
+        r#"name: Incident
+description: Report a production incident
+labels: incident, triage
+body:
+  - type: markdown
+    attributes:
+      value: Thanks for reporting!
+  - type: input
+    id: service
+    attributes:
+      label: Service
+      value: api-gateway
+  - type: dropdown
+    id: severity
+    attributes:
+      label: Severity
+      multiple: true
+      options:
+        - Low
+        - High
+      default: 1
+  - type: checkboxes
+    id: terms
+    attributes:
+      label: Checks
+      options:
+        - label: I searched existing incidents
+          required: true
+        - label: I can reproduce it
+"#,
+    )
+    .unwrap();
+    let config_path = temp_path.join("config.toml");
+    fs::write(
+        &config_path,
+        format!(
+            "[sources.issue]\nacme = \"file://{}\"\n",
+            registry.display()
+        ),
+    )
+    .unwrap();
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path)
+        .env("HOME", &temp_path)
+        .env("GH_TEMPLATES_CONFIG", &config_path)
+        .args([
+            "issue", "preview", "--render", "--source", "acme", "incident",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Labels     incident, triage"))
+        .stdout(predicate::str::contains("Thanks for reporting!"))
+        .stdout(predicate::str::contains("│ api-gateway"))
+        .stdout(predicate::str::contains("select one or more"))
+        .stdout(predicate::str::contains("○ Low"))
+        .stdout(predicate::str::contains("● High"))
+        .stdout(predicate::str::contains(
+            "[ ] I searched existing incidents *",
+        ))
+        .stdout(predicate::str::contains("[ ] I can reproduce it\n"));
+}
+
 // --------     HELP COMMAND TEST     --------
 
 #[test]
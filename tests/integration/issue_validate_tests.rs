@@ -0,0 +1,133 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+
+/**
+Integration tests for the `gh-templates issue validate` subcommand.
+
+This test suite covers the following scenarios:
+
+- `test_issue_validate_installed_templates`: Verifies that templates added with `issue add` validate from the default directory.
+- `test_issue_validate_unknown_type`: Ensures that an unknown body item type is reported on its line.
+- `test_issue_validate_unknown_field`: Ensures that a misspelled attribute is reported on its own line.
+- `test_issue_validate_duplicate_ids`: Ensures that duplicate ids and labels are reported and the exit code is non-zero.
+- `test_issue_validate_skips_config`: Verifies that `config.yml` is not treated as an issue form.
+*/
+// Import utility functions
+use crate::common::test_utils::{create_git_repo, gh_templates, setup_test_env};
+
+fn write_form(temp_path: &Path, name: &str, content: &str) {
+    let dir = temp_path.join(".github/ISSUE_TEMPLATE");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(name), content).unwrap();
+}
+
+#[test]
+fn test_issue_validate_installed_templates() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["issue", "add", "bug", "feature"])
+        .assert()
+        .success();
+
+    gh_templates(&temp_path)
+        .args(["issue", "validate"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("bug.yml"))
+        .stdout(predicate::str::contains("feature.yml"));
+}
+
+#[test]
+fn test_issue_validate_unknown_type() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    write_form(
+        &temp_path,
+        "bug.yml",
+        "name: Bug\ndescription: Report a bug\nbody:\n  - type: input\n    attributes:\n      label: Version\n  - type: textbox\n    attributes:\n      label: Details\n",
+    );
+
+    gh_templates(&temp_path)
+        .args(["issue", "validate"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "bug.yml:7: body[1]: unknown variant `textbox`",
+        ));
+}
+
+#[test]
+fn test_issue_validate_unknown_field() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    write_form(
+        &temp_path,
+        "bug.yml",
+        "name: Bug\ndescription: Report a bug\nbody:\n  - type: textarea\n    attributes:\n      label: Details\n      placholder: Tell us more\n",
+    );
+
+    gh_templates(&temp_path)
+        .args(["issue", "validate", ".github/ISSUE_TEMPLATE/bug.yml"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "bug.yml:7: body[0]: unknown field `placholder`",
+        ));
+}
+
+#[test]
+fn test_issue_validate_duplicate_ids() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    write_form(
+        &temp_path,
+        "bug.yml",
+        "name: Bug\ndescription: Report a bug\nbody:\n  - type: input\n    id: version\n    attributes:\n      label: Version\n  - type: dropdown\n    id: version\n    attributes:\n      label: version\n      options: [a, b]\n      default: 2\n",
+    );
+
+    gh_templates(&temp_path)
+        .args(["issue", "validate"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "bug.yml:9: body[1] (dropdown): duplicate id `version`",
+        ))
+        .stderr(predicate::str::contains(
+            "bug.yml:11: body[1] (dropdown): duplicate label `version`",
+        ))
+        .stderr(predicate::str::contains(
+            "bug.yml:13: body[1] (dropdown): `default` is 2",
+        ))
+        .stderr(predicate::str::contains("1 of 1 issue form(s) are invalid"));
+}
+
+#[test]
+fn test_issue_validate_skips_config() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    write_form(&temp_path, "config.yml", "blank_issues_enabled: false\n");
+    write_form(
+        &temp_path,
+        "ask.yml",
+        "name: Ask\ndescription: Ask a question\nbody:\n  - type: textarea\n    attributes:\n      label: Question\n",
+    );
+
+    gh_templates(&temp_path)
+        .args(["issue", "validate"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ask.yml"))
+        .stdout(predicate::str::contains("config.yml").not());
+}
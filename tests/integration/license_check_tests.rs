@@ -0,0 +1,339 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+
+/**
+Integration tests for the `license check` command.
+
+This test suite covers the following scenarios:
+
+- `test_license_check_cargo_incompatible`: Verifies that a GPL crate from `Cargo.lock` and `cargo metadata` is flagged in an MIT project and fails the check.
+- `test_license_check_cargo_json_report`: Verifies the JSON report, including `OR` expressions and crates missing from the metadata.
+- `test_license_check_cargo_without_metadata`: Ensures that `Cargo.lock` alone asks for `--cargo-metadata`.
+- `test_license_check_npm_lockfile`: Verifies that `package-lock.json` licenses are checked and dev dependencies are skipped unless `--include-dev` is set.
+- `test_license_check_python_environment`: Verifies that `pyproject.toml` dependencies are resolved from the metadata of the installed distributions.
+- `test_license_check_python_venv_dir`: Verifies that the installed distributions are also found in `venv/`.
+- `test_license_check_license_override`: Verifies `--license`, the GPL-2.0 / Apache-2.0 rule, `--strict` and invalid expressions.
+*/
+// Import utility functions
+use crate::common::test_utils::{create_git_repo, gh_templates, setup_test_env};
+
+/// A Rust project licensed `license` with the given (name, version, license) crates
+fn write_cargo_project(temp_path: &Path, license: &str, crates: &[(&str, &str, Option<&str>)]) {
+    fs::write(
+        temp_path.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\nlicense = \"{}\"\n",
+            license
+        ),
+    )
+    .unwrap();
+
+    let mut lock =
+        String::from("version = 4\n\n[[package]]\nname = \"app\"\nversion = \"0.1.0\"\n");
+    let mut packages = vec![serde_json::json!({
+        "name": "app", "version": "0.1.0", "license": license, "source": null
+    })];
+    for (name, version, crate_license) in crates {
+        lock.push_str(&format!(
+            "\n[[package]]\nname = \"{}\"\nversion = \"{}\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\n",
+            name, version
+        ));
+        packages.push(serde_json::json!({
+            "name": name,
+            "version": version,
+            "license": crate_license,
+            "source": "registry+https://github.com/rust-lang/crates.io-index"
+        }));
+    }
+    fs::write(temp_path.join("Cargo.lock"), lock).unwrap();
+    fs::write(
+        temp_path.join("metadata.json"),
+        serde_json::json!({ "packages": packages }).to_string(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_license_check_cargo_incompatible() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    write_cargo_project(
+        &temp_path,
+        "MIT",
+        &[
+            ("serde", "1.0.0", Some("MIT OR Apache-2.0")),
+            ("readline", "2.0.0", Some("GPL-3.0-only")),
+        ],
+    );
+
+    gh_templates(&temp_path)
+        .args(["license", "check", "--cargo-metadata", "metadata.json"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "License: MIT (declared in Cargo.toml)",
+        ))
+        .stdout(predicate::str::contains(
+            "readline 2.0.0 (cargo) - GPL-3.0-only: GPL-3.0-only is strong copyleft",
+        ))
+        .stdout(predicate::str::contains(
+            "Checked 2 dependencies: 1 compatible, 0 to review, 1 incompatible.",
+        ))
+        .stderr(predicate::str::contains(
+            "1 dependency license(s) are incompatible with MIT.",
+        ));
+}
+
+#[test]
+fn test_license_check_cargo_json_report() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    write_cargo_project(
+        &temp_path,
+        "Apache-2.0",
+        &[
+            ("either", "1.0.0", Some("MIT/Apache-2.0")),
+            ("gpl-or-mit", "0.3.0", Some("GPL-2.0-only OR MIT")),
+            ("colours", "3.0.0", Some("MPL-2.0")),
+        ],
+    );
+    // A crate in Cargo.lock that the metadata file does not know about
+    let mut lock = fs::read_to_string(temp_path.join("Cargo.lock")).unwrap();
+    lock.push_str("\n[[package]]\nname = \"stale\"\nversion = \"0.1.0\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\n");
+    fs::write(temp_path.join("Cargo.lock"), lock).unwrap();
+
+    let output = gh_templates(&temp_path)
+        .args([
+            "license",
+            "check",
+            "--cargo-metadata",
+            "metadata.json",
+            "--format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["license"], "Apache-2.0");
+    assert_eq!(report["declared_in"], "Cargo.toml");
+    assert_eq!(report["ok"], 2);
+    assert_eq!(report["review"], 2);
+    assert_eq!(report["incompatible"], 0);
+
+    let status = |name: &str| {
+        report["dependencies"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|d| d["name"] == name)
+            .map(|d| (d["status"].clone(), d["reason"].clone()))
+            .unwrap()
+    };
+    assert_eq!(status("either").0, "ok");
+    assert_eq!(status("gpl-or-mit").0, "ok");
+    assert_eq!(status("colours").0, "review");
+    assert_eq!(status("stale").0, "review");
+    assert_eq!(status("stale").1, "no license information");
+}
+
+#[test]
+fn test_license_check_cargo_without_metadata() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    write_cargo_project(&temp_path, "MIT", &[("serde", "1.0.0", Some("MIT"))]);
+
+    gh_templates(&temp_path)
+        .args(["license", "check"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pass --cargo-metadata FILE"))
+        .stderr(predicate::str::contains("No dependency licenses found."));
+}
+
+#[test]
+fn test_license_check_npm_lockfile() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    fs::write(
+        temp_path.join("package.json"),
+        r#"{ "name": "web", "license": "MIT" }"#,
+    )
+    .unwrap();
+    fs::write(
+        temp_path.join("package-lock.json"),
+        r#"{
+  "name": "web",
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "web", "license": "MIT" },
+    "node_modules/react": { "version": "18.2.0", "license": "MIT" },
+    "node_modules/@scope/lib": { "version": "1.0.0", "license": "ISC" },
+    "node_modules/gpl-lint": { "version": "2.0.0", "license": "GPL-3.0", "dev": true }
+  }
+}"#,
+    )
+    .unwrap();
+
+    gh_templates(&temp_path)
+        .args(["license", "check"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Checked 2 dependencies: 2 compatible, 0 to review, 0 incompatible.",
+        ));
+
+    gh_templates(&temp_path)
+        .args(["license", "check", "--include-dev", "--format", "tsv"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "npm\t@scope/lib\t1.0.0\tISC\tok\t",
+        ))
+        .stdout(predicate::str::contains(
+            "npm\tgpl-lint\t2.0.0\tGPL-3.0\tincompatible\t",
+        ));
+}
+
+#[test]
+fn test_license_check_python_environment() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    fs::write(
+        temp_path.join("pyproject.toml"),
+        "[project]\nname = \"tool\"\nlicense = \"GPL-3.0-or-later\"\ndependencies = [\"requests>=2.31\", \"Flask_Login\", \"mystery\"]\n",
+    )
+    .unwrap();
+
+    let site_packages = temp_path.join(".venv/lib/python3.12/site-packages");
+    let dist = |name: &str, metadata: &str| {
+        let dir = site_packages.join(format!("{}.dist-info", name));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("METADATA"), metadata).unwrap();
+    };
+    dist(
+        "requests-2.32.3",
+        "Metadata-Version: 2.4\nName: requests\nVersion: 2.32.3\nLicense-Expression: Apache-2.0\n\nLong description\n",
+    );
+    dist(
+        "flask_login-0.6.3",
+        "Metadata-Version: 2.1\nName: Flask-Login\nVersion: 0.6.3\nClassifier: License :: OSI Approved :: MIT License\n",
+    );
+
+    let output = gh_templates(&temp_path)
+        .args(["license", "check", "--format", "yaml"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let report: serde_yaml::Value = serde_yaml::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["declared_in"].as_str(), Some("pyproject.toml"));
+    let dependencies = report["dependencies"].as_sequence().unwrap();
+    assert_eq!(dependencies.len(), 3);
+    assert_eq!(dependencies[0]["license"].as_str(), Some("Apache-2.0"));
+    assert_eq!(dependencies[0]["version"].as_str(), Some("2.32.3"));
+    assert_eq!(dependencies[1]["license"].as_str(), Some("MIT"));
+    assert_eq!(dependencies[2]["status"].as_str(), Some("review"));
+}
+
+#[test]
+fn test_license_check_python_venv_dir() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    fs::write(
+        temp_path.join("pyproject.toml"),
+        "[project]\nname = \"tool\"\nlicense = \"MIT\"\ndependencies = [\"requests\"]\n",
+    )
+    .unwrap();
+
+    // Only `venv/`, without a `.venv/`
+    let dist = temp_path.join("venv/lib/python3.12/site-packages/requests-2.32.3.dist-info");
+    fs::create_dir_all(&dist).unwrap();
+    fs::write(
+        dist.join("METADATA"),
+        "Metadata-Version: 2.4\nName: requests\nVersion: 2.32.3\nLicense-Expression: Apache-2.0\n",
+    )
+    .unwrap();
+
+    let output = gh_templates(&temp_path)
+        .args(["license", "check", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["dependencies"][0]["name"], "requests");
+    assert_eq!(report["dependencies"][0]["license"], "Apache-2.0");
+    assert_eq!(report["ok"], 1);
+}
+
+#[test]
+fn test_license_check_license_override() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    write_cargo_project(
+        &temp_path,
+        "MIT",
+        &[
+            ("ring", "0.17.0", Some("Apache-2.0")),
+            ("colours", "3.0.0", Some("MPL-2.0")),
+        ],
+    );
+
+    gh_templates(&temp_path)
+        .args(["license", "check", "--cargo-metadata", "metadata.json"])
+        .assert()
+        .success();
+
+    gh_templates(&temp_path)
+        .args([
+            "license",
+            "check",
+            "--cargo-metadata",
+            "metadata.json",
+            "--strict",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "1 dependency license(s) need a review (--strict).",
+        ));
+
+    gh_templates(&temp_path)
+        .args([
+            "license",
+            "check",
+            "--cargo-metadata",
+            "metadata.json",
+            "--license",
+            "GPL-2.0-only",
+        ])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "License: GPL-2.0-only (given with --license)",
+        ))
+        .stdout(predicate::str::contains(
+            "Apache-2.0 is not compatible with GPL-2.0-only",
+        ));
+
+    gh_templates(&temp_path)
+        .args(["license", "check", "--license", "MIT AND"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "'MIT AND' is not a valid SPDX license expression.",
+        ));
+}
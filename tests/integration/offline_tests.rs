@@ -0,0 +1,174 @@
+use assert_cmd::Command as AssertCommand;
+use predicates::prelude::*;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/**
+Integration tests for the global `--offline` flag.
+
+This test suite covers the following scenarios:
+
+- `test_offline_issue_add_embedded`: Verifies that `issue add` is served from the embedded templates.
+- `test_offline_gitignore_add_from_cache`: Verifies that `gitignore add` uses the cached index and body.
+- `test_offline_license_add_from_cache`: Verifies that `license add` uses the cached SPDX index and details.
+- `test_offline_not_cached`: Ensures that a template that was never fetched fails with a "not cached" error.
+- `test_offline_license_not_cached`: Ensures that `license add` without a cached SPDX index fails instead of reporting an empty index.
+
+Each test points `HOME` at a temporary directory holding a pre-populated cache, so no network access is needed.
+*/
+// Import utility functions
+use crate::common::test_utils::{
+    assert_file_contains, assert_file_exists, create_git_repo, setup_test_env,
+};
+
+fn cache_dir(home: &Path) -> PathBuf {
+    home.join(".local").join("share").join("gh-templates")
+}
+
+/// Mirrors `CacheManager::get_content_path`
+fn cache_content(home: &Path, url: &str, content: &str) {
+    let file_name = format!("{:x}", Sha256::digest(url.as_bytes()));
+
+    let content_dir = cache_dir(home).join("content");
+    fs::create_dir_all(&content_dir).unwrap();
+    fs::write(content_dir.join(file_name), content).unwrap();
+}
+
+fn cache_index(home: &Path, cache_name: &str, entries: serde_json::Value) {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let entries: serde_json::Map<String, serde_json::Value> = entries
+        .as_object()
+        .unwrap()
+        .iter()
+        .map(|(k, v)| {
+            let entry = serde_json::json!({ "data": v, "timestamp": now, "metadata": {} });
+            (k.clone(), entry)
+        })
+        .collect();
+    let cache = serde_json::json!({
+        "metadata": { "last_updated": now, "total_entries": entries.len() },
+        "entries": entries,
+    });
+
+    fs::create_dir_all(cache_dir(home)).unwrap();
+    fs::write(
+        cache_dir(home).join(format!("{}.json", cache_name)),
+        cache.to_string(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_offline_issue_add_embedded() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    // First-party templates are built into the binary, so no cache is needed
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path).env("HOME", &temp_path);
+    cmd.args(["--offline", "issue", "add", "bug"])
+        .assert()
+        .success();
+
+    assert_file_contains(
+        &temp_path.join(".github/ISSUE_TEMPLATE/bug.yml"),
+        "Bug Report",
+    );
+}
+
+#[test]
+fn test_offline_gitignore_add_from_cache() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    cache_index(
+        &temp_path,
+        "gitignore_templates",
+        serde_json::json!({ "rust": "Rust.gitignore" }),
+    );
+    cache_content(
+        &temp_path,
+        "https://raw.githubusercontent.com/github/gitignore/main/Rust.gitignore",
+        "/target\n",
+    );
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path).env("HOME", &temp_path);
+    cmd.args(["--offline", "gitignore", "add", "rust"])
+        .assert()
+        .success();
+
+    assert_file_contains(&temp_path.join(".gitignore"), "/target");
+}
+
+#[test]
+fn test_offline_license_add_from_cache() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    cache_index(
+        &temp_path,
+        "spdx_license_cache",
+        serde_json::json!({ "MIT": { "licenseId": "MIT", "name": "MIT License" } }),
+    );
+    cache_content(
+        &temp_path,
+        "https://raw.githubusercontent.com/spdx/license-list-data/main/json/details/MIT.json",
+        r#"{"licenseId": "MIT", "licenseText": "MIT License\n\nCopyright (c) <year> <copyright holders>\n"}"#,
+    );
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path).env("HOME", &temp_path);
+    cmd.args([
+        "--offline",
+        "license",
+        "add",
+        "mit",
+        "--param",
+        "year=2025",
+        "--param",
+        "copyright-holders=Jane Doe",
+    ])
+    .assert()
+    .success();
+
+    assert_file_exists(&temp_path.join("LICENSE"));
+    assert_file_contains(&temp_path.join("LICENSE"), "Copyright (c) 2025 Jane Doe");
+}
+
+#[test]
+fn test_offline_not_cached() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path).env("HOME", &temp_path);
+    cmd.args(["--offline", "gitignore", "add", "rust"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not cached"));
+}
+
+#[test]
+fn test_offline_license_not_cached() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let mut cmd = AssertCommand::cargo_bin("gh-templates").unwrap();
+    cmd.current_dir(&temp_path).env("HOME", &temp_path);
+    cmd.args(["--offline", "license", "add", "mit"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not cached"));
+
+    assert!(!temp_path.join("LICENSE").exists());
+}
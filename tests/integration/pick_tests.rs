@@ -0,0 +1,70 @@
+use predicates::prelude::*;
+
+/**
+Integration tests for the interactive `pick` command.
+
+This test suite covers the following scenarios:
+
+- `test_pick_requires_terminal`: Ensures that `pick` refuses to run when stdin/stdout are not terminals.
+- `test_pick_source_requires_category`: Ensures that `--source` is rejected without a category.
+- `test_pick_invalid_category`: Ensures that an unknown category is rejected.
+- `test_add_without_arguments_outside_terminal`: Verifies that `add` without arguments keeps its error in scripts.
+
+The picker itself needs a terminal, so these tests cover the non-interactive paths only.
+*/
+// Import utility functions
+use crate::common::test_utils::{create_git_repo, gh_templates, setup_test_env};
+
+#[test]
+fn test_pick_requires_terminal() {
+    let temp_dir = setup_test_env();
+
+    gh_templates(temp_dir.path())
+        .args(["pick", "issue"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("needs an interactive terminal"));
+}
+
+#[test]
+fn test_pick_source_requires_category() {
+    let temp_dir = setup_test_env();
+
+    gh_templates(temp_dir.path())
+        .args(["pick", "--source", "acme"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Pass a category too"));
+}
+
+#[test]
+fn test_pick_invalid_category() {
+    let temp_dir = setup_test_env();
+
+    gh_templates(temp_dir.path())
+        .args(["pick", "widgets"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'widgets'"));
+}
+
+#[test]
+fn test_add_without_arguments_outside_terminal() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["issue", "add"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No issue template specified"));
+
+    gh_templates(&temp_path)
+        .args(["pr", "add"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No pull request template specified",
+        ));
+}
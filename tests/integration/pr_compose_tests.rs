@@ -0,0 +1,193 @@
+use predicates::prelude::*;
+use std::fs;
+
+/**
+Integration tests for composing pull request templates from fragments.
+
+This test suite covers the following scenarios:
+
+- `test_pr_compose_list`: Verifies that `--list` shows the fragments of the manifest with their descriptions, and that `pr list` does not show them as templates.
+- `test_pr_compose_sections`: Verifies that the chosen fragments are assembled in order into the default template, without their description comments.
+- `test_pr_compose_output`: Verifies that `--output` writes into `.github/PULL_REQUEST_TEMPLATE/` and that an existing template needs `--force`.
+- `test_pr_compose_unknown_fragment`: Ensures that unknown fragment names are rejected with the available ones.
+- `test_pr_compose_local_source`: Verifies that fragments are read from the manifest of a local source.
+*/
+// Import utility functions
+use crate::common::test_utils::{create_git_repo, gh_templates, setup_test_env};
+
+const DEFAULT_TEMPLATE: &str = ".github/pull_request_template.md";
+
+#[test]
+fn test_pr_compose_list() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    gh_templates(&temp_path)
+        .args(["pr", "compose", "--list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("summary"))
+        .stdout(predicate::str::contains("How the change was tested"))
+        .stdout(predicate::str::contains("screenshots"));
+
+    gh_templates(&temp_path)
+        .args(["pr", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("default"))
+        .stdout(predicate::str::contains("fragments").not())
+        .stdout(predicate::str::contains("summary").not());
+}
+
+#[test]
+fn test_pr_compose_sections() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args([
+            "pr",
+            "compose",
+            "--sections",
+            "summary,testing,security,screenshots",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            ".github/pull_request_template.md - has been added.",
+        ));
+
+    let content = fs::read_to_string(temp_path.join(DEFAULT_TEMPLATE)).unwrap();
+    assert!(content.starts_with("## Summary\n"));
+    assert!(!content.contains("What the change does and why"));
+
+    let headings: Vec<&str> = content.lines().filter(|l| l.starts_with("## ")).collect();
+    assert_eq!(
+        headings,
+        [
+            "## Summary",
+            "## Testing",
+            "## Security Considerations",
+            "## Screenshots"
+        ]
+    );
+
+    // The composed template passes the lint
+    gh_templates(&temp_path)
+        .args(["pr", "lint"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_pr_compose_output() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args([
+            "pr",
+            "compose",
+            "--sections",
+            "summary,checklist",
+            "-o",
+            "small",
+        ])
+        .assert()
+        .success();
+
+    let small = temp_path.join(".github/PULL_REQUEST_TEMPLATE/small.md");
+    let content = fs::read_to_string(&small).unwrap();
+    assert!(content.contains("## Checklist"));
+    assert!(!temp_path.join(DEFAULT_TEMPLATE).exists());
+
+    gh_templates(&temp_path)
+        .args(["pr", "compose", "--sections", "summary", "-o", "small"])
+        .assert()
+        .failure();
+
+    gh_templates(&temp_path)
+        .args([
+            "pr",
+            "compose",
+            "--sections",
+            "summary",
+            "-o",
+            "small",
+            "--force",
+        ])
+        .assert()
+        .success();
+    let content = fs::read_to_string(&small).unwrap();
+    assert!(!content.contains("## Checklist"));
+}
+
+#[test]
+fn test_pr_compose_unknown_fragment() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["pr", "compose", "--sections", "summary,changelog"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown fragment 'changelog'."))
+        .stderr(predicate::str::contains("Available: summary,"));
+
+    assert!(!temp_path.join(DEFAULT_TEMPLATE).exists());
+}
+
+#[test]
+fn test_pr_compose_local_source() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let registry = temp_path.join("registry");
+    fs::create_dir_all(registry.join("parts")).unwrap();
+    fs::write(
+        registry.join("manifest.yml"),
+        "type: pull_request\ntemplates:\n  - custom.md\n\nfragments:\n  rollout: parts/rollout.md\n  owners: parts/owners.md\n",
+    )
+    .unwrap();
+    fs::write(registry.join("custom.md"), "<!-- Custom -->\n").unwrap();
+    fs::write(
+        registry.join("parts/rollout.md"),
+        "<!-- Rollout plan -->\n## Rollout\n\n- [ ] Feature flag added\n",
+    )
+    .unwrap();
+    fs::write(
+        registry.join("parts/owners.md"),
+        "## Owners\n\n/cc @acme/core\n",
+    )
+    .unwrap();
+
+    let config_path = temp_path.join("config.toml");
+    fs::write(
+        &config_path,
+        format!("[sources.pr]\nacme = \"file://{}\"\n", registry.display()),
+    )
+    .unwrap();
+
+    gh_templates(&temp_path)
+        .env("GH_TEMPLATES_CONFIG", &config_path)
+        .args([
+            "pr",
+            "compose",
+            "--source",
+            "acme",
+            "--sections",
+            "owners,rollout",
+        ])
+        .assert()
+        .success();
+
+    let content = fs::read_to_string(temp_path.join(DEFAULT_TEMPLATE)).unwrap();
+    assert_eq!(
+        content,
+        "## Owners\n\n/cc @acme/core\n\n## Rollout\n\n- [ ] Feature flag added\n"
+    );
+}
@@ -0,0 +1,229 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/**
+Integration tests for the pull request template chooser and `pr link`.
+
+This test suite covers the following scenarios:
+
+- `test_pr_add_single_template_no_chooser`: Verifies that no default template is generated for a single template.
+- `test_pr_add_generates_chooser`: Verifies that adding several templates generates a default template linking to each of them.
+- `test_pr_add_default_keeps_chooser`: Verifies that adding the default template replaces a generated chooser and keeps the links on top.
+- `test_pr_add_chooser_links_remote`: Verifies that the chooser links to the compare page of the `origin` remote.
+- `test_pr_add_chooser_keeps_lockfile`: Verifies that putting the chooser on top of the default template does not show it as locally modified.
+- `test_pr_add_dry_run_shows_chooser`: Verifies that `--dry-run` prints the chooser it would write.
+- `test_pr_link`: Verifies that `pr link` prints the compare URL built from the git remote and branch.
+- `test_pr_link_unknown_template`: Ensures that `pr link` rejects templates that are not installed.
+*/
+// Import utility functions
+use crate::common::test_utils::{create_git_repo, gh_templates, setup_test_env};
+
+const DEFAULT_TEMPLATE: &str = ".github/pull_request_template.md";
+
+fn git(temp_path: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(temp_path)
+        .status()
+        .expect("Failed to run git");
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+#[test]
+fn test_pr_add_single_template_no_chooser() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["pr", "add", "bugfix"])
+        .assert()
+        .success();
+
+    assert!(!temp_path.join(DEFAULT_TEMPLATE).exists());
+}
+
+#[test]
+fn test_pr_add_generates_chooser() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["pr", "add", "bugfix", "feature"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            ".github/pull_request_template.md - has been added.",
+        ));
+
+    let chooser = fs::read_to_string(temp_path.join(DEFAULT_TEMPLATE)).unwrap();
+    assert!(chooser.contains("[bugfix](?expand=1&template=bugfix.md)"));
+    assert!(chooser.contains("[feature](?expand=1&template=feature.md)"));
+
+    // A third template is added to the existing list
+    gh_templates(&temp_path)
+        .args(["pr", "add", "security"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Updated .github/pull_request_template.md",
+        ));
+
+    let chooser = fs::read_to_string(temp_path.join(DEFAULT_TEMPLATE)).unwrap();
+    assert!(chooser.contains("[security](?expand=1&template=security.md)"));
+    assert_eq!(chooser.matches("gh-templates:chooser:start").count(), 1);
+}
+
+#[test]
+fn test_pr_add_default_keeps_chooser() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["pr", "add", "bugfix", "feature"])
+        .assert()
+        .success();
+
+    // The generated chooser does not need --force to be replaced
+    gh_templates(&temp_path)
+        .args(["pr", "add", "default"])
+        .assert()
+        .success();
+
+    let content = fs::read_to_string(temp_path.join(DEFAULT_TEMPLATE)).unwrap();
+    assert!(content.starts_with("<!-- gh-templates:chooser:start -->"));
+    assert!(content.contains("[bugfix](?expand=1&template=bugfix.md)"));
+    assert!(content.contains("Thanks for contributing"));
+}
+
+#[test]
+fn test_pr_add_chooser_links_remote() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    git(
+        &temp_path,
+        &[
+            "remote",
+            "add",
+            "origin",
+            "https://github.com/owner/repo.git",
+        ],
+    );
+
+    gh_templates(&temp_path)
+        .args(["pr", "add", "bugfix", "feature"])
+        .assert()
+        .success();
+
+    let chooser = fs::read_to_string(temp_path.join(DEFAULT_TEMPLATE)).unwrap();
+    assert!(chooser.contains(
+        "[bugfix](https://github.com/owner/repo/compare?quick_pull=1&template=bugfix.md)"
+    ));
+    assert!(chooser.contains(
+        "[feature](https://github.com/owner/repo/compare?quick_pull=1&template=feature.md)"
+    ));
+}
+
+#[test]
+fn test_pr_add_chooser_keeps_lockfile() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["pr", "add", "default", "feature"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Updated .github/pull_request_template.md",
+        ));
+
+    gh_templates(&temp_path)
+        .args(["status"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"pull_request_template\.md\s+unchanged").unwrap());
+}
+
+#[test]
+fn test_pr_add_dry_run_shows_chooser() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["--dry-run", "pr", "add", "bugfix", "feature"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Would create .github/pull_request_template.md",
+        ))
+        .stdout(predicate::str::contains("template=feature.md"));
+
+    assert!(!temp_path.join(DEFAULT_TEMPLATE).exists());
+    assert!(!temp_path.join(".github/PULL_REQUEST_TEMPLATE").exists());
+}
+
+#[test]
+fn test_pr_link() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+    git(
+        &temp_path,
+        &["remote", "add", "origin", "git@github.com:owner/repo.git"],
+    );
+    git(&temp_path, &["checkout", "-q", "-b", "fix-login"]);
+
+    gh_templates(&temp_path)
+        .args(["pr", "add", "bugfix"])
+        .assert()
+        .success();
+
+    gh_templates(&temp_path)
+        .args(["pr", "link", "bugfix"])
+        .assert()
+        .success()
+        .stdout(
+            "https://github.com/owner/repo/compare/fix-login?quick_pull=1&template=bugfix.md\n",
+        );
+
+    gh_templates(&temp_path)
+        .args(["pr", "link", "bugfix.md", "--base", "develop", "--head", "other"])
+        .assert()
+        .success()
+        .stdout(
+            "https://github.com/owner/repo/compare/develop...other?quick_pull=1&template=bugfix.md\n",
+        );
+
+    gh_templates(&temp_path)
+        .args(["pr", "link", "bugfix", "--remote", "upstream"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No git remote named 'upstream'."));
+}
+
+#[test]
+fn test_pr_link_unknown_template() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["pr", "add", "bugfix"])
+        .assert()
+        .success();
+
+    gh_templates(&temp_path)
+        .args(["pr", "link", "feature"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "'feature.md' is not in .github/PULL_REQUEST_TEMPLATE/. Installed: bugfix.md",
+        ));
+}
@@ -0,0 +1,142 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+
+/**
+Integration tests for the `pr lint` command.
+
+This test suite covers the following scenarios:
+
+- `test_pr_lint_shipped_templates`: Verifies that every bundled pull request template passes the lint.
+- `test_pr_lint_installed_templates`: Verifies that templates added with `pr add` are found and pass without arguments.
+- `test_pr_lint_reports_problems`: Ensures that empty sections, duplicate headings, unbalanced comments and broken links are reported with line numbers and a failing exit code.
+- `test_pr_lint_max_checklist`: Verifies that checklists longer than `--max-checklist` are reported.
+- `test_pr_lint_no_templates`: Ensures that linting a repository without pull request templates fails.
+*/
+// Import utility functions
+use crate::common::test_utils::{create_git_repo, gh_templates, setup_test_env};
+
+#[test]
+fn test_pr_lint_shipped_templates() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    let templates = Path::new(env!("CARGO_MANIFEST_DIR")).join("templates/pr-templates");
+
+    gh_templates(&temp_path)
+        .args(["pr", "lint"])
+        .arg(&templates)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("feature.md"));
+}
+
+#[test]
+fn test_pr_lint_installed_templates() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["pr", "add", "default", "feature"])
+        .assert()
+        .success();
+
+    gh_templates(&temp_path)
+        .args(["pr", "lint"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(".github/pull_request_template.md"))
+        .stdout(predicate::str::contains(
+            ".github/PULL_REQUEST_TEMPLATE/feature.md",
+        ));
+}
+
+#[test]
+fn test_pr_lint_reports_problems() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let template = "<!-- Welcome!\n\
+                    # Pull Request\n\
+                    \n\
+                    ## Summary\n\
+                    \n\
+                    ## Testing\n\
+                    See [the guide](docs/testing.md).\n\
+                    \n\
+                    ## Summary\n\
+                    Text\n";
+    fs::create_dir_all(temp_path.join(".github")).unwrap();
+    fs::write(temp_path.join(".github/pull_request_template.md"), template).unwrap();
+
+    gh_templates(&temp_path)
+        .args(["pr", "lint"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "pull_request_template.md:1: HTML comment is never closed",
+        ));
+
+    // With the comment closed the Markdown problems show up
+    let template = template.replace("Welcome!", "Welcome! -->");
+    fs::write(temp_path.join(".github/pull_request_template.md"), template).unwrap();
+
+    gh_templates(&temp_path)
+        .args(["pr", "lint"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "pull_request_template.md:4: Section 'Summary' is empty.",
+        ))
+        .stderr(predicate::str::contains(
+            "pull_request_template.md:7: Relative link 'docs/testing.md' points to a missing file.",
+        ))
+        .stderr(predicate::str::contains(
+            "pull_request_template.md:9: Duplicate heading 'Summary' (first on line 4).",
+        ))
+        .stderr(predicate::str::contains(
+            "1 of 1 pull request template(s) have problems.",
+        ));
+}
+
+#[test]
+fn test_pr_lint_max_checklist() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let items: String = (1..=5).map(|n| format!("- [ ] Item {}\n", n)).collect();
+    fs::write(
+        temp_path.join("template.md"),
+        format!("## Checklist\n{}", items),
+    )
+    .unwrap();
+
+    gh_templates(&temp_path)
+        .args(["pr", "lint", "template.md"])
+        .assert()
+        .success();
+
+    gh_templates(&temp_path)
+        .args(["pr", "lint", "template.md", "--max-checklist", "4"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "template.md:2: Checklist has 5 items (at most 4)",
+        ));
+}
+
+#[test]
+fn test_pr_lint_no_templates() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["pr", "lint"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No pull request templates (*.md) found to lint.",
+        ));
+}
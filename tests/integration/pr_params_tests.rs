@@ -0,0 +1,178 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/**
+Integration tests for variables and conditional sections in pull request templates.
+
+This test suite covers the following scenarios:
+
+- `test_pr_add_without_params`: Verifies that a template is installed without any template syntax left when no values are given.
+- `test_pr_add_with_params`: Verifies that `--param` fills variables and keeps conditional sections.
+- `test_pr_add_project_config_params`: Verifies that `[pr] params` in `.gh-templates.toml` are used and `--param` overrides them.
+- `test_pr_add_defaults_and_else`: Verifies variable defaults, `{{else}}` branches and nested blocks from a local source.
+- `test_pr_add_unclosed_block`: Ensures that a template with an unclosed `{{#if}}` is rejected.
+*/
+// Import utility functions
+use crate::common::test_utils::{
+    create_git_repo, gh_templates, setup_test_env, write_config, write_registry,
+};
+
+fn read_detailed(temp_path: &Path) -> String {
+    fs::read_to_string(temp_path.join(".github/PULL_REQUEST_TEMPLATE/detailed.md")).unwrap()
+}
+
+/// Write a one-template PR registry and a config naming it `acme`; returns the config path
+fn write_source(temp_path: &Path, template: &str) -> PathBuf {
+    let registry = temp_path.join("registry");
+    write_registry(&registry, "pull_request", &[("custom.md", template)]);
+    write_config(temp_path, "pr", &registry)
+}
+
+#[test]
+fn test_pr_add_without_params() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args(["pr", "add", "detailed"])
+        .assert()
+        .success();
+
+    let content = read_detailed(&temp_path);
+    assert!(content.contains("# Pull Request\n"));
+    assert!(content.contains("Fixes #"));
+    assert!(!content.contains("{{"));
+    assert!(!content.contains("Security Checklist"));
+    assert!(!content.contains("Ticket:"));
+}
+
+#[test]
+fn test_pr_add_with_params() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    gh_templates(&temp_path)
+        .args([
+            "pr",
+            "add",
+            "detailed",
+            "--param",
+            "project_name=Acme",
+            "--param",
+            "ticket-url=https://jira.example.com/browse/ACME-",
+            "--param",
+            "security_checklist=yes",
+            "--param",
+            "reviewers=@acme/core",
+            "--param",
+            "unknown=1",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 unused parameter(s)"))
+        .stdout(predicate::str::contains("- unknown"));
+
+    let content = read_detailed(&temp_path);
+    assert!(content.contains("# Pull Request to Acme\n"));
+    assert!(content.contains("Ticket: https://jira.example.com/browse/ACME-\nFixes #"));
+    assert!(content.contains("## Security Checklist\n"));
+    assert!(content.contains("/cc @acme/core"));
+    assert!(!content.contains("{{"));
+}
+
+#[test]
+fn test_pr_add_project_config_params() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    fs::write(
+        temp_path.join(".gh-templates.toml"),
+        "[pr]\nparams = { project_name = \"Acme\", security_checklist = \"true\" }\n",
+    )
+    .unwrap();
+
+    gh_templates(&temp_path)
+        .args(["pr", "add", "detailed", "--param", "project_name=Widgets"])
+        .assert()
+        .success();
+
+    let content = read_detailed(&temp_path);
+    assert!(content.contains("# Pull Request to Widgets\n"));
+    assert!(content.contains("## Security Checklist\n"));
+
+    // `apply` skips a `[pr]` section that only holds params
+    gh_templates(&temp_path)
+        .args(["apply"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Nothing to apply"));
+}
+
+#[test]
+fn test_pr_add_defaults_and_else() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let config_path = write_source(
+        &temp_path,
+        "<!-- Custom -->\n\
+         Reviewers: {{ reviewers | @org/maintainers }}\n\
+         {{#if ticket}}\n\
+         Ticket: {{ ticket }}\n\
+         {{else}}\n\
+         No ticket.\n\
+         {{/if}}\n\
+         {{#if docs}}\n\
+         Docs{{#if api}} and API{{/if}} updated.\n\
+         {{/if}}\n\
+         Owner: {{ owner }}\n",
+    );
+
+    gh_templates(&temp_path)
+        .env("GH_TEMPLATES_CONFIG", &config_path)
+        .args([
+            "pr",
+            "add",
+            "custom",
+            "--source",
+            "acme",
+            "--param",
+            "docs=true",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 variable(s) remain unfilled"))
+        .stdout(predicate::str::contains("- [owner]"));
+
+    let content =
+        fs::read_to_string(temp_path.join(".github/PULL_REQUEST_TEMPLATE/custom.md")).unwrap();
+    assert_eq!(
+        content,
+        "<!-- Custom -->\nReviewers: @org/maintainers\nNo ticket.\nDocs updated.\nOwner: [owner]\n"
+    );
+}
+
+#[test]
+fn test_pr_add_unclosed_block() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let config_path = write_source(&temp_path, "<!-- Custom -->\n{{#if docs}}\nDocs\n");
+
+    gh_templates(&temp_path)
+        .env("GH_TEMPLATES_CONFIG", &config_path)
+        .args(["pr", "add", "custom", "--source", "acme"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("{{#if docs}} is not closed"));
+
+    assert!(!temp_path
+        .join(".github/PULL_REQUEST_TEMPLATE/custom.md")
+        .exists());
+}
@@ -0,0 +1,162 @@
+use predicates::prelude::*;
+use std::path::Path;
+
+/**
+Integration tests for configurable template sources (`--source NAME`).
+
+This test suite covers the following scenarios:
+
+- `test_source_issue_add`: Verifies that `issue add` fetches from a `file://` source.
+- `test_source_issue_list`: Verifies that `issue list` reads the source's `manifest.yml`.
+- `test_source_gitignore_add`: Verifies that `gitignore add` indexes nested manifests of a source.
+- `test_source_license_add`: Verifies that `license add` and `license preview` read an SPDX license-list-data mirror without `--update-cache`.
+- `test_source_unknown`: Ensures that an undefined source name fails with a helpful error.
+
+Each test writes a local registry and points `GH_TEMPLATES_CONFIG` at a config file naming it.
+*/
+// Import utility functions
+use crate::common::test_utils::{
+    assert_file_contains, create_git_repo, gh_templates_with_config, setup_test_env, write_config,
+    write_file,
+};
+
+fn write_issue_registry(registry: &Path) {
+    write_file(
+        &registry.join("manifest.yml"),
+        "type: issue\ntemplates:\n  - incident.yml\n",
+    );
+    write_file(
+        &registry.join("incident.yml"),
+        "# Report a production incident\nname: Incident\n",
+    );
+}
+
+#[test]
+fn test_source_issue_add() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let registry = temp_path.join("registry");
+    write_issue_registry(&registry);
+    let config_path = write_config(&temp_path, "issue", &registry);
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["issue", "add", "incident", "--source", "acme"])
+        .assert()
+        .success();
+
+    assert_file_contains(
+        &temp_path.join(".github/ISSUE_TEMPLATE/incident.yml"),
+        "name: Incident",
+    );
+}
+
+#[test]
+fn test_source_issue_list() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let registry = temp_path.join("registry");
+    write_issue_registry(&registry);
+    let config_path = write_config(&temp_path, "issue", &registry);
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["issue", "list", "--source", "acme"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("incident"))
+        .stdout(predicate::str::contains("Report a production incident"))
+        .stdout(predicate::str::contains("bug").not());
+}
+
+#[test]
+fn test_source_gitignore_add() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let registry = temp_path.join("registry");
+    write_file(
+        &registry.join("manifest.yml"),
+        "type: gitignore\ntemplates:\n  - Rust.gitignore\n  Global: Global/\n",
+    );
+    write_file(&registry.join("Rust.gitignore"), "/target\n");
+    write_file(
+        &registry.join("Global/manifest.yml"),
+        "type: gitignore\ntemplates:\n  - Windows.gitignore\n",
+    );
+    write_file(&registry.join("Global/Windows.gitignore"), "Thumbs.db\n");
+    let config_path = write_config(&temp_path, "gitignore", &registry);
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args([
+            "gitignore",
+            "add",
+            "rust",
+            "global/windows",
+            "--source",
+            "acme",
+        ])
+        .assert()
+        .success();
+
+    assert_file_contains(&temp_path.join(".gitignore"), "/target");
+    assert_file_contains(&temp_path.join(".gitignore"), "Thumbs.db");
+}
+
+#[test]
+fn test_source_license_add() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let registry = temp_path.join("registry");
+    write_file(
+        &registry.join("json/licenses.json"),
+        r#"{"licenses": [{"licenseId": "ACME-1.0", "name": "Acme Internal License"}]}"#,
+    );
+    write_file(
+        &registry.join("json/details/ACME-1.0.json"),
+        r#"{"licenseId": "ACME-1.0", "licenseText": "Acme Internal License\n"}"#,
+    );
+    let config_path = write_config(&temp_path, "license", &registry);
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["license", "add", "acme-1.0", "--source", "acme"])
+        .assert()
+        .success();
+
+    assert_file_contains(&temp_path.join("LICENSE"), "Acme Internal License");
+
+    // Metadata comes from the source too, not from choosealicense.com
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["license", "preview", "acme-1.0", "--source", "acme", "-D"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "SPDX License Name: Acme Internal License",
+        ))
+        .stdout(predicate::str::contains("Not available from SPDX."));
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["license", "list", "--popular", "--source", "acme"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with --source"));
+}
+
+#[test]
+fn test_source_unknown() {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let registry = temp_path.join("registry");
+    let config_path = write_config(&temp_path, "issue", &registry);
+
+    gh_templates_with_config(&temp_path, &config_path)
+        .args(["pr", "add", "default", "--source", "acme"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown pr source 'acme'"));
+}
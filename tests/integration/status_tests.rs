@@ -0,0 +1,357 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/**
+Integration tests for the lockfile and the `status` / `update` commands.
+
+This test suite covers the following scenarios:
+
+- `test_add_records_lockfile`: Verifies that `issue add` records the template, URL and hash in `.gh-templates.lock`.
+- `test_status_reports_drift`: Verifies that `status` reports unchanged, locally modified, upstream updated and missing files.
+- `test_status_after_custom_metadata`: Verifies that a file written with `--label` is not reported as locally modified.
+- `test_update_refreshes_unedited_files`: Verifies that `update` rewrites upstream-updated files and skips locally modified ones.
+- `test_update_merges_local_and_upstream_changes`: Verifies that `update` three-way merges non-overlapping edits.
+- `test_update_writes_conflict_markers`: Ensures that overlapping edits produce conflict markers and a failing exit code.
+- `test_update_no_merge_shows_diff`: Ensures that `--no-merge` leaves the file alone and prints a diff.
+- `test_update_without_base_shows_diff`: Ensures that a missing install-time version falls back to a diff.
+- `test_status_after_filling_in`: Verifies that a PR template filled in with `--param` is recorded with its upstream base and reported as unchanged.
+- `test_update_keeps_filled_in_values`: Verifies that `update` merges upstream changes into a filled-in template and keeps its values.
+- `test_update_untracked_path`: Ensures that `update` rejects a path that is not in the lockfile.
+- `test_status_without_lockfile`: Verifies that `status` explains when nothing is tracked.
+
+Templates come from a `file://` source so the tests can change "upstream" between commands.
+*/
+// Import utility functions
+use crate::common::test_utils::{
+    assert_file_contains, create_git_repo, gh_templates_with_config, setup_test_env, write_config,
+    write_registry,
+};
+
+/// Create a repository using a local issue template source with the given templates
+fn setup_repo(templates: &[(&str, &str)]) -> (tempfile::TempDir, PathBuf, PathBuf) {
+    let temp_dir = setup_test_env();
+    let temp_path = temp_dir.path().to_path_buf();
+    create_git_repo(&temp_path);
+
+    let files: Vec<(String, &str)> = templates
+        .iter()
+        .map(|(name, content)| (format!("{}.yml", name), *content))
+        .collect();
+    let registry = temp_path.join("registry");
+    write_registry(&registry, "issue", &files);
+    write_config(&temp_path, "issue", &registry);
+
+    (temp_dir, temp_path, registry)
+}
+
+/// Serve `custom.md` with `template` from a PR source `acme` as well
+fn add_pr_source(temp_path: &Path, template: &str) -> PathBuf {
+    let registry = temp_path.join("pr-registry");
+    write_registry(&registry, "pull_request", &[("custom.md", template)]);
+
+    let config_path = temp_path.join("config.toml");
+    let mut config = fs::read_to_string(&config_path).unwrap();
+    config.push_str(&format!(
+        "\n[sources.pr]\nacme = \"file://{}\"\n",
+        registry.display()
+    ));
+    fs::write(&config_path, config).unwrap();
+
+    registry
+}
+
+fn add_filled_in_template(temp_path: &Path) {
+    gh_templates_with_config(temp_path, &temp_path.join("config.toml"))
+        .args([
+            "pr",
+            "add",
+            "custom",
+            "--source",
+            "acme",
+            "--param",
+            "project_name=Acme",
+        ])
+        .assert()
+        .success();
+}
+
+fn add_issue_templates(temp_path: &Path, templates: &[&str]) {
+    gh_templates_with_config(temp_path, &temp_path.join("config.toml"))
+        .args(["issue", "add", "--source", "acme"])
+        .args(templates)
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_add_records_lockfile() {
+    let (_temp_dir, temp_path, registry) = setup_repo(&[("bug", "name: Bug\n")]);
+    add_issue_templates(&temp_path, &["bug"]);
+
+    let lockfile = temp_path.join(".gh-templates.lock");
+    assert_file_contains(&lockfile, "[files.\".github/ISSUE_TEMPLATE/bug.yml\"]");
+    assert_file_contains(&lockfile, "category = \"issue\"");
+    assert_file_contains(&lockfile, "template = \"bug\"");
+    assert_file_contains(
+        &lockfile,
+        &format!("url = \"file://{}/bug.yml\"", registry.display()),
+    );
+}
+
+#[test]
+fn test_status_reports_drift() {
+    let (_temp_dir, temp_path, registry) = setup_repo(&[
+        ("bug", "name: Bug\n"),
+        ("docs", "name: Docs\n"),
+        ("feature", "name: Feature\n"),
+        ("support", "name: Support\n"),
+    ]);
+    add_issue_templates(&temp_path, &["bug", "docs", "feature", "support"]);
+
+    let issue_dir = temp_path.join(".github/ISSUE_TEMPLATE");
+    fs::write(issue_dir.join("docs.yml"), "name: Our Docs\n").unwrap();
+    fs::write(registry.join("feature.yml"), "name: Feature Request\n").unwrap();
+    fs::remove_file(issue_dir.join("support.yml")).unwrap();
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"bug\.yml\s+unchanged").unwrap())
+        .stdout(predicate::str::is_match(r"docs\.yml\s+locally modified").unwrap())
+        .stdout(predicate::str::is_match(r"feature\.yml\s+upstream updated").unwrap())
+        .stdout(predicate::str::is_match(r"support\.yml\s+missing").unwrap());
+}
+
+#[test]
+fn test_status_after_custom_metadata() {
+    let (_temp_dir, temp_path, _registry) = setup_repo(&[("bug", BASE)]);
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .args(["issue", "add", "bug", "--source", "acme"])
+        .args(["--label", "type:bug"])
+        .assert()
+        .success();
+    assert_file_contains(
+        &temp_path.join(".github/ISSUE_TEMPLATE/bug.yml"),
+        "type:bug",
+    );
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"bug\.yml\s+unchanged").unwrap());
+}
+
+#[test]
+fn test_update_refreshes_unedited_files() {
+    let (_temp_dir, temp_path, registry) =
+        setup_repo(&[("bug", "name: Bug\n"), ("feature", "name: Feature\n")]);
+    add_issue_templates(&temp_path, &["bug", "feature"]);
+
+    let issue_dir = temp_path.join(".github/ISSUE_TEMPLATE");
+    fs::write(issue_dir.join("bug.yml"), "name: Our Bug\n").unwrap();
+    fs::write(registry.join("feature.yml"), "name: Feature Request\n").unwrap();
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .arg("update")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("feature.yml - has been updated."))
+        .stdout(predicate::str::contains("bug.yml - skipped"));
+
+    assert_file_contains(&issue_dir.join("feature.yml"), "Feature Request");
+    assert_file_contains(&issue_dir.join("bug.yml"), "Our Bug");
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"feature\.yml\s+unchanged").unwrap());
+}
+
+const BASE: &str = "name: Bug\ndescription: Report a bug\nlabels: [bug]\n";
+
+/// Install `bug`, then edit the first line locally and `upstream` in the registry
+fn setup_diverged(upstream: &str) -> (tempfile::TempDir, PathBuf) {
+    let (temp_dir, temp_path, registry) = setup_repo(&[("bug", BASE)]);
+    add_issue_templates(&temp_path, &["bug"]);
+
+    fs::write(
+        temp_path.join(".github/ISSUE_TEMPLATE/bug.yml"),
+        BASE.replace("name: Bug", "name: Our Bug"),
+    )
+    .unwrap();
+    fs::write(registry.join("bug.yml"), upstream).unwrap();
+
+    (temp_dir, temp_path)
+}
+
+#[test]
+fn test_update_merges_local_and_upstream_changes() {
+    let (_temp_dir, temp_path) = setup_diverged(&BASE.replace("[bug]", "[bug, triage]"));
+    let bug = temp_path.join(".github/ISSUE_TEMPLATE/bug.yml");
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .arg("update")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "bug.yml - merged upstream changes.",
+        ));
+
+    assert_file_contains(&bug, "name: Our Bug");
+    assert_file_contains(&bug, "labels: [bug, triage]");
+
+    // The merged file keeps local edits, so it is still reported as modified
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"bug\.yml\s+locally modified\n").unwrap());
+}
+
+#[test]
+fn test_update_writes_conflict_markers() {
+    let (_temp_dir, temp_path) = setup_diverged(&BASE.replace("name: Bug", "name: Bug Report"));
+    let bug = temp_path.join(".github/ISSUE_TEMPLATE/bug.yml");
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .arg("update")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("bug.yml - merged with conflicts."))
+        .stderr(predicate::str::contains(
+            "Merge conflicts in: .github/ISSUE_TEMPLATE/bug.yml",
+        ));
+
+    assert_file_contains(&bug, "<<<<<<<");
+    assert_file_contains(&bug, "name: Our Bug");
+    assert_file_contains(&bug, "name: Bug Report");
+    assert_file_contains(&bug, ">>>>>>>");
+}
+
+#[test]
+fn test_update_no_merge_shows_diff() {
+    let (_temp_dir, temp_path) = setup_diverged(&BASE.replace("[bug]", "[bug, triage]"));
+    let bug = temp_path.join(".github/ISSUE_TEMPLATE/bug.yml");
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .args(["update", "--no-merge"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("merging disabled"))
+        .stdout(predicate::str::contains("labels: [bug, triage]"));
+
+    assert_eq!(
+        fs::read_to_string(&bug).unwrap(),
+        BASE.replace("name: Bug", "name: Our Bug")
+    );
+}
+
+#[test]
+fn test_update_without_base_shows_diff() {
+    let (_temp_dir, temp_path) = setup_diverged(&BASE.replace("[bug]", "[bug, triage]"));
+    let bug = temp_path.join(".github/ISSUE_TEMPLATE/bug.yml");
+
+    // Simulate a clone on another machine: the lockfile exists but no base was cached
+    fs::remove_dir_all(temp_path.join(".local/share/gh-templates/objects")).unwrap();
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .arg("update")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "no installed version to merge against",
+        ));
+
+    assert_file_contains(&bug, "labels: [bug]");
+}
+
+const FILLED_IN: &str = "<!-- Custom -->\n# {{ project_name }}\n\n## Summary\n";
+
+#[test]
+fn test_status_after_filling_in() {
+    let (_temp_dir, temp_path, _registry) = setup_repo(&[]);
+    add_pr_source(&temp_path, FILLED_IN);
+    add_filled_in_template(&temp_path);
+
+    // The lockfile keeps the template as the base and the filled-in file as the hash
+    let lockfile = fs::read_to_string(temp_path.join(".gh-templates.lock")).unwrap();
+    let field = |key: &str| {
+        lockfile
+            .lines()
+            .find_map(|line| line.strip_prefix(&format!("{} = ", key)))
+            .unwrap()
+            .to_string()
+    };
+    assert_ne!(field("base"), field("hash"));
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"custom\.md\s+unchanged").unwrap());
+}
+
+#[test]
+fn test_update_keeps_filled_in_values() {
+    let (_temp_dir, temp_path, _registry) = setup_repo(&[]);
+    let registry = add_pr_source(&temp_path, FILLED_IN);
+    add_filled_in_template(&temp_path);
+    let custom = temp_path.join(".github/PULL_REQUEST_TEMPLATE/custom.md");
+
+    fs::write(
+        registry.join("custom.md"),
+        format!("{}\n## Testing\n", FILLED_IN),
+    )
+    .unwrap();
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"custom\.md\s+upstream updated").unwrap());
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .arg("update")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("custom.md - has been updated."));
+
+    assert_eq!(
+        fs::read_to_string(&custom).unwrap(),
+        "<!-- Custom -->\n# Acme\n\n## Summary\n\n## Testing\n"
+    );
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"custom\.md\s+unchanged").unwrap());
+}
+
+#[test]
+fn test_update_untracked_path() {
+    let (_temp_dir, temp_path, _registry) = setup_repo(&[("bug", "name: Bug\n")]);
+    add_issue_templates(&temp_path, &["bug"]);
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .args(["update", ".github/ISSUE_TEMPLATE/other.yml"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not tracked"));
+}
+
+#[test]
+fn test_status_without_lockfile() {
+    let (_temp_dir, temp_path, _registry) = setup_repo(&[]);
+
+    gh_templates_with_config(&temp_path, &temp_path.join("config.toml"))
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No templates are tracked yet"));
+}
@@ -88,3 +88,29 @@ fn all_templates_start_with_comment() {
         "Some template files do not start with the expected comment. See output above."
     );
 }
+
+// Checks that every issue template is a valid GitHub issue form, so `issue add`
+// never installs a form that GitHub refuses to render.
+#[test]
+fn issue_templates_are_valid_issue_forms() {
+    let template_dir = Path::new("./templates/issue-templates");
+
+    let mut forms: Vec<_> = fs::read_dir(template_dir)
+        .expect("Failed to read issue templates directory")
+        .map(|entry| entry.expect("Failed to read file entry").path())
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "yml")
+                && path.file_name().is_some_and(|name| name != "manifest.yml")
+        })
+        .collect();
+    forms.sort();
+    assert!(!forms.is_empty(), "No issue templates found");
+
+    assert_cmd::Command::cargo_bin("gh-templates")
+        .unwrap()
+        .arg("issue")
+        .arg("validate")
+        .args(&forms)
+        .assert()
+        .success();
+}